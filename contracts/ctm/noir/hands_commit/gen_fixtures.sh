@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+# Regenerate the UltraHonk fixtures used by the ctm contract tests.
+#
+# Requires nargo (>= 1.0.0-beta.9) and a matching bb. Writes proof, vk and
+# public_inputs for the Prover.toml witness into fixtures/. The checked-in
+# public_inputs was derived from the circuit's layout; running this replaces
+# it with bb's output, which the ctm tests must still match.
+set -euo pipefail
+cd "$(dirname "$0")"
+
+nargo execute hands_commit
+bb prove -b target/hands_commit.json -w target/hands_commit.gz -o target --oracle_hash keccak
+bb write_vk -b target/hands_commit.json -o target --oracle_hash keccak
+
+mkdir -p fixtures
+cp target/proof target/vk target/public_inputs fixtures/
@@ -13,6 +13,11 @@
 //! the appropriate phase.  Companion Noir circuits can generate off-chain proofs
 //! that the committed hands are valid *before* the reveal step.
 //!
+//! `commit_hands_with_proof` accepts an UltraHonk proof of the `hands_commit`
+//! circuit alongside the hash.  The proof is checked by the admin-configured
+//! verifier contract against the verification key held in instance storage,
+//! so a commitment to invalid or identical hands is rejected up front.
+//!
 //! ## Game Phases
 //! 1. **CommitHands** – both players submit `keccak256(left || right || salt)`
 //! 2. **RevealHands** – both reveal hands + salt; contract verifies hashes
@@ -20,31 +25,78 @@
 //! 4. **RevealChoice** – both reveal; contract verifies, resolves RPS, calls GameHub
 //! 5. **Complete**
 //!
+//! Every open phase carries a ledger-sequence deadline.  If one player acts
+//! and the other stalls past it, the player who acted can `claim_timeout`
+//! and take the win.
+//!
+//! ## Match Mode
+//! `start_match` opens a best-of-N session (N = 1, 3, 5 or 7).  Each round
+//! replays phases 1–4; the result is appended to `Game.history` and the
+//! match ends once a player has won a majority of rounds.
+//!
+//! ## Draws
+//! What happens when both kept hands are equal is set by the admin's
+//! `DrawPolicy` and frozen into each game at start: the legacy player 1
+//! tiebreak, replaying the choice phase with the same hands, replaying the
+//! whole round, or a true draw reported through `end_game_draw`.
+//!
+//! ## Escrow
+//! When the admin configures a SEP-41 stake token, `start_game` pulls each
+//! player's points as real tokens into the contract.  The pot goes to the
+//! winner (less an optional rake paid to the admin) on resolution or
+//! timeout; a true draw refunds both stakes.
+//!
+//! ## Lobby
+//! The admin can register a lobby contract that starts sessions through
+//! `start_lobby_game` once a posted challenge is accepted, so the players
+//! never have to co-sign one transaction.  The lobby holds the stakes
+//! itself, so lobby sessions skip the escrow above and lock no points.
+//!
+//! ## Tournaments
+//! The admin can also register a tournament contract.  It opens best-of-1
+//! sessions between bracket pairings with `start_tournament_game`, which
+//! lets the Game Hub allocate the session ID and locks no points.
+//!
+//! ## Rematch
+//! Once a game is complete either player can `request_rematch`, optionally
+//! swapping seats so the player 1 tiebreak alternates.  When the opponent
+//! calls `accept_rematch` a new session with the same players, stakes, match
+//! length, draw policy and rake is opened in the Game Hub.  `Game.rematch_of`
+//! and `Game.rematch` link the sessions into a chain.  Escrowed stakes use the
+//! original game's token and each player deposits theirs when they sign.
+//! Lobby games cannot be rematched; post a new challenge instead.
+//!
+//! ## Queries
+//! `get_games` reads a batch of sessions in one call.  Every session a
+//! player takes part in is also indexed under their address, and
+//! `get_player_games` pages through that index newest first so a wallet
+//! can list its games without an off-chain indexer.
+//!
+//! `get_game_view` projects a game for one viewer: commitments become
+//! "has committed" flags, the opponent's hands stay hidden until both are
+//! revealed and their kept hand until the game is complete.  Storage is
+//! public, so this hides nothing from a determined reader; it keeps UIs
+//! from depending on fields they must not show.
+//!
 //! ## Game Hub Integration
-//! Calls `start_game` and `end_game` on the Game Hub contract.
+//! Calls `start_game` and `end_game` on the Game Hub contract.  In match mode
+//! only the final match result is reported.
 
+use game_core::{admin, hub, lobby, session, Admin, CoreError, GameResult, Lobby};
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype,
-    vec, Address, Bytes, BytesN, Env, IntoVal,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
+    token, vec, Address, Bytes, BytesN, Env, IntoVal, Map, Vec,
 };
 
 // ============================================================================
-// Game Hub Interface
+// UltraHonk Verifier Interface
 // ============================================================================
 
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
+#[contractclient(name = "HonkVerifierClient")]
+pub trait HonkVerifier {
+    /// Returns `true` when `proof` is valid for `vk` and `public_inputs`
+    /// (concatenated 32-byte big-endian field elements).
+    fn verify_proof(env: Env, vk: Bytes, public_inputs: Bytes, proof: Bytes) -> bool;
 }
 
 // ============================================================================
@@ -55,29 +107,140 @@ pub trait GameHub {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    GameNotFound      = 1,
-    NotPlayer         = 2,
-    WrongPhase        = 3,
-    AlreadyCommitted  = 4,
-    InvalidHand       = 5,
-    HandsMustDiffer   = 6,
-    HashMismatch      = 7,
-    InvalidChoice     = 8,
-    GameAlreadyEnded  = 9,
+    GameNotFound       = 1,
+    NotPlayer          = 2,
+    WrongPhase         = 3,
+    AlreadyCommitted   = 4,
+    InvalidHand        = 5,
+    HandsMustDiffer    = 6,
+    HashMismatch       = 7,
+    InvalidChoice      = 8,
+    GameAlreadyEnded   = 9,
+    DeadlineNotReached = 10,
+    CannotClaimTimeout = 11,
+    InvalidRounds      = 12,
+    ProofNotConfigured = 13,
+    InvalidProof       = 14,
+    InvalidRake        = 15,
+    InvalidStake       = 16,
+    SelfPlay           = 17,
+    HubNotSet          = 18,
+    LobbyNotSet        = 19,
+    SessionExists      = 20,
+    RematchExists      = 21,
+    NoRematchOffer     = 22,
+    CannotAcceptOwn    = 23,
+    TournamentNotSet   = 24,
+    InvalidPageSize    = 25,
+    LobbyRematch       = 26,
+}
+
+impl From<CoreError> for Error {
+    fn from(err: CoreError) -> Self {
+        match err {
+            CoreError::SelfPlay => Error::SelfPlay,
+            CoreError::HubNotSet => Error::HubNotSet,
+            CoreError::SessionExists => Error::SessionExists,
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+//
+// One event per phase transition so frontends and indexers can follow a game
+// without polling `get_game`.  Session lifecycle (start / end) is still
+// published by the Game Hub.
+
+#[contractevent]
+pub struct HandsCommitted {
+    pub session_id: u32,
+    pub player: Address,
+    pub hands_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct HandsRevealed {
+    pub session_id: u32,
+    pub player: Address,
+    pub left_hand: u32,
+    pub right_hand: u32,
+}
+
+#[contractevent]
+pub struct ChoiceCommitted {
+    pub session_id: u32,
+    pub player: Address,
+    pub choice_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct ChoiceRevealed {
+    pub session_id: u32,
+    pub player: Address,
+    pub kept_hand: u32,
+}
+
+/// Published when a player offers a rematch of a completed game.
+#[contractevent]
+pub struct RematchRequested {
+    pub session_id: u32,
+    pub player: Address,
+    pub swap: bool,
+}
+
+/// Published when a rematch offer is accepted and the new session opens.
+#[contractevent]
+pub struct RematchStarted {
+    pub session_id: u32,
+    pub rematch_session_id: u32,
+}
+
+/// Published once when the game ends; `winner` is `None` for a true draw.
+#[contractevent]
+pub struct GameResolved {
+    pub session_id: u32,
+    pub winner: Option<Address>,
+    pub p1_wins: u32,
+    pub p2_wins: u32,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+/// Game phases.  Encoded as `u32` (1..5) on the wire.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Phase {
+    /// Waiting for both commit hashes
+    CommitHands = 1,
+    /// Waiting for both to reveal hands
+    RevealHands = 2,
+    /// Waiting for both to commit which hand to keep
+    CommitChoice = 3,
+    /// Waiting for both to reveal their choice
+    RevealChoice = 4,
+    /// Winner determined (none after a true draw)
+    Complete = 5,
+}
+
+/// Player actions accepted by the state machine.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Action {
+    CommitHands,
+    RevealHands,
+    CommitChoice,
+    RevealChoice,
+    ClaimTimeout,
+    RequestRematch,
+    AcceptRematch,
+    CancelRematch,
+}
+
 /// Hand constants: 0 = Rock 🪨,  1 = Paper ✋,  2 = Scissors ✌️
-///
-/// Game phases:
-///   1 = CommitHands      – waiting for both commit hashes
-///   2 = RevealHands      – waiting for both to reveal hands
-///   3 = CommitChoice     – waiting for both to commit which hand to keep
-///   4 = RevealChoice     – waiting for both to reveal their choice
-///   5 = Complete         – winner determined
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -86,7 +249,7 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub phase: u32,
+    pub phase: Phase,
 
     // Phase 1 – commitment hashes  keccak256(left_hand || right_hand || salt)
     pub p1_commit: Option<BytesN<32>>,
@@ -106,21 +269,172 @@ pub struct Game {
     pub p1_kept: Option<u32>,
     pub p2_kept: Option<u32>,
     pub winner: Option<Address>,
+
+    // Ledger sequence after which the current phase can be forfeited
+    pub deadline: u32,
+
+    // Match – best-of-N rounds (1 for a single duel)
+    pub best_of: u32,
+    pub round: u32,
+    pub p1_wins: u32,
+    pub p2_wins: u32,
+    pub history: Vec<RoundResult>,
+
+    // Draw handling, fixed when the game starts
+    pub draw_policy: DrawPolicy,
+
+    // Escrow – token holding both stakes (None = points only) and rake in bps
+    pub escrow_token: Option<Address>,
+    pub rake_bps: u32,
+
+    // Started by `start_lobby_game`, so the stakes are held by the lobby
+    pub lobby: bool,
+
+    // Rematch chain – previous and next session, plus a pending offer from
+    // `rematch_offer` (whose stake is already deposited) and whether it swaps seats
+    pub rematch_of: Option<u32>,
+    pub rematch: Option<u32>,
+    pub rematch_offer: Option<Address>,
+    pub rematch_swap: bool,
+}
+
+/// One player's side of a `GameView`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatView {
+    pub player: Address,
+    pub points: i128,
+    pub wins: u32,
+    pub hands_committed: bool,
+    pub hands_revealed: bool,
+    /// Hidden from the opponent until both hands are revealed
+    pub left_hand: Option<u32>,
+    pub right_hand: Option<u32>,
+    pub choice_committed: bool,
+    pub choice_revealed: bool,
+    /// Hidden from the opponent until the game is complete
+    pub kept_hand: Option<u32>,
+}
+
+/// A game as one viewer may see it, from `get_game_view`.  Commitment
+/// hashes are never included.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameView {
+    pub session_id: u32,
+    pub phase: Phase,
+    pub player1: SeatView,
+    pub player2: SeatView,
+    pub winner: Option<Address>,
+    pub deadline: u32,
+    pub best_of: u32,
+    pub round: u32,
+    pub history: Vec<RoundResult>,
+    pub draw_policy: DrawPolicy,
+    pub escrow_token: Option<Address>,
+    pub rake_bps: u32,
+    pub lobby: bool,
+    pub rematch_of: Option<u32>,
+    pub rematch: Option<u32>,
+    pub rematch_offer: Option<Address>,
+    pub rematch_swap: bool,
+}
+
+/// Sessions a player has taken part in, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PlayerIndex {
+    /// Entries dropped from the front to stay within `MAX_PLAYER_GAMES`
+    dropped: u32,
+    sessions: Vec<u32>,
+}
+
+/// One page of a player's sessions from `get_player_games`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GamePage {
+    /// `(session_id, game)` pairs on this page, newest first.  Sessions
+    /// whose game has expired from storage are left out, so a page can
+    /// hold fewer than `limit`.
+    pub games: Vec<(u32, Game)>,
+    /// Cursor for the next page, `None` once the index is exhausted.
+    /// Counted from the player's oldest session, so games started while
+    /// paging do not shift it.
+    pub next_cursor: Option<u32>,
+}
+
+/// Outcome of one completed round in a match.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundResult {
+    pub p1_left: u32,
+    pub p1_right: u32,
+    pub p2_left: u32,
+    pub p2_right: u32,
+    pub p1_kept: u32,
+    pub p2_kept: u32,
+    pub outcome: RoundOutcome,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
 }
 
+/// How a round resolves when both kept hands are equal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DrawPolicy {
+    /// Player 1 takes the round (original studio tiebreak)
+    Player1Wins,
+    /// Keep the revealed hands and reopen phase 3
+    ReplayChoice,
+    /// Discard the round and reopen phase 1
+    ReplayRound,
+    /// Score the round as a draw; a tied match is reported via `end_game_draw`
+    TrueDraw,
+}
+
+/// CTM configuration.  Games, the admin and the Game Hub address are stored
+/// under `game_core::CoreKey`.
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
+    DrawPolicy,
+    HandsVerifier,
+    HandsVk,
+    EscrowToken,
+    RakeBps,
+    Tournament,
+    /// `PlayerIndex` of the sessions a player has taken part in (persistent)
+    PlayerGames(Address),
 }
 
 // ============================================================================
-// Storage TTL  (30 days ≈ 518 400 ledgers at ~5 s each)
+// Constants
 // ============================================================================
 
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Ledgers a phase may stay open before a stalled player can be forfeited
+/// (1 day ≈ 17 280 ledgers).
+const PHASE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Rake is expressed in basis points of the pot.
+const MAX_RAKE_BPS: u32 = 10_000;
+
+/// Longest match a session may be configured for.
+const MAX_BEST_OF: u32 = 7;
+
+/// Most sessions `get_games` and `get_player_games` return per call.
+const MAX_PAGE_SIZE: u32 = 20;
+
+/// Sessions kept in a player's index; older entries are dropped first.
+const MAX_PLAYER_GAMES: u32 = 500;
+
+/// A player's index outlives their last game (30 days) by another 30.
+const PLAYER_GAMES_TTL_LEDGERS: u32 = 2 * session::GAME_TTL_LEDGERS;
 
 // ============================================================================
 // Helpers
@@ -147,6 +461,20 @@ fn hash_choice(env: &Env, choice: u32, salt: &BytesN<32>) -> BytesN<32> {
     env.crypto().keccak256(&pre).into()
 }
 
+/// Public inputs of the `hands_commit` circuit for a given commitment.
+///
+/// Noir exposes `commitment: pub [u8; 32]` as 32 field elements, one per
+/// byte, each encoded as a 32-byte big-endian word.
+fn hands_public_inputs(env: &Env, hands_hash: &BytesN<32>) -> Bytes {
+    let mut inputs = Bytes::new(env);
+    for byte in hands_hash.to_array() {
+        let mut word = [0u8; 32];
+        word[31] = byte;
+        inputs.append(&Bytes::from_array(env, &word));
+    }
+    inputs
+}
+
 /// Classic Rock-Paper-Scissors resolution.
 /// Returns `true` when `hand1` beats `hand2`.
 fn rps_beats(hand1: u32, hand2: u32) -> bool {
@@ -155,12 +483,374 @@ fn rps_beats(hand1: u32, hand2: u32) -> bool {
     || (hand1 == 2 && hand2 == 1) // Scissors beats Paper
 }
 
-fn save_game(env: &Env, session_id: u32, game: &Game) {
-    let key = DataKey::Game(session_id);
-    env.storage().temporary().set(&key, game);
+/// Score a round under the game's draw policy.
+/// Returns `None` when a draw must be replayed rather than scored.
+fn round_outcome(h1: u32, h2: u32, policy: DrawPolicy) -> Option<RoundOutcome> {
+    if rps_beats(h1, h2) {
+        Some(RoundOutcome::Player1Won)
+    } else if rps_beats(h2, h1) {
+        Some(RoundOutcome::Player2Won)
+    } else {
+        match policy {
+            DrawPolicy::Player1Wins => Some(RoundOutcome::Player1Won),
+            DrawPolicy::TrueDraw => Some(RoundOutcome::Draw),
+            DrawPolicy::ReplayChoice | DrawPolicy::ReplayRound => None,
+        }
+    }
+}
+
+/// The CTM state machine.
+///
+/// Checks that `action` is legal in `phase` and returns the phase the game
+/// enters once both players have performed it.  Entry points call this
+/// before touching state; draws and match rounds may later send the game
+/// back to an earlier phase instead.
+pub(crate) fn transition(phase: Phase, action: Action) -> Result<Phase, Error> {
+    match (phase, action) {
+        (Phase::CommitHands, Action::CommitHands) => Ok(Phase::RevealHands),
+        (Phase::RevealHands, Action::RevealHands) => Ok(Phase::CommitChoice),
+        (Phase::CommitChoice, Action::CommitChoice) => Ok(Phase::RevealChoice),
+        (Phase::RevealChoice, Action::RevealChoice) => Ok(Phase::Complete),
+        (Phase::Complete, Action::ClaimTimeout) => Err(Error::GameAlreadyEnded),
+        (_, Action::ClaimTimeout) => Ok(Phase::Complete),
+        (Phase::Complete, Action::RequestRematch | Action::AcceptRematch | Action::CancelRematch) => {
+            Ok(Phase::Complete)
+        }
+        _ => Err(Error::WrongPhase),
+    }
+}
+
+/// Deadline for a phase that opens at the current ledger.
+fn next_deadline(env: &Env) -> u32 {
+    env.ledger().sequence().saturating_add(PHASE_TIMEOUT_LEDGERS)
+}
+
+/// Whether each player has already acted in the current phase.
+/// Returns `(player1_acted, player2_acted)`.
+fn phase_progress(game: &Game) -> (bool, bool) {
+    match game.phase {
+        Phase::CommitHands => (game.p1_commit.is_some(), game.p2_commit.is_some()),
+        Phase::RevealHands => (game.p1_left.is_some(), game.p2_left.is_some()),
+        Phase::CommitChoice => (game.p1_choice_commit.is_some(), game.p2_choice_commit.is_some()),
+        Phase::RevealChoice => (game.p1_kept.is_some(), game.p2_kept.is_some()),
+        Phase::Complete => (false, false),
+    }
+}
+
+/// Clear the per-round state and reopen phase 1.
+fn reset_round(env: &Env, game: &mut Game) {
+    game.p1_commit = None;
+    game.p2_commit = None;
+    game.p1_left = None;
+    game.p1_right = None;
+    game.p2_left = None;
+    game.p2_right = None;
+    reopen_choice(env, game);
+    game.phase = Phase::CommitHands;
+}
+
+/// Clear the choice commitments and reopen phase 3, keeping revealed hands.
+fn reopen_choice(env: &Env, game: &mut Game) {
+    game.p1_choice_commit = None;
+    game.p2_choice_commit = None;
+    game.p1_kept = None;
+    game.p2_kept = None;
+    game.phase = Phase::CommitChoice;
+    game.deadline = next_deadline(env);
+}
+
+/// Store a player's hands commitment and advance once both are in.
+fn record_hands_commit(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    hands_hash: BytesN<32>,
+) -> Result<(), Error> {
+    let mut game: Game = session::load(env, session_id).ok_or(Error::GameNotFound)?;
+
+    let next = transition(game.phase, Action::CommitHands)?;
+
+    if *player == game.player1 {
+        if game.p1_commit.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+        game.p1_commit = Some(hands_hash.clone());
+    } else if *player == game.player2 {
+        if game.p2_commit.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+        game.p2_commit = Some(hands_hash.clone());
+    } else {
+        return Err(Error::NotPlayer);
+    }
+
+    HandsCommitted {
+        session_id,
+        player: player.clone(),
+        hands_hash,
+    }
+    .publish(env);
+
+    // Auto-advance when both have committed
+    if game.p1_commit.is_some() && game.p2_commit.is_some() {
+        game.phase = next;
+        game.deadline = next_deadline(env);
+    }
+
+    session::save(env, session_id, &game);
+    Ok(())
+}
+
+/// Pull a player's stake into the contract when the game is escrowed.
+fn deposit_stake(
+    env: &Env,
+    escrow_token: &Option<Address>,
+    player: &Address,
+    points: i128,
+) -> Result<(), Error> {
+    let Some(token_addr) = escrow_token else {
+        return Ok(());
+    };
+    if points < 0 {
+        return Err(Error::InvalidStake);
+    }
+    let contract = env.current_contract_address();
+    token::TokenClient::new(env, token_addr).transfer(player, &contract, &points);
+    Ok(())
+}
+
+/// Take both stakes for a new game started by its players.
+///
+/// Uses the configured escrow token, if any, and returns it for the game.
+fn deposit_stakes(
+    env: &Env,
+    player1: &Address,
+    player2: &Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<Option<Address>, Error> {
+    let escrow_token: Option<Address> = env.storage().instance().get(&DataKey::EscrowToken);
+    deposit_stake(env, &escrow_token, player1, player1_points)?;
+    deposit_stake(env, &escrow_token, player2, player2_points)?;
+    Ok(escrow_token)
+}
+
+/// The draw policy new games are started with.
+fn current_draw_policy(env: &Env) -> DrawPolicy {
     env.storage()
-        .temporary()
-        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        .instance()
+        .get(&DataKey::DrawPolicy)
+        .unwrap_or(DrawPolicy::Player1Wins)
+}
+
+/// The admin's current cut of each escrowed pot, in basis points.
+fn current_rake_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::RakeBps).unwrap_or(0)
+}
+
+/// Register the session with the Game Hub and store a fresh game.
+///
+/// Escrowed stakes must already be held by the contract; `escrow_token` is
+/// `None` for points-only games and lobby sessions, whose stakes the lobby
+/// holds (`lobby`).  `draw_policy` and `rake_bps` are fixed for the life of
+/// the game.
+#[allow(clippy::too_many_arguments)]
+fn create_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    best_of: u32,
+    draw_policy: DrawPolicy,
+    escrow_token: Option<Address>,
+    rake_bps: u32,
+    lobby: bool,
+    rematch_of: Option<u32>,
+) -> Result<(), Error> {
+    hub::start_game(
+        env,
+        session_id,
+        &player1,
+        &player2,
+        player1_points,
+        player2_points,
+    )?;
+
+    let game = Game {
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        phase: Phase::CommitHands,
+        p1_commit: None,
+        p2_commit: None,
+        p1_left: None,
+        p1_right: None,
+        p2_left: None,
+        p2_right: None,
+        p1_choice_commit: None,
+        p2_choice_commit: None,
+        p1_kept: None,
+        p2_kept: None,
+        winner: None,
+        deadline: next_deadline(env),
+        best_of,
+        round: 1,
+        p1_wins: 0,
+        p2_wins: 0,
+        history: Vec::new(env),
+        draw_policy,
+        escrow_token,
+        rake_bps,
+        lobby,
+        rematch_of,
+        rematch: None,
+        rematch_offer: None,
+        rematch_swap: false,
+    };
+
+    session::save(env, session_id, &game);
+    index_player_game(env, &game.player1, session_id);
+    index_player_game(env, &game.player2, session_id);
+    Ok(())
+}
+
+/// Append a session to a player's index, dropping the oldest entry once it
+/// holds `MAX_PLAYER_GAMES`.
+fn index_player_game(env: &Env, player: &Address, session_id: u32) {
+    let key = DataKey::PlayerGames(player.clone());
+    let mut index: PlayerIndex = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(PlayerIndex {
+            dropped: 0,
+            sessions: Vec::new(env),
+        });
+    if index.sessions.len() >= MAX_PLAYER_GAMES {
+        index.sessions.pop_front();
+        index.dropped += 1;
+    }
+    index.sessions.push_back(session_id);
+    env.storage().persistent().set(&key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PLAYER_GAMES_TTL_LEDGERS, PLAYER_GAMES_TTL_LEDGERS);
+}
+
+/// Project one seat of `game` for `viewer`.
+///
+/// A player always sees their own seat.  The opponent's hands are shown
+/// once both have been revealed (any phase after `RevealHands`) and their
+/// kept hand once the game is complete.
+fn seat_view(game: &Game, player1: bool, viewer: &Address) -> SeatView {
+    let (player, points, wins, commit, left, right, choice_commit, kept) = if player1 {
+        (
+            &game.player1,
+            game.player1_points,
+            game.p1_wins,
+            &game.p1_commit,
+            game.p1_left,
+            game.p1_right,
+            &game.p1_choice_commit,
+            game.p1_kept,
+        )
+    } else {
+        (
+            &game.player2,
+            game.player2_points,
+            game.p2_wins,
+            &game.p2_commit,
+            game.p2_left,
+            game.p2_right,
+            &game.p2_choice_commit,
+            game.p2_kept,
+        )
+    };
+    let own = player == viewer;
+    let show_hands = own || game.phase != Phase::RevealHands;
+    let show_kept = own || game.phase == Phase::Complete;
+
+    SeatView {
+        player: player.clone(),
+        points,
+        wins,
+        hands_committed: commit.is_some(),
+        hands_revealed: left.is_some(),
+        left_hand: left.filter(|_| show_hands),
+        right_hand: right.filter(|_| show_hands),
+        choice_committed: choice_commit.is_some(),
+        choice_revealed: kept.is_some(),
+        kept_hand: kept.filter(|_| show_kept),
+    }
+}
+
+/// Pay out the escrowed pot, if any.
+///
+/// The winner receives both stakes less the rake, which goes to the admin.
+/// With no winner (a true draw) each player gets their own stake back.
+fn settle_escrow(env: &Env, game: &Game) {
+    let Some(token_addr) = &game.escrow_token else {
+        return;
+    };
+    let stake = token::TokenClient::new(env, token_addr);
+    let contract = env.current_contract_address();
+
+    match &game.winner {
+        Some(winner) => {
+            let pot = game.player1_points + game.player2_points;
+            let rake = pot * game.rake_bps as i128 / MAX_RAKE_BPS as i128;
+            if rake > 0 {
+                let admin = admin::address(env);
+                stake.transfer(&contract, &admin, &rake);
+            }
+            if pot - rake > 0 {
+                stake.transfer(&contract, winner, &(pot - rake));
+            }
+        }
+        None => {
+            if game.player1_points > 0 {
+                stake.transfer(&contract, &game.player1, &game.player1_points);
+            }
+            if game.player2_points > 0 {
+                stake.transfer(&contract, &game.player2, &game.player2_points);
+            }
+        }
+    }
+}
+
+/// Close the match on its round tally and report the result to the Game Hub.
+/// Equal tallies are reported as a draw and leave `winner` unset.
+fn finish_match(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+    game.phase = Phase::Complete;
+
+    if game.p1_wins != game.p2_wins {
+        game.winner = Some(if game.p1_wins > game.p2_wins {
+            game.player1.clone()
+        } else {
+            game.player2.clone()
+        });
+    }
+    publish_resolved(env, session_id, game);
+    settle_escrow(env, game);
+
+    match &game.winner {
+        Some(winner) => hub::end_game(env, session_id, *winner == game.player1)?,
+        None => hub::end_game_draw(env, session_id)?,
+    }
+    Ok(())
+}
+
+fn publish_resolved(env: &Env, session_id: u32, game: &Game) {
+    GameResolved {
+        session_id,
+        winner: game.winner.clone(),
+        p1_wins: game.p1_wins,
+        p2_wins: game.p2_wins,
+    }
+    .publish(env);
 }
 
 // ============================================================================
@@ -176,10 +866,7 @@ impl CtmContract {
 
     /// Initialize the contract with admin + Game Hub address.
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     // ------------------------------------------------------------ start_game
@@ -187,7 +874,8 @@ impl CtmContract {
     /// Start a new Gawi Bawi Bo session.
     ///
     /// Creates a session in the Game Hub and locks both players' points.
-    /// Requires multi-sig auth from both players.
+    /// Requires multi-sig auth from both players. Pass `session_id` 0 to let
+    /// the Game Hub allocate an unused ID; the session ID is returned.
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -195,10 +883,8 @@ impl CtmContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
-        if player1 == player2 {
-            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
-        }
+    ) -> Result<u32, Error> {
+        session::require_distinct_players(&player1, &player2)?;
 
         // Both players authorize their point commitment
         player1.require_auth_for_args(vec![
@@ -212,43 +898,161 @@ impl CtmContract {
             player2_points.into_val(&env),
         ]);
 
-        // Register with Game Hub
-        let hub_addr: Address = env
+        // Use the requested ID unless it already holds a game, or allocate one
+        let session_id = session::claim(&env, session_id)?;
+        // Pull both stakes into the contract before the session exists
+        let escrow_token =
+            deposit_stakes(&env, &player1, &player2, player1_points, player2_points)?;
+        create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            1,
+            current_draw_policy(&env),
+            escrow_token,
+            current_rake_bps(&env),
+            false,
+            None,
+        )?;
+        Ok(session_id)
+    }
+
+    // ------------------------------------------------------ start_lobby_game
+
+    /// Start a best-of-1 session on behalf of the registered lobby.
+    ///
+    /// Only the lobby's auth is required: the players already authorized
+    /// their stakes when posting and accepting the challenge there.  The
+    /// lobby holds those stakes, so no points are locked.
+    pub fn start_lobby_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+    ) -> Result<(), Error> {
+        let lobby = lobby::address(&env).ok_or(Error::LobbyNotSet)?;
+        lobby.require_auth();
+        session::require_distinct_players(&player1, &player2)?;
+        // The lobby keys its challenge by this ID, so never reallocate it
+        session::require_new(&env, session_id)?;
+
+        create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            0,
+            0,
+            1,
+            current_draw_policy(&env),
+            None,
+            current_rake_bps(&env),
+            true,
+            None,
+        )
+    }
+
+    // ------------------------------------------------- start_tournament_game
+
+    /// Start a best-of-1 session for a bracket pairing of the registered
+    /// tournament contract.
+    ///
+    /// Only the tournament's auth is required: the players signed up when
+    /// they registered there.  The Game Hub allocates the session ID, which
+    /// is returned, and no points are locked.  Drawn rounds are never
+    /// replayed: under either replay policy they are scored as `TrueDraw`.
+    pub fn start_tournament_game(
+        env: Env,
+        player1: Address,
+        player2: Address,
+    ) -> Result<u32, Error> {
+        let tournament: Address = env
             .storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let hub = GameHubClient::new(&env, &hub_addr);
-        hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+            .get(&DataKey::Tournament)
+            .ok_or(Error::TournamentNotSet)?;
+        tournament.require_auth();
+        session::require_distinct_players(&player1, &player2)?;
+
+        // A replayed draw could outlast the tournament's match deadline, so
+        // it is scored as a draw and the tournament breaks the tie by seed
+        let draw_policy = match current_draw_policy(&env) {
+            DrawPolicy::ReplayChoice | DrawPolicy::ReplayRound => DrawPolicy::TrueDraw,
+            policy => policy,
+        };
 
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        let session_id = session::claim(&env, 0)?;
+        create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            0,
+            0,
+            1,
+            draw_policy,
+            None,
+            current_rake_bps(&env),
+            false,
+            None,
+        )?;
+        Ok(session_id)
+    }
+
+    // ----------------------------------------------------------- start_match
+
+    /// Start a best-of-N match (`best_of` = 1, 3, 5 or 7).
+    ///
+    /// Same as `start_game`, but each player's auth also covers `best_of`
+    /// so neither side can be signed into a longer match than agreed.
+    pub fn start_match(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        best_of: u32,
+    ) -> Result<u32, Error> {
+        session::require_distinct_players(&player1, &player2)?;
+        if best_of == 0 || best_of > MAX_BEST_OF || best_of.is_multiple_of(2) {
+            return Err(Error::InvalidRounds);
+        }
+
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            best_of.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            best_of.into_val(&env),
+        ]);
+
+        let session_id = session::claim(&env, session_id)?;
+        let escrow_token =
+            deposit_stakes(&env, &player1, &player2, player1_points, player2_points)?;
+        create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            phase: 1,
-            p1_commit: None,
-            p2_commit: None,
-            p1_left: None,
-            p1_right: None,
-            p2_left: None,
-            p2_right: None,
-            p1_choice_commit: None,
-            p2_choice_commit: None,
-            p1_kept: None,
-            p2_kept: None,
-            winner: None,
-        };
-
-        save_game(&env, session_id, &game);
-        Ok(())
+            best_of,
+            current_draw_policy(&env),
+            escrow_token,
+            current_rake_bps(&env),
+            false,
+            None,
+        )?;
+        Ok(session_id)
     }
 
     // ---------------------------------------------------------- commit_hands
@@ -263,39 +1067,46 @@ impl CtmContract {
         hands_hash: BytesN<32>,
     ) -> Result<(), Error> {
         player.require_auth();
+        record_hands_commit(&env, session_id, &player, hands_hash)
+    }
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
-
-        if game.phase != 1 {
-            return Err(Error::WrongPhase);
-        }
+    // ----------------------------------------------- commit_hands_with_proof
 
-        if player == game.player1 {
-            if game.p1_commit.is_some() {
-                return Err(Error::AlreadyCommitted);
-            }
-            game.p1_commit = Some(hands_hash);
-        } else if player == game.player2 {
-            if game.p2_commit.is_some() {
-                return Err(Error::AlreadyCommitted);
-            }
-            game.p2_commit = Some(hands_hash);
-        } else {
-            return Err(Error::NotPlayer);
-        }
+    /// **Phase 1** – Commit two hands with a `hands_commit` UltraHonk proof.
+    ///
+    /// The proof shows the hash opens to two valid, different hands, so the
+    /// commitment cannot later fail `reveal_hands` on hand validity.
+    pub fn commit_hands_with_proof(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hands_hash: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
 
-        // Auto-advance when both have committed
-        if game.p1_commit.is_some() && game.p2_commit.is_some() {
-            game.phase = 2;
+        let verifier: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::HandsVerifier)
+            .ok_or(Error::ProofNotConfigured)?;
+        let vk: Bytes = env
+            .storage()
+            .instance()
+            .get(&DataKey::HandsVk)
+            .ok_or(Error::ProofNotConfigured)?;
+
+        let public_inputs = hands_public_inputs(&env, &hands_hash);
+        let valid = HonkVerifierClient::new(&env, &verifier).verify_proof(
+            &vk,
+            &public_inputs,
+            &proof,
+        );
+        if !valid {
+            return Err(Error::InvalidProof);
         }
 
-        save_game(&env, session_id, &game);
-        Ok(())
+        record_hands_commit(&env, session_id, &player, hands_hash)
     }
 
     // ---------------------------------------------------------- reveal_hands
@@ -315,16 +1126,9 @@ impl CtmContract {
     ) -> Result<(), Error> {
         player.require_auth();
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
-        if game.phase != 2 {
-            return Err(Error::WrongPhase);
-        }
+        let next = transition(game.phase, Action::RevealHands)?;
         if left_hand > 2 || right_hand > 2 {
             return Err(Error::InvalidHand);
         }
@@ -358,12 +1162,21 @@ impl CtmContract {
             return Err(Error::NotPlayer);
         }
 
+        HandsRevealed {
+            session_id,
+            player,
+            left_hand,
+            right_hand,
+        }
+        .publish(&env);
+
         // Auto-advance when both have revealed
         if game.p1_left.is_some() && game.p2_left.is_some() {
-            game.phase = 3;
+            game.phase = next;
+            game.deadline = next_deadline(&env);
         }
 
-        save_game(&env, session_id, &game);
+        session::save(&env, session_id, &game);
         Ok(())
     }
 
@@ -381,36 +1194,37 @@ impl CtmContract {
     ) -> Result<(), Error> {
         player.require_auth();
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
-        if game.phase != 3 {
-            return Err(Error::WrongPhase);
-        }
+        let next = transition(game.phase, Action::CommitChoice)?;
 
         if player == game.player1 {
             if game.p1_choice_commit.is_some() {
                 return Err(Error::AlreadyCommitted);
             }
-            game.p1_choice_commit = Some(choice_hash);
+            game.p1_choice_commit = Some(choice_hash.clone());
         } else if player == game.player2 {
             if game.p2_choice_commit.is_some() {
                 return Err(Error::AlreadyCommitted);
             }
-            game.p2_choice_commit = Some(choice_hash);
+            game.p2_choice_commit = Some(choice_hash.clone());
         } else {
             return Err(Error::NotPlayer);
         }
 
+        ChoiceCommitted {
+            session_id,
+            player,
+            choice_hash,
+        }
+        .publish(&env);
+
         if game.p1_choice_commit.is_some() && game.p2_choice_commit.is_some() {
-            game.phase = 4;
+            game.phase = next;
+            game.deadline = next_deadline(&env);
         }
 
-        save_game(&env, session_id, &game);
+        session::save(&env, session_id, &game);
         Ok(())
     }
 
@@ -419,8 +1233,9 @@ impl CtmContract {
     /// **Phase 4** – Reveal which hand you kept.
     ///
     /// The contract verifies the hash, looks up the actual hand value, and —
-    /// once both players have revealed — resolves the RPS duel and reports to
-    /// the Game Hub.
+    /// once both players have revealed — resolves the RPS duel.  The round is
+    /// appended to `history`; if neither player has a majority yet the next
+    /// round opens in phase 1, otherwise the match result goes to the Game Hub.
     pub fn reveal_choice(
         env: Env,
         session_id: u32,
@@ -430,23 +1245,16 @@ impl CtmContract {
     ) -> Result<(), Error> {
         player.require_auth();
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
-        if game.phase != 4 {
-            return Err(Error::WrongPhase);
-        }
+        transition(game.phase, Action::RevealChoice)?;
         if choice_index > 1 {
             return Err(Error::InvalidChoice);
         }
 
         let computed = hash_choice(&env, choice_index, &salt);
 
-        if player == game.player1 {
+        let kept_hand = if player == game.player1 {
             if game.p1_kept.is_some() {
                 return Err(Error::AlreadyCommitted);
             }
@@ -460,6 +1268,7 @@ impl CtmContract {
                 game.p1_right.unwrap()
             };
             game.p1_kept = Some(kept);
+            kept
         } else if player == game.player2 {
             if game.p2_kept.is_some() {
                 return Err(Error::AlreadyCommitted);
@@ -474,37 +1283,246 @@ impl CtmContract {
                 game.p2_right.unwrap()
             };
             game.p2_kept = Some(kept);
+            kept
         } else {
             return Err(Error::NotPlayer);
+        };
+
+        ChoiceRevealed {
+            session_id,
+            player,
+            kept_hand,
         }
+        .publish(&env);
 
         // ---- resolve when both revealed ----
-        if game.p1_kept.is_some() && game.p2_kept.is_some() {
-            let h1 = game.p1_kept.unwrap();
-            let h2 = game.p2_kept.unwrap();
+        if let (Some(h1), Some(h2)) = (game.p1_kept, game.p2_kept) {
+            match round_outcome(h1, h2, game.draw_policy) {
+                None if game.draw_policy == DrawPolicy::ReplayChoice => {
+                    reopen_choice(&env, &mut game);
+                }
+                None => reset_round(&env, &mut game),
+                Some(outcome) => {
+                    game.history.push_back(RoundResult {
+                        p1_left: game.p1_left.unwrap(),
+                        p1_right: game.p1_right.unwrap(),
+                        p2_left: game.p2_left.unwrap(),
+                        p2_right: game.p2_right.unwrap(),
+                        p1_kept: h1,
+                        p2_kept: h2,
+                        outcome,
+                    });
+                    match outcome {
+                        RoundOutcome::Player1Won => game.p1_wins += 1,
+                        RoundOutcome::Player2Won => game.p2_wins += 1,
+                        RoundOutcome::Draw => {}
+                    }
+
+                    // Scored draws can use up every round without a majority
+                    let needed = game.best_of / 2 + 1;
+                    if game.p1_wins >= needed
+                        || game.p2_wins >= needed
+                        || game.round >= game.best_of
+                    {
+                        finish_match(&env, session_id, &mut game)?;
+                    } else {
+                        game.round += 1;
+                        reset_round(&env, &mut game);
+                    }
+                }
+            }
+        }
 
-            // Draw → player 1 wins (tiebreaker, per studio convention)
-            let player1_won = rps_beats(h1, h2) || h1 == h2;
+        session::save(&env, session_id, &game);
+        Ok(())
+    }
+
+    // --------------------------------------------------------- claim_timeout
+
+    /// Claim the win after the opponent stalls past the phase deadline.
+    ///
+    /// Only the player who already acted in the current phase may claim, and
+    /// only once the ledger sequence is past `game.deadline`.  The claimant is
+    /// recorded as winner and the outcome is reported to the Game Hub.
+    pub fn claim_timeout(
+        env: Env,
+        session_id: u32,
+        claimant: Address,
+    ) -> Result<Address, Error> {
+        claimant.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let next = transition(game.phase, Action::ClaimTimeout)?;
+
+        let (p1_acted, p2_acted) = phase_progress(&game);
+        let player1_won = if claimant == game.player1 {
+            true
+        } else if claimant == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if env.ledger().sequence() <= game.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // The claimant must have acted and the opponent must not have
+        let (claimant_acted, opponent_acted) = if player1_won {
+            (p1_acted, p2_acted)
+        } else {
+            (p2_acted, p1_acted)
+        };
+        if !claimant_acted || opponent_acted {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        game.winner = Some(claimant.clone());
+        game.phase = next;
+        publish_resolved(&env, session_id, &game);
+        settle_escrow(&env, &game);
+
+        hub::end_game(&env, session_id, player1_won)?;
+
+        session::save(&env, session_id, &game);
+        Ok(claimant)
+    }
 
-            let winner = if player1_won {
-                game.player1.clone()
+    // ------------------------------------------------------- request_rematch
+
+    /// Offer a rematch of a completed game to the opponent.
+    ///
+    /// The rematch keeps the players, stakes, match length, draw policy and
+    /// rake; with `swap` player 1 and player 2 trade seats.  In an escrowed
+    /// game the requester's stake is deposited now and refunded by
+    /// `cancel_rematch`.  Lobby games cannot be rematched: their stakes are
+    /// held and paid out by the lobby, so a rematch would be unstaked.
+    pub fn request_rematch(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        swap: bool,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        transition(game.phase, Action::RequestRematch)?;
+        if game.lobby {
+            return Err(Error::LobbyRematch);
+        }
+        if game.rematch.is_some() || game.rematch_offer.is_some() {
+            return Err(Error::RematchExists);
+        }
+        let points = if player == game.player1 {
+            game.player1_points
+        } else if player == game.player2 {
+            game.player2_points
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        deposit_stake(&env, &game.escrow_token, &player, points)?;
+        game.rematch_offer = Some(player.clone());
+        game.rematch_swap = swap;
+
+        RematchRequested {
+            session_id,
+            player,
+            swap,
+        }
+        .publish(&env);
+
+        session::save(&env, session_id, &game);
+        Ok(())
+    }
+
+    // -------------------------------------------------------- accept_rematch
+
+    /// Accept the opponent's rematch offer and open the new session.
+    ///
+    /// The session ID is allocated by the Game Hub and returned.  Both
+    /// players' points are locked again, and in an escrowed game the
+    /// accepting player's stake is deposited.
+    pub fn accept_rematch(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        player.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        transition(game.phase, Action::AcceptRematch)?;
+        let requester = game.rematch_offer.clone().ok_or(Error::NoRematchOffer)?;
+        let points = if player == requester {
+            return Err(Error::CannotAcceptOwn);
+        } else if player == game.player1 {
+            game.player1_points
+        } else if player == game.player2 {
+            game.player2_points
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let rematch_id = session::claim(&env, 0)?;
+        deposit_stake(&env, &game.escrow_token, &player, points)?;
+
+        let (player1, player2, player1_points, player2_points) = if game.rematch_swap {
+            (game.player2.clone(), game.player1.clone(), game.player2_points, game.player1_points)
+        } else {
+            (game.player1.clone(), game.player2.clone(), game.player1_points, game.player2_points)
+        };
+        create_game(
+            &env,
+            rematch_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            game.best_of,
+            game.draw_policy,
+            game.escrow_token.clone(),
+            game.rake_bps,
+            false,
+            Some(session_id),
+        )?;
+
+        game.rematch_offer = None;
+        game.rematch_swap = false;
+        game.rematch = Some(rematch_id);
+
+        RematchStarted {
+            session_id,
+            rematch_session_id: rematch_id,
+        }
+        .publish(&env);
+
+        session::save(&env, session_id, &game);
+        Ok(rematch_id)
+    }
+
+    // -------------------------------------------------------- cancel_rematch
+
+    /// Withdraw a pending rematch offer, refunding an escrowed stake.
+    pub fn cancel_rematch(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        transition(game.phase, Action::CancelRematch)?;
+        let requester = game.rematch_offer.take().ok_or(Error::NoRematchOffer)?;
+        requester.require_auth();
+        game.rematch_swap = false;
+
+        if let Some(token_addr) = &game.escrow_token {
+            let points = if requester == game.player1 {
+                game.player1_points
             } else {
-                game.player2.clone()
+                game.player2_points
             };
-            game.winner = Some(winner);
-            game.phase = 5;
-
-            // Report outcome to Game Hub
-            let hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let hub = GameHubClient::new(&env, &hub_addr);
-            hub.end_game(&session_id, &player1_won);
+            if points > 0 {
+                let contract = env.current_contract_address();
+                token::TokenClient::new(&env, token_addr).transfer(&contract, &requester, &points);
+            }
         }
 
-        save_game(&env, session_id, &game);
+        session::save(&env, session_id, &game);
         Ok(())
     }
 
@@ -512,62 +1530,180 @@ impl CtmContract {
 
     /// Read the current game state.
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+        session::load(&env, session_id).ok_or(Error::GameNotFound)
     }
 
-    // ============================================================ Admin fns
+    // ------------------------------------------------------------ get_result
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+    /// Read whether a game is complete and who won, without the full state.
+    pub fn get_result(env: Env, session_id: u32) -> Result<GameResult, Error> {
+        let game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(GameResult {
+            complete: game.phase == Phase::Complete,
+            winner: game.winner,
+        })
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    // --------------------------------------------------------- get_game_view
+
+    /// Read the game as `viewer` may see it, without commitment hashes or
+    /// the opponent's undisclosed hands.  Any address can view; only the
+    /// players see their own seat unredacted.
+    pub fn get_game_view(env: Env, session_id: u32, viewer: Address) -> Result<GameView, Error> {
+        let game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(GameView {
+            session_id,
+            phase: game.phase,
+            player1: seat_view(&game, true, &viewer),
+            player2: seat_view(&game, false, &viewer),
+            winner: game.winner,
+            deadline: game.deadline,
+            best_of: game.best_of,
+            round: game.round,
+            history: game.history,
+            draw_policy: game.draw_policy,
+            escrow_token: game.escrow_token,
+            rake_bps: game.rake_bps,
+            lobby: game.lobby,
+            rematch_of: game.rematch_of,
+            rematch: game.rematch,
+            rematch_offer: game.rematch_offer,
+            rematch_swap: game.rematch_swap,
+        })
     }
 
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    // ------------------------------------------------------------- get_games
+
+    /// Read up to `MAX_PAGE_SIZE` games at once, keyed by session ID.
+    /// Unknown or expired sessions are left out of the map.
+    pub fn get_games(env: Env, session_ids: Vec<u32>) -> Result<Map<u32, Game>, Error> {
+        if session_ids.len() > MAX_PAGE_SIZE {
+            return Err(Error::InvalidPageSize);
+        }
+        let mut games = Map::new(&env);
+        for session_id in session_ids.iter() {
+            if let Some(game) = session::load::<Game>(&env, session_id) {
+                games.set(session_id, game);
+            }
+        }
+        Ok(games)
     }
 
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
+    // ------------------------------------------------------ get_player_games
+
+    /// Page through the sessions `player` has taken part in, newest first.
+    ///
+    /// `cursor` is 0 for the first page and `next_cursor` of the previous
+    /// page after that; `limit` is 1 to `MAX_PAGE_SIZE`.  A page picks up
+    /// where the previous one stopped even if new games were indexed in
+    /// between; sessions dropped from the index meanwhile are skipped.
+    pub fn get_player_games(
+        env: Env,
+        player: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<GamePage, Error> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(Error::InvalidPageSize);
+        }
+        let Some(index) = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .persistent()
+            .get::<_, PlayerIndex>(&DataKey::PlayerGames(player))
+        else {
+            return Ok(GamePage {
+                games: Vec::new(&env),
+                next_cursor: None,
+            });
+        };
+
+        // Positions count from the player's first indexed session, so
+        // `cursor` (the oldest position already returned) stays put as new
+        // sessions are appended and old ones dropped
+        let total = index.dropped + index.sessions.len();
+        let end = if cursor == 0 { total } else { cursor.min(total) };
+        let start = end.saturating_sub(limit).max(index.dropped);
+
+        let mut games = Vec::new(&env);
+        for position in (start..end).rev() {
+            let session_id = index.sessions.get_unchecked(position - index.dropped);
+            if let Some(game) = session::load::<Game>(&env, session_id) {
+                games.push_back((session_id, game));
+            }
+        }
+        Ok(GamePage {
+            games,
+            next_cursor: (start > index.dropped).then_some(start),
+        })
+    }
+
+    // ============================================================ Admin fns
+
+    pub fn get_draw_policy(env: Env) -> DrawPolicy {
+        current_draw_policy(&env)
+    }
+
+    /// Set the draw policy for games started from now on.  Tournament games
+    /// score drawn rounds as `TrueDraw` in place of either replay policy.
+    pub fn set_draw_policy(env: Env, policy: DrawPolicy) {
+        admin::require_admin(&env);
+        env.storage().instance().set(&DataKey::DrawPolicy, &policy);
+    }
+
+    /// Configure the UltraHonk verifier contract and `hands_commit` VK used
+    /// by `commit_hands_with_proof`.
+    pub fn set_hands_verifier(env: Env, verifier: Address, vk: Bytes) {
+        admin::require_admin(&env);
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .set(&DataKey::HandsVerifier, &verifier);
+        env.storage().instance().set(&DataKey::HandsVk, &vk);
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
+    pub fn get_escrow(env: Env) -> Option<(Address, u32)> {
+        let token: Option<Address> = env.storage().instance().get(&DataKey::EscrowToken);
+        token.map(|t| (t, current_rake_bps(&env)))
+    }
+
+    /// Stake real tokens in new games: `token` is a SEP-41 contract and
+    /// `rake_bps` the admin's cut of each pot in basis points.
+    pub fn set_escrow(env: Env, token: Address, rake_bps: u32) -> Result<(), Error> {
+        admin::require_admin(&env);
+        if rake_bps > MAX_RAKE_BPS {
+            return Err(Error::InvalidRake);
+        }
+        env.storage().instance().set(&DataKey::EscrowToken, &token);
+        env.storage().instance().set(&DataKey::RakeBps, &rake_bps);
+        Ok(())
+    }
+
+    /// Return to points-only games.  Games already in escrow still settle.
+    pub fn clear_escrow(env: Env) {
+        admin::require_admin(&env);
+        env.storage().instance().remove(&DataKey::EscrowToken);
+        env.storage().instance().remove(&DataKey::RakeBps);
+    }
+
+    pub fn get_tournament(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Tournament)
+    }
+
+    pub fn set_tournament(env: Env, tournament: Address) {
+        admin::require_admin(&env);
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+            .set(&DataKey::Tournament, &tournament);
     }
 }
 
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for CtmContract {}
+
+// get_lobby / set_lobby
+#[contractimpl(contracttrait)]
+impl Lobby for CtmContract {}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -4,14 +4,29 @@
 //!
 //! Uses a minimal mock GameHub for isolation.
 
-use crate::{CtmContract, CtmContractClient, Error};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use crate::{
+    transition, Action, ChoiceCommitted, ChoiceRevealed, CtmContract, CtmContractClient,
+    DrawPolicy, Error, GamePage, GameResolved, HandsCommitted, HandsRevealed, Phase,
+    RoundOutcome,
+};
+use game_core::CoreKey;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, Event, Vec,
+};
 
 // ============================================================================
 // Mock GameHub
 // ============================================================================
 
+#[contracttype]
+pub enum MockHubKey {
+    Results(u32),
+    Drawn(u32),
+    NextId,
+}
+
 #[contract]
 pub struct MockGameHub;
 
@@ -27,8 +42,119 @@ impl MockGameHub {
         _player2_points: i128,
     ) {
     }
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+    /// Allocates 42, 43, ...
+    pub fn reserve_session_id(env: Env, _game_id: Address) -> u32 {
+        let session_id = env.storage().instance().get(&MockHubKey::NextId).unwrap_or(42);
+        env.storage()
+            .instance()
+            .set(&MockHubKey::NextId, &(session_id + 1));
+        session_id
+    }
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        let key = MockHubKey::Results(session_id);
+        let mut results: Vec<bool> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        results.push_back(player1_won);
+        env.storage().instance().set(&key, &results);
+    }
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        env.storage()
+            .instance()
+            .set(&MockHubKey::Drawn(session_id), &true);
+    }
     pub fn add_game(_env: Env, _game_address: Address) {}
+
+    /// Every `end_game` outcome reported for a session, in call order.
+    pub fn results(env: Env, session_id: u32) -> Vec<bool> {
+        env.storage()
+            .instance()
+            .get(&MockHubKey::Results(session_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Whether `end_game_draw` was reported for a session.
+    pub fn drawn(env: Env, session_id: u32) -> bool {
+        env.storage()
+            .instance()
+            .get(&MockHubKey::Drawn(session_id))
+            .unwrap_or(false)
+    }
+}
+
+// ============================================================================
+// Mock UltraHonk verifier
+// ============================================================================
+
+/// Stand-in for the UltraHonk verifier contract.  A proof is "valid" when it
+/// equals `keccak256(vk || public_inputs)`, which binds it to both the key and
+/// the commitment the same way a real proof does.  The public-input encoding
+/// itself is checked against the circuit's layout with
+/// `RecordingHonkVerifier`.
+#[contract]
+pub struct MockHonkVerifier;
+
+#[contractimpl]
+impl MockHonkVerifier {
+    pub fn verify_proof(env: Env, vk: Bytes, public_inputs: Bytes, proof: Bytes) -> bool {
+        proof == mock_prove(&env, &vk, &public_inputs)
+    }
+}
+
+fn mock_prove(env: &Env, vk: &Bytes, public_inputs: &Bytes) -> Bytes {
+    let mut pre = vk.clone();
+    pre.append(public_inputs);
+    let digest: BytesN<32> = env.crypto().keccak256(&pre).into();
+    Bytes::from_array(env, &digest.to_array())
+}
+
+#[contracttype]
+pub enum RecordingVerifierKey {
+    Vk,
+    PublicInputs,
+}
+
+/// Verifier that accepts every proof and records the key and public inputs
+/// it was called with, so tests can inspect exactly what CTM sends.
+#[contract]
+pub struct RecordingHonkVerifier;
+
+#[contractimpl]
+impl RecordingHonkVerifier {
+    pub fn verify_proof(env: Env, vk: Bytes, public_inputs: Bytes, _proof: Bytes) -> bool {
+        env.storage().instance().set(&RecordingVerifierKey::Vk, &vk);
+        env.storage()
+            .instance()
+            .set(&RecordingVerifierKey::PublicInputs, &public_inputs);
+        true
+    }
+
+    /// The `(vk, public_inputs)` of the last `verify_proof` call.
+    pub fn last_call(env: Env) -> (Bytes, Bytes) {
+        let storage = env.storage().instance();
+        (
+            storage.get(&RecordingVerifierKey::Vk).unwrap(),
+            storage.get(&RecordingVerifierKey::PublicInputs).unwrap(),
+        )
+    }
+}
+
+/// Fixture verification key for the `hands_commit` circuit.
+fn hands_vk(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[7u8; 64])
+}
+
+/// Fixture proof that `hands_hash` opens to two valid, different hands.
+fn hands_proof(env: &Env, vk: &Bytes, hands_hash: &BytesN<32>) -> Bytes {
+    let mut inputs = Bytes::new(env);
+    for byte in hands_hash.to_array() {
+        let mut word = [0u8; 32];
+        word[31] = byte;
+        inputs.append(&Bytes::from_array(env, &word));
+    }
+    mock_prove(env, vk, &inputs)
 }
 
 // ============================================================================
@@ -103,6 +229,11 @@ fn assert_ctm_error<T: core::fmt::Debug, E: core::fmt::Debug>(
     }
 }
 
+/// Advance the ledger sequence by `ledgers`.
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
 /// Fixed salt for deterministic tests.
 fn test_salt(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[42u8; 32])
@@ -118,6 +249,7 @@ fn test_salt2(env: &Env) -> BytesN<32> {
 
 /// Plays through Phase 1+2 (commit & reveal hands) for both players.
 /// Returns (p1_left, p1_right, p2_left, p2_right).
+#[allow(clippy::too_many_arguments)]
 fn play_hands(
     env: &Env,
     client: &CtmContractClient,
@@ -177,13 +309,13 @@ fn test_complete_game_p1_wins() {
     play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
 
     let game = client.get_game(&session);
-    assert_eq!(game.phase, 3); // Now in CommitChoice
+    assert_eq!(game.phase, Phase::CommitChoice); // Now in CommitChoice
 
     // P1 keeps left (Rock=0), P2 keeps left (Scissors=2)
     play_choices(&env, &client, session, &p1, &p2, 0, 0);
 
     let game = client.get_game(&session);
-    assert_eq!(game.phase, 5);
+    assert_eq!(game.phase, Phase::Complete);
     assert_eq!(game.p1_kept, Some(0)); // Rock
     assert_eq!(game.p2_kept, Some(2)); // Scissors
     assert_eq!(game.winner, Some(p1)); // Rock beats Scissors
@@ -204,7 +336,7 @@ fn test_complete_game_p2_wins() {
     play_choices(&env, &client, session, &p1, &p2, 0, 0);
 
     let game = client.get_game(&session);
-    assert_eq!(game.phase, 5);
+    assert_eq!(game.phase, Phase::Complete);
     assert_eq!(game.p1_kept, Some(0)); // Rock
     assert_eq!(game.p2_kept, Some(1)); // Paper
     assert_eq!(game.winner, Some(p2)); // Paper beats Rock
@@ -225,7 +357,7 @@ fn test_draw_p1_wins_tiebreak() {
     play_choices(&env, &client, session, &p1, &p2, 0, 0);
 
     let game = client.get_game(&session);
-    assert_eq!(game.phase, 5);
+    assert_eq!(game.phase, Phase::Complete);
     assert_eq!(game.p1_kept, Some(0));
     assert_eq!(game.p2_kept, Some(0));
     assert_eq!(game.winner, Some(p1));
@@ -363,6 +495,70 @@ fn test_not_player_rejected() {
     assert_ctm_error(&result, Error::NotPlayer);
 }
 
+#[test]
+fn test_self_play_rejected() {
+    let (env, client, _hub, p1, _p2) = setup_test();
+    client.set_lobby(&Address::generate(&env));
+
+    let result = client.try_start_game(&16, &p1, &p1, &100, &100);
+    assert_ctm_error(&result, Error::SelfPlay);
+    let result = client.try_start_match(&16, &p1, &p1, &100, &100, &3);
+    assert_ctm_error(&result, Error::SelfPlay);
+    let result = client.try_start_lobby_game(&16, &p1, &p1);
+    assert_ctm_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_session_exists_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    client.set_lobby(&Address::generate(&env));
+
+    client.start_game(&19, &p1, &p2, &100, &100);
+    let result = client.try_start_game(&19, &p2, &p1, &100, &100);
+    assert_ctm_error(&result, Error::SessionExists);
+    let result = client.try_start_match(&19, &p2, &p1, &100, &100, &3);
+    assert_ctm_error(&result, Error::SessionExists);
+    let result = client.try_start_lobby_game(&19, &p2, &p1);
+    assert_ctm_error(&result, Error::SessionExists);
+    assert_eq!(client.get_game(&19).player1, p1);
+}
+
+#[test]
+fn test_session_id_allocated_by_hub() {
+    let (_env, client, _hub, p1, p2) = setup_test();
+
+    let session = client.start_match(&0, &p1, &p2, &100, &100, &3);
+    assert_eq!(session, 42);
+    let game = client.get_game(&42);
+    assert_eq!(game.player1, p1);
+    assert_eq!(game.best_of, 3);
+}
+
+#[test]
+fn test_hub_not_set() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 17u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 1, 2);
+    let salt = test_salt2(&env);
+    client.commit_choice(&session, &p1, &compute_choice_hash(&env, 0, &salt));
+    client.commit_choice(&session, &p2, &compute_choice_hash(&env, 0, &salt));
+    client.reveal_choice(&session, &p1, &0, &salt);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CoreKey::GameHubAddress);
+    });
+
+    let result = client.try_start_game(&18, &p1, &p2, &100_0000000, &100_0000000);
+    assert_ctm_error(&result, Error::HubNotSet);
+
+    // Resolving the game cannot report it, so nothing is recorded
+    let result = client.try_reveal_choice(&session, &p2, &0, &salt);
+    assert_ctm_error(&result, Error::HubNotSet);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealChoice);
+}
+
 #[test]
 fn test_invalid_choice_rejected() {
     let (env, client, _hub, p1, p2) = setup_test();
@@ -435,7 +631,7 @@ fn test_phase_transitions() {
     let session = 200u32;
 
     client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
-    assert_eq!(client.get_game(&session).phase, 1);
+    assert_eq!(client.get_game(&session).phase, Phase::CommitHands);
 
     let salt = test_salt(&env);
     let h1 = compute_hands_hash(&env, 0, 1, &salt);
@@ -443,19 +639,19 @@ fn test_phase_transitions() {
 
     // One commit → still phase 1
     client.commit_hands(&session, &p1, &h1);
-    assert_eq!(client.get_game(&session).phase, 1);
+    assert_eq!(client.get_game(&session).phase, Phase::CommitHands);
 
     // Both commit → phase 2
     client.commit_hands(&session, &p2, &h2);
-    assert_eq!(client.get_game(&session).phase, 2);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealHands);
 
     // One reveal → still phase 2
     client.reveal_hands(&session, &p1, &0, &1, &salt);
-    assert_eq!(client.get_game(&session).phase, 2);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealHands);
 
     // Both reveal → phase 3
     client.reveal_hands(&session, &p2, &1, &2, &salt);
-    assert_eq!(client.get_game(&session).phase, 3);
+    assert_eq!(client.get_game(&session).phase, Phase::CommitChoice);
 
     let salt2 = test_salt2(&env);
     let c1 = compute_choice_hash(&env, 0, &salt2);
@@ -463,17 +659,1392 @@ fn test_phase_transitions() {
 
     // One choice commit → still phase 3
     client.commit_choice(&session, &p1, &c1);
-    assert_eq!(client.get_game(&session).phase, 3);
+    assert_eq!(client.get_game(&session).phase, Phase::CommitChoice);
 
     // Both choice commit → phase 4
     client.commit_choice(&session, &p2, &c2);
-    assert_eq!(client.get_game(&session).phase, 4);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealChoice);
 
     // One reveal choice → still phase 4
     client.reveal_choice(&session, &p1, &0, &salt2);
-    assert_eq!(client.get_game(&session).phase, 4);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealChoice);
 
     // Both reveal → phase 5 (complete)
     client.reveal_choice(&session, &p2, &1, &salt2);
-    assert_eq!(client.get_game(&session).phase, 5);
+    assert_eq!(client.get_game(&session).phase, Phase::Complete);
+}
+
+// ============================================================================
+// Timeouts
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_after_stalled_hand_reveal() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 300u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    let h2 = compute_hands_hash(&env, 1, 2, &salt);
+    client.commit_hands(&session, &p1, &h1);
+    client.commit_hands(&session, &p2, &h2);
+
+    // P2 reveals, P1 sees the hands and walks away
+    client.reveal_hands(&session, &p2, &1, &2, &salt);
+
+    let deadline = client.get_game(&session).deadline;
+    advance_ledgers(&env, deadline - env.ledger().sequence() + 1);
+
+    let winner = client.claim_timeout(&session, &p2);
+    assert_eq!(winner, p2);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, Some(p2));
+}
+
+#[test]
+fn test_claim_timeout_before_deadline_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 301u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    client.commit_hands(&session, &p1, &h1);
+
+    // Exactly at the deadline is still inside the window
+    let deadline = client.get_game(&session).deadline;
+    advance_ledgers(&env, deadline - env.ledger().sequence());
+
+    let result = client.try_claim_timeout(&session, &p1);
+    assert_ctm_error(&result, Error::DeadlineNotReached);
+}
+
+#[test]
+fn test_claim_timeout_requires_claimant_to_have_acted() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 302u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    client.commit_hands(&session, &p1, &h1);
+
+    advance_ledgers(&env, 20_000);
+
+    // P2 is the one stalling and cannot profit from it
+    let result = client.try_claim_timeout(&session, &p2);
+    assert_ctm_error(&result, Error::CannotClaimTimeout);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_claim_timeout(&session, &outsider);
+    assert_ctm_error(&result, Error::NotPlayer);
+
+    // P1 did act and can claim
+    assert_eq!(client.claim_timeout(&session, &p1), p1);
+}
+
+#[test]
+fn test_claim_timeout_when_nobody_acted_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 303u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    advance_ledgers(&env, 20_000);
+
+    let result = client.try_claim_timeout(&session, &p1);
+    assert_ctm_error(&result, Error::CannotClaimTimeout);
+}
+
+#[test]
+fn test_deadline_resets_on_phase_advance() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 304u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    let first_deadline = client.get_game(&session).deadline;
+    assert!(first_deadline > env.ledger().sequence());
+
+    advance_ledgers(&env, 1_000);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 1, 2);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitChoice);
+    assert_eq!(game.deadline, first_deadline + 1_000);
+}
+
+#[test]
+fn test_claim_timeout_after_game_ended_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 305u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    advance_ledgers(&env, 20_000);
+
+    let result = client.try_claim_timeout(&session, &p1);
+    assert_ctm_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Match mode
+// ============================================================================
+
+#[test]
+fn test_single_game_is_best_of_one() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 400u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    let game = client.get_game(&session);
+    assert_eq!(game.best_of, 1);
+    assert_eq!(game.round, 1);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.p1_wins, 1);
+    assert_eq!(game.history.len(), 1);
+    assert_eq!(hub.results(&session), vec![&env, true]);
+}
+
+#[test]
+fn test_best_of_three_sweep() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 401u32;
+
+    client.start_match(&session, &p1, &p2, &100_0000000, &100_0000000, &3);
+
+    // Round 1: Rock vs Scissors → P1
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitHands);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.p1_wins, 1);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.p1_commit, None);
+    assert_eq!(game.p1_kept, None);
+    assert_eq!(hub.results(&session).len(), 0);
+
+    // Round 2: Paper vs Rock → P1 takes the match
+    play_hands(&env, &client, session, &p1, &p2, 1, 2, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.p1_wins, 2);
+    assert_eq!(game.p2_wins, 0);
+    assert_eq!(game.winner, Some(p1));
+    assert_eq!(hub.results(&session), vec![&env, true]);
+}
+
+#[test]
+fn test_best_of_three_comeback_with_history() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 402u32;
+
+    client.start_match(&session, &p1, &p2, &100_0000000, &100_0000000, &3);
+
+    // Round 1: Rock vs Scissors → P1
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // Round 2: Rock vs Paper → P2
+    play_hands(&env, &client, session, &p1, &p2, 0, 2, 1, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // Round 3: Scissors vs Rock → P2 takes the match
+    play_hands(&env, &client, session, &p1, &p2, 0, 2, 1, 0);
+    play_choices(&env, &client, session, &p1, &p2, 1, 1);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.round, 3);
+    assert_eq!(game.p1_wins, 1);
+    assert_eq!(game.p2_wins, 2);
+    assert_eq!(game.winner, Some(p2));
+
+    assert_eq!(game.history.len(), 3);
+    let r1 = game.history.get(0).unwrap();
+    assert_eq!((r1.p1_kept, r1.p2_kept, r1.outcome), (0, 2, RoundOutcome::Player1Won));
+    let r2 = game.history.get(1).unwrap();
+    assert_eq!((r2.p1_kept, r2.p2_kept, r2.outcome), (0, 1, RoundOutcome::Player2Won));
+    let r3 = game.history.get(2).unwrap();
+    assert_eq!((r3.p1_left, r3.p1_right), (0, 2));
+    assert_eq!((r3.p1_kept, r3.p2_kept, r3.outcome), (2, 0, RoundOutcome::Player2Won));
+
+    // Only the final match result reaches the hub
+    assert_eq!(hub.results(&session), vec![&env, false]);
+}
+
+#[test]
+fn test_invalid_best_of_rejected() {
+    let (_env, client, _hub, p1, p2) = setup_test();
+
+    for (i, best_of) in [0u32, 2, 4, 9].iter().enumerate() {
+        let session = 410 + i as u32;
+        let result = client.try_start_match(&session, &p1, &p2, &100_0000000, &100_0000000, best_of);
+        assert_ctm_error(&result, Error::InvalidRounds);
+    }
+}
+
+#[test]
+fn test_match_round_reopens_commit_phase() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 420u32;
+
+    client.start_match(&session, &p1, &p2, &100_0000000, &100_0000000, &5);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // Same salt may be reused across rounds since commitments are cleared
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    client.commit_hands(&session, &p1, &h1);
+    assert_eq!(client.get_game(&session).p1_commit, Some(h1));
+
+    // Choice-phase calls are rejected until the new round reaches them
+    let c = compute_choice_hash(&env, 0, &test_salt2(&env));
+    let result = client.try_commit_choice(&session, &p2, &c);
+    assert_ctm_error(&result, Error::WrongPhase);
+}
+
+// ============================================================================
+// Draw policies
+// ============================================================================
+
+#[test]
+fn test_draw_policy_defaults_to_player1_wins() {
+    let (_env, client, _hub, _p1, _p2) = setup_test();
+    assert_eq!(client.get_draw_policy(), DrawPolicy::Player1Wins);
+}
+
+#[test]
+fn test_draw_replay_choice_keeps_hands() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 500u32;
+
+    client.set_draw_policy(&DrawPolicy::ReplayChoice);
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    // P1: Rock(0) + Paper(1), P2: Rock(0) + Scissors(2)
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+
+    // Rock vs Rock → back to choice phase with the same hands
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitChoice);
+    assert_eq!(game.round, 1);
+    assert_eq!((game.p1_left, game.p1_right), (Some(0), Some(1)));
+    assert_eq!((game.p2_left, game.p2_right), (Some(0), Some(2)));
+    assert_eq!(game.p1_choice_commit, None);
+    assert_eq!(game.p1_kept, None);
+    assert_eq!(game.history.len(), 0);
+    assert_eq!(hub.results(&session).len(), 0);
+
+    // Paper vs Rock → P1
+    play_choices(&env, &client, session, &p1, &p2, 1, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, Some(p1));
+    assert_eq!(hub.results(&session), vec![&env, true]);
+}
+
+#[test]
+fn test_draw_replay_round_clears_hands() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 501u32;
+
+    client.set_draw_policy(&DrawPolicy::ReplayRound);
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitHands);
+    assert_eq!(game.round, 1);
+    assert_eq!(game.p1_commit, None);
+    assert_eq!(game.p1_left, None);
+    assert_eq!(game.p2_kept, None);
+    assert_eq!(game.history.len(), 0);
+
+    // Replayed round: Scissors vs Rock → P2
+    play_hands(&env, &client, session, &p1, &p2, 2, 1, 0, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, Some(p2));
+    assert_eq!(hub.results(&session), vec![&env, false]);
+}
+
+#[test]
+fn test_true_draw_reported_to_hub() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 502u32;
+
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.history.get(0).unwrap().outcome, RoundOutcome::Draw);
+    assert!(hub.drawn(&session));
+    assert_eq!(hub.results(&session).len(), 0);
+}
+
+#[test]
+fn test_true_draw_match_tied_after_all_rounds() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 503u32;
+
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+    client.start_match(&session, &p1, &p2, &100_0000000, &100_0000000, &3);
+
+    // Round 1: Rock vs Rock → draw
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    // Round 2: Rock vs Scissors → P1
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    // Round 3: Rock vs Paper → P2
+    play_hands(&env, &client, session, &p1, &p2, 0, 2, 1, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!((game.p1_wins, game.p2_wins), (1, 1));
+    assert_eq!(game.history.len(), 3);
+    assert_eq!(game.winner, None);
+    assert!(hub.drawn(&session));
+}
+
+#[test]
+fn test_true_draw_match_decided_on_tally() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 504u32;
+
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+    client.start_match(&session, &p1, &p2, &100_0000000, &100_0000000, &3);
+
+    // Draw, P1, draw → P1 wins 1-0 once the rounds run out
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    play_hands(&env, &client, session, &p1, &p2, 1, 2, 1, 0);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!((game.p1_wins, game.p2_wins), (1, 0));
+    assert_eq!(game.winner, Some(p1));
+    assert!(!hub.drawn(&session));
+    assert_eq!(hub.results(&session), vec![&env, true]);
+}
+
+#[test]
+fn test_draw_policy_frozen_at_game_start() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 505u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // Game started under the legacy tiebreak keeps it
+    let game = client.get_game(&session);
+    assert_eq!(game.draw_policy, DrawPolicy::Player1Wins);
+    assert_eq!(game.winner, Some(p1));
+}
+
+// ============================================================================
+// Proof-checked hand commitments
+// ============================================================================
+
+fn setup_verifier(env: &Env, client: &CtmContractClient) -> Bytes {
+    let verifier = env.register(MockHonkVerifier, ());
+    let vk = hands_vk(env);
+    client.set_hands_verifier(&verifier, &vk);
+    vk
+}
+
+#[test]
+fn test_hands_public_inputs_match_circuit_fixture() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let verifier = env.register(RecordingHonkVerifier, ());
+    let vk = hands_vk(&env);
+    client.set_hands_verifier(&verifier, &vk);
+    client.start_game(&599, &p1, &p2, &100_0000000, &100_0000000);
+
+    // The Prover.toml witness: Rock + Scissors under the test salt
+    let commitment = compute_hands_hash(&env, 0, 2, &test_salt(&env));
+    client.commit_hands_with_proof(&599, &p1, &commitment, &Bytes::new(&env));
+
+    // Derived from the circuit's public-input layout, not produced by bb:
+    // `gen_fixtures.sh` overwrites it with bb's output for the same witness
+    let fixture = Bytes::from_slice(
+        &env,
+        include_bytes!("../noir/hands_commit/fixtures/public_inputs"),
+    );
+    let (sent_vk, sent_inputs) = RecordingHonkVerifierClient::new(&env, &verifier).last_call();
+    assert_eq!(sent_vk, vk);
+    assert_eq!(sent_inputs, fixture);
+}
+
+#[test]
+fn test_commit_hands_with_valid_proof() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 600u32;
+    let vk = setup_verifier(&env, &client);
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    let h2 = compute_hands_hash(&env, 1, 2, &salt);
+    client.commit_hands_with_proof(&session, &p1, &h1, &hands_proof(&env, &vk, &h1));
+    client.commit_hands_with_proof(&session, &p2, &h2, &hands_proof(&env, &vk, &h2));
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::RevealHands);
+    assert_eq!(game.p1_commit, Some(h1));
+
+    // Proven commitments reveal like any other
+    client.reveal_hands(&session, &p1, &0, &1, &salt);
+    client.reveal_hands(&session, &p2, &1, &2, &salt);
+    assert_eq!(client.get_game(&session).phase, Phase::CommitChoice);
+}
+
+#[test]
+fn test_commit_hands_with_proof_for_other_hash_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 601u32;
+    let vk = setup_verifier(&env, &client);
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let valid = compute_hands_hash(&env, 0, 1, &salt);
+    let same_hands = compute_hands_hash(&env, 1, 1, &salt);
+
+    // A proof for one commitment cannot be replayed for a garbage one
+    let proof = hands_proof(&env, &vk, &valid);
+    let result = client.try_commit_hands_with_proof(&session, &p1, &same_hands, &proof);
+    assert_ctm_error(&result, Error::InvalidProof);
+    assert_eq!(client.get_game(&session).p1_commit, None);
+}
+
+#[test]
+fn test_commit_hands_with_proof_under_wrong_vk_rejected() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 602u32;
+    setup_verifier(&env, &client);
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let h1 = compute_hands_hash(&env, 0, 1, &test_salt(&env));
+    let other_vk = Bytes::from_array(&env, &[8u8; 64]);
+    let proof = hands_proof(&env, &other_vk, &h1);
+
+    let result = client.try_commit_hands_with_proof(&session, &p1, &h1, &proof);
+    assert_ctm_error(&result, Error::InvalidProof);
+}
+
+#[test]
+fn test_commit_hands_with_proof_requires_config() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 603u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let h1 = compute_hands_hash(&env, 0, 1, &test_salt(&env));
+    let proof = hands_proof(&env, &hands_vk(&env), &h1);
+
+    let result = client.try_commit_hands_with_proof(&session, &p1, &h1, &proof);
+    assert_ctm_error(&result, Error::ProofNotConfigured);
+}
+
+#[test]
+fn test_commit_hands_with_proof_still_checks_phase() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 604u32;
+    let vk = setup_verifier(&env, &client);
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let h1 = compute_hands_hash(&env, 0, 1, &test_salt(&env));
+    let proof = hands_proof(&env, &vk, &h1);
+    client.commit_hands_with_proof(&session, &p1, &h1, &proof);
+
+    let result = client.try_commit_hands_with_proof(&session, &p1, &h1, &proof);
+    assert_ctm_error(&result, Error::AlreadyCommitted);
+}
+
+// ============================================================================
+// Token escrow
+// ============================================================================
+
+/// Register a Stellar Asset Contract as the stake token, fund both players
+/// with 1 000 units each and enable escrow with the given rake.
+fn setup_escrow(
+    env: &Env,
+    client: &CtmContractClient,
+    p1: &Address,
+    p2: &Address,
+    rake_bps: u32,
+) -> TokenClient<'static> {
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let asset = StellarAssetClient::new(env, &sac.address());
+    asset.mint(p1, &1_000);
+    asset.mint(p2, &1_000);
+    client.set_escrow(&sac.address(), &rake_bps);
+    TokenClient::new(env, &sac.address())
+}
+
+#[test]
+fn test_escrow_pays_winner() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 700u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 0);
+
+    client.start_game(&session, &p1, &p2, &100, &100);
+    assert_eq!(token.balance(&p1), 900);
+    assert_eq!(token.balance(&p2), 900);
+    assert_eq!(token.balance(&client.address), 200);
+
+    // Rock vs Scissors → P1
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    assert_eq!(token.balance(&p1), 1_100);
+    assert_eq!(token.balance(&p2), 900);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_escrow_rake_goes_to_admin() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 701u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 250); // 2.5 %
+
+    client.start_game(&session, &p1, &p2, &200, &200);
+
+    // Rock vs Paper → P2
+    play_hands(&env, &client, session, &p1, &p2, 0, 2, 1, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    assert_eq!(token.balance(&p1), 800);
+    assert_eq!(token.balance(&p2), 1_190);
+    assert_eq!(token.balance(&client.get_admin()), 10);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_escrow_pays_timeout_claimant() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 702u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 0);
+
+    client.start_game(&session, &p1, &p2, &100, &300);
+
+    let h2 = compute_hands_hash(&env, 1, 2, &test_salt(&env));
+    client.commit_hands(&session, &p2, &h2);
+    advance_ledgers(&env, 20_000);
+    client.claim_timeout(&session, &p2);
+
+    assert_eq!(token.balance(&p1), 900);
+    assert_eq!(token.balance(&p2), 1_100);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_escrow_true_draw_refunds_stakes() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 703u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 500);
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+
+    client.start_game(&session, &p1, &p2, &100, &150);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    assert_eq!(token.balance(&p1), 1_000);
+    assert_eq!(token.balance(&p2), 1_000);
+    assert_eq!(token.balance(&client.get_admin()), 0);
+}
+
+#[test]
+fn test_escrow_config_validation() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    assert_eq!(client.get_escrow(), None);
+
+    let token = Address::generate(&env);
+    let result = client.try_set_escrow(&token, &10_001);
+    assert_ctm_error(&result, Error::InvalidRake);
+
+    let stake = setup_escrow(&env, &client, &p1, &p2, 100);
+    assert_eq!(client.get_escrow(), Some((stake.address.clone(), 100)));
+
+    let result = client.try_start_game(&704, &p1, &p2, &-1, &100);
+    assert_ctm_error(&result, Error::InvalidStake);
+
+    client.clear_escrow();
+    assert_eq!(client.get_escrow(), None);
+
+    // Points-only games move no tokens
+    client.start_game(&705, &p1, &p2, &100, &100);
+    assert_eq!(stake.balance(&p1), 1_000);
+    assert_eq!(client.get_game(&705).escrow_token, None);
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[test]
+fn test_events_for_each_phase() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 800u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    let salt = test_salt(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    let h2 = compute_hands_hash(&env, 2, 1, &salt);
+
+    client.commit_hands(&session, &p1, &h1);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [HandsCommitted {
+            session_id: session,
+            player: p1.clone(),
+            hands_hash: h1.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    client.commit_hands(&session, &p2, &h2);
+
+    client.reveal_hands(&session, &p1, &0, &1, &salt);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [HandsRevealed {
+            session_id: session,
+            player: p1.clone(),
+            left_hand: 0,
+            right_hand: 1,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    client.reveal_hands(&session, &p2, &2, &1, &salt);
+
+    let salt2 = test_salt2(&env);
+    let c1 = compute_choice_hash(&env, 0, &salt2);
+    let c2 = compute_choice_hash(&env, 0, &salt2);
+
+    client.commit_choice(&session, &p1, &c1);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [ChoiceCommitted {
+            session_id: session,
+            player: p1.clone(),
+            choice_hash: c1.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    client.commit_choice(&session, &p2, &c2);
+
+    client.reveal_choice(&session, &p1, &0, &salt2);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [ChoiceRevealed {
+            session_id: session,
+            player: p1.clone(),
+            kept_hand: 0,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+
+    // Final reveal resolves the game: Rock vs Scissors → P1
+    client.reveal_choice(&session, &p2, &0, &salt2);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            ChoiceRevealed {
+                session_id: session,
+                player: p2.clone(),
+                kept_hand: 2,
+            }
+            .to_xdr(&env, &client.address),
+            GameResolved {
+                session_id: session,
+                winner: Some(p1.clone()),
+                p1_wins: 1,
+                p2_wins: 0,
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+}
+
+#[test]
+fn test_event_game_resolved_on_timeout() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 801u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    let h2 = compute_hands_hash(&env, 1, 2, &test_salt(&env));
+    client.commit_hands(&session, &p2, &h2);
+    advance_ledgers(&env, 20_000);
+
+    client.claim_timeout(&session, &p2);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [GameResolved {
+            session_id: session,
+            winner: Some(p2.clone()),
+            p1_wins: 0,
+            p2_wins: 0,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_event_game_resolved_on_true_draw() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 802u32;
+
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let events = env.events().all().filter_by_contract(&client.address);
+    let last = events.events().last().unwrap().clone();
+    assert_eq!(
+        last,
+        GameResolved {
+            session_id: session,
+            winner: None,
+            p1_wins: 0,
+            p2_wins: 0,
+        }
+        .to_xdr(&env, &client.address)
+    );
+}
+
+// ============================================================================
+// State machine
+// ============================================================================
+
+#[test]
+fn test_transition_table_exhaustive() {
+    let phases = [
+        Phase::CommitHands,
+        Phase::RevealHands,
+        Phase::CommitChoice,
+        Phase::RevealChoice,
+        Phase::Complete,
+    ];
+    let actions = [
+        Action::CommitHands,
+        Action::RevealHands,
+        Action::CommitChoice,
+        Action::RevealChoice,
+        Action::ClaimTimeout,
+        Action::RequestRematch,
+        Action::AcceptRematch,
+        Action::CancelRematch,
+    ];
+
+    for phase in phases {
+        for action in actions {
+            let expected = match (phase, action) {
+                (Phase::CommitHands, Action::CommitHands) => Ok(Phase::RevealHands),
+                (Phase::RevealHands, Action::RevealHands) => Ok(Phase::CommitChoice),
+                (Phase::CommitChoice, Action::CommitChoice) => Ok(Phase::RevealChoice),
+                (Phase::RevealChoice, Action::RevealChoice) => Ok(Phase::Complete),
+                (Phase::Complete, Action::ClaimTimeout) => Err(Error::GameAlreadyEnded),
+                (_, Action::ClaimTimeout) => Ok(Phase::Complete),
+                (Phase::Complete, Action::RequestRematch) => Ok(Phase::Complete),
+                (Phase::Complete, Action::AcceptRematch) => Ok(Phase::Complete),
+                (Phase::Complete, Action::CancelRematch) => Ok(Phase::Complete),
+                _ => Err(Error::WrongPhase),
+            };
+            assert_eq!(
+                transition(phase, action),
+                expected,
+                "{:?} in {:?}",
+                action,
+                phase
+            );
+        }
+    }
+}
+
+#[test]
+fn test_every_action_rejected_after_completion() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 900u32;
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    assert_eq!(client.get_game(&session).phase, Phase::Complete);
+
+    let salt = test_salt(&env);
+    let h = compute_hands_hash(&env, 0, 1, &salt);
+    let c = compute_choice_hash(&env, 0, &salt);
+    assert_ctm_error(&client.try_commit_hands(&session, &p1, &h), Error::WrongPhase);
+    assert_ctm_error(
+        &client.try_reveal_hands(&session, &p1, &0, &1, &salt),
+        Error::WrongPhase,
+    );
+    assert_ctm_error(&client.try_commit_choice(&session, &p1, &c), Error::WrongPhase);
+    assert_ctm_error(
+        &client.try_reveal_choice(&session, &p1, &0, &salt),
+        Error::WrongPhase,
+    );
+    assert_ctm_error(&client.try_claim_timeout(&session, &p1), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_out_of_order_actions_rejected_in_each_phase() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 901u32;
+
+    let salt = test_salt(&env);
+    let salt2 = test_salt2(&env);
+    let h1 = compute_hands_hash(&env, 0, 1, &salt);
+    let h2 = compute_hands_hash(&env, 2, 1, &salt);
+    let c = compute_choice_hash(&env, 0, &salt2);
+
+    client.start_game(&session, &p1, &p2, &100_0000000, &100_0000000);
+
+    // CommitHands: only commit_hands is legal
+    assert_ctm_error(&client.try_commit_choice(&session, &p1, &c), Error::WrongPhase);
+    assert_ctm_error(
+        &client.try_reveal_choice(&session, &p1, &0, &salt2),
+        Error::WrongPhase,
+    );
+    client.commit_hands(&session, &p1, &h1);
+    client.commit_hands(&session, &p2, &h2);
+
+    // RevealHands
+    assert_ctm_error(&client.try_commit_hands(&session, &p1, &h1), Error::WrongPhase);
+    assert_ctm_error(&client.try_commit_choice(&session, &p1, &c), Error::WrongPhase);
+    client.reveal_hands(&session, &p1, &0, &1, &salt);
+    client.reveal_hands(&session, &p2, &2, &1, &salt);
+
+    // CommitChoice
+    assert_ctm_error(
+        &client.try_reveal_hands(&session, &p1, &0, &1, &salt),
+        Error::WrongPhase,
+    );
+    assert_ctm_error(
+        &client.try_reveal_choice(&session, &p1, &0, &salt2),
+        Error::WrongPhase,
+    );
+    client.commit_choice(&session, &p1, &c);
+    client.commit_choice(&session, &p2, &c);
+
+    // RevealChoice
+    assert_ctm_error(&client.try_commit_choice(&session, &p1, &c), Error::WrongPhase);
+    assert_ctm_error(&client.try_commit_hands(&session, &p1, &h1), Error::WrongPhase);
+    assert_eq!(client.get_game(&session).phase, Phase::RevealChoice);
+}
+
+// ============================================================================
+// Lobby
+// ============================================================================
+
+#[test]
+fn test_lobby_starts_game_without_player_auth() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1000u32;
+    let lobby = Address::generate(&env);
+    client.set_lobby(&lobby);
+    assert_eq!(client.get_lobby(), Some(lobby.clone()));
+
+    client.start_lobby_game(&session, &p1, &p2);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, lobby);
+
+    // The lobby holds the stakes, so no points are locked
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitHands);
+    assert_eq!(game.best_of, 1);
+    assert_eq!(game.player1_points, 0);
+}
+
+#[test]
+fn test_lobby_game_skips_escrow() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1001u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 0);
+    client.set_lobby(&Address::generate(&env));
+
+    client.start_lobby_game(&session, &p1, &p2);
+    assert_eq!(token.balance(&p1), 1_000);
+    assert_eq!(client.get_game(&session).escrow_token, None);
+}
+
+#[test]
+fn test_lobby_game_requires_lobby() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1002u32;
+
+    // No lobby registered
+    let result = client.try_start_lobby_game(&session, &p1, &p2);
+    assert_ctm_error(&result, Error::LobbyNotSet);
+
+    // Registered, but the lobby did not authorize the call
+    client.set_lobby(&Address::generate(&env));
+    env.set_auths(&[]);
+    assert!(client.try_start_lobby_game(&session, &p1, &p2).is_err());
+}
+
+// ============================================================================
+// Tournament
+// ============================================================================
+
+#[test]
+fn test_tournament_starts_game_with_allocated_id() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let tournament = Address::generate(&env);
+    client.set_tournament(&tournament);
+    assert_eq!(client.get_tournament(), Some(tournament.clone()));
+
+    let session = client.start_tournament_game(&p1, &p2);
+    assert_eq!(session, 42);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, tournament);
+
+    let game = client.get_game(&session);
+    assert_eq!(game.phase, Phase::CommitHands);
+    assert_eq!(game.best_of, 1);
+    assert_eq!(game.player1_points, 0);
+    assert_eq!(game.escrow_token, None);
+    assert_eq!(client.start_tournament_game(&p2, &p1), 43);
+}
+
+#[test]
+fn test_tournament_game_never_replays_draws() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    client.set_tournament(&Address::generate(&env));
+
+    // Replays could run past the tournament's match deadline
+    for policy in [DrawPolicy::ReplayChoice, DrawPolicy::ReplayRound] {
+        client.set_draw_policy(&policy);
+        let session = client.start_tournament_game(&p1, &p2);
+        assert_eq!(client.get_game(&session).draw_policy, DrawPolicy::TrueDraw);
+    }
+
+    client.set_draw_policy(&DrawPolicy::Player1Wins);
+    let session = client.start_tournament_game(&p1, &p2);
+    assert_eq!(client.get_game(&session).draw_policy, DrawPolicy::Player1Wins);
+}
+
+#[test]
+fn test_tournament_game_requires_tournament() {
+    let (env, client, _hub, p1, p2) = setup_test();
+
+    let result = client.try_start_tournament_game(&p1, &p2);
+    assert_ctm_error(&result, Error::TournamentNotSet);
+
+    client.set_tournament(&Address::generate(&env));
+    let result = client.try_start_tournament_game(&p1, &p1);
+    assert_ctm_error(&result, Error::SelfPlay);
+    env.set_auths(&[]);
+    assert!(client.try_start_tournament_game(&p1, &p2).is_err());
+}
+
+// ============================================================================
+// Rematch
+// ============================================================================
+
+#[test]
+fn test_rematch_swaps_seats_and_links_chain() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 1100u32;
+
+    client.start_match(&session, &p1, &p2, &100, &200, &3);
+    for _ in 0..2 {
+        // Rock vs Scissors → P1
+        play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+        play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    }
+    assert_eq!(client.get_game(&session).phase, Phase::Complete);
+
+    client.request_rematch(&session, &p2, &true);
+    let game = client.get_game(&session);
+    assert_eq!(game.rematch_offer, Some(p2.clone()));
+    assert!(game.rematch_swap);
+
+    let rematch = client.accept_rematch(&session, &p1);
+    assert_eq!(rematch, 42);
+    let game = client.get_game(&session);
+    assert_eq!(game.rematch, Some(rematch));
+    assert_eq!(game.rematch_offer, None);
+
+    let next = client.get_game(&rematch);
+    assert_eq!(next.player1, p2);
+    assert_eq!(next.player2, p1);
+    assert_eq!(next.player1_points, 200);
+    assert_eq!(next.player2_points, 100);
+    assert_eq!(next.best_of, 3);
+    assert_eq!(next.phase, Phase::CommitHands);
+    assert_eq!(next.rematch_of, Some(session));
+
+    // Claim a timeout to finish, then rematch again without swapping
+    let h = compute_hands_hash(&env, 0, 1, &test_salt(&env));
+    client.commit_hands(&rematch, &p1, &h);
+    advance_ledgers(&env, 20_000);
+    client.claim_timeout(&rematch, &p1);
+    assert_eq!(hub.results(&rematch), vec![&env, false]);
+
+    client.request_rematch(&rematch, &p1, &false);
+    let third = client.accept_rematch(&rematch, &p2);
+    assert_eq!(third, 43);
+    assert_eq!(client.get_game(&rematch).rematch, Some(third));
+    let game = client.get_game(&third);
+    assert_eq!(game.player1, p2);
+    assert_eq!(game.rematch_of, Some(rematch));
+}
+
+#[test]
+fn test_rematch_validation() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1101u32;
+    let outsider = Address::generate(&env);
+
+    client.start_game(&session, &p1, &p2, &100, &100);
+    let result = client.try_request_rematch(&session, &p1, &false);
+    assert_ctm_error(&result, Error::WrongPhase);
+
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    let result = client.try_request_rematch(&session, &outsider, &false);
+    assert_ctm_error(&result, Error::NotPlayer);
+    let result = client.try_accept_rematch(&session, &p2);
+    assert_ctm_error(&result, Error::NoRematchOffer);
+    let result = client.try_cancel_rematch(&session);
+    assert_ctm_error(&result, Error::NoRematchOffer);
+    let result = client.try_request_rematch(&1102, &p1, &false);
+    assert_ctm_error(&result, Error::GameNotFound);
+
+    client.request_rematch(&session, &p1, &false);
+    let result = client.try_request_rematch(&session, &p2, &false);
+    assert_ctm_error(&result, Error::RematchExists);
+    let result = client.try_accept_rematch(&session, &p1);
+    assert_ctm_error(&result, Error::CannotAcceptOwn);
+    let result = client.try_accept_rematch(&session, &outsider);
+    assert_ctm_error(&result, Error::NotPlayer);
+
+    client.accept_rematch(&session, &p2);
+    let result = client.try_request_rematch(&session, &p2, &false);
+    assert_ctm_error(&result, Error::RematchExists);
+}
+
+#[test]
+fn test_rematch_escrow_deposits_and_cancel_refund() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1103u32;
+    let token = setup_escrow(&env, &client, &p1, &p2, 0);
+
+    client.start_game(&session, &p1, &p2, &100, &100);
+    assert_eq!(client.get_game(&session).rake_bps, 0);
+    // Rock vs Scissors → P1
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    assert_eq!(token.balance(&p1), 1_100);
+
+    // The offer holds the requester's stake until it is withdrawn
+    client.request_rematch(&session, &p2, &false);
+    assert_eq!(token.balance(&p2), 800);
+    client.cancel_rematch(&session);
+    assert_eq!(token.balance(&p2), 900);
+    assert_eq!(client.get_game(&session).rematch_offer, None);
+
+    // Escrow is switched off, but the rematch keeps the original token
+    client.clear_escrow();
+    client.request_rematch(&session, &p2, &false);
+    let rematch = client.accept_rematch(&session, &p1);
+    assert_eq!(token.balance(&p1), 1_000);
+    assert_eq!(token.balance(&p2), 800);
+    assert_eq!(token.balance(&client.address), 200);
+    let next = client.get_game(&rematch);
+    assert_eq!(next.escrow_token, Some(token.address.clone()));
+    assert_eq!(next.rake_bps, 0);
+
+    // A rake raised after the original game does not reach its rematches
+    client.set_escrow(&token.address, &500);
+    play_hands(&env, &client, rematch, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, rematch, &p1, &p2, 0, 0);
+    client.request_rematch(&rematch, &p2, &false);
+    let third = client.accept_rematch(&rematch, &p1);
+    assert_eq!(client.get_game(&third).rake_bps, 0);
+}
+
+#[test]
+fn test_rematch_keeps_draw_policy() {
+    let (env, client, hub, p1, p2) = setup_test();
+    let session = 1104u32;
+
+    client.set_draw_policy(&DrawPolicy::TrueDraw);
+    client.start_game(&session, &p1, &p2, &100, &100);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // A policy changed after the original game does not reach its rematch
+    client.set_draw_policy(&DrawPolicy::Player1Wins);
+    client.request_rematch(&session, &p1, &false);
+    let rematch = client.accept_rematch(&session, &p2);
+    assert_eq!(client.get_game(&rematch).draw_policy, DrawPolicy::TrueDraw);
+
+    // Rock vs Rock is still a true draw
+    play_hands(&env, &client, rematch, &p1, &p2, 0, 1, 0, 2);
+    play_choices(&env, &client, rematch, &p1, &p2, 0, 0);
+    assert_eq!(client.get_game(&rematch).winner, None);
+    assert!(hub.drawn(&rematch));
+}
+
+#[test]
+fn test_lobby_game_rematch_refused() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1105u32;
+    client.set_lobby(&Address::generate(&env));
+
+    client.start_lobby_game(&session, &p1, &p2);
+    assert!(client.get_game(&session).lobby);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+
+    // The lobby pays out the stakes, so a rematch would be unstaked
+    let result = client.try_request_rematch(&session, &p2, &false);
+    assert_ctm_error(&result, Error::LobbyRematch);
+    let result = client.try_accept_rematch(&session, &p1);
+    assert_ctm_error(&result, Error::NoRematchOffer);
+}
+
+// ============================================================================
+// Batch Reads
+// ============================================================================
+
+#[test]
+fn test_get_games_returns_found_sessions() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    client.start_game(&1, &p1, &p2, &100, &100);
+    client.start_match(&2, &p1, &p2, &100, &100, &3);
+
+    let games = client.get_games(&vec![&env, 1u32, 2, 99]);
+    assert_eq!(games.len(), 2);
+    assert_eq!(games.get(1).unwrap().best_of, 1);
+    assert_eq!(games.get(2).unwrap().best_of, 3);
+    assert!(games.get(99).is_none());
+
+    let mut too_many = Vec::new(&env);
+    for session_id in 0..21u32 {
+        too_many.push_back(session_id);
+    }
+    assert_ctm_error(&client.try_get_games(&too_many), Error::InvalidPageSize);
+}
+
+#[test]
+fn test_get_result() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    client.start_game(&1, &p1, &p2, &100, &100);
+
+    let pending = client.get_result(&1);
+    assert!(!pending.complete);
+    assert_eq!(pending.winner, None);
+
+    // Rock vs Scissors → P1
+    play_hands(&env, &client, 1, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, 1, &p1, &p2, 0, 0);
+    let result = client.get_result(&1);
+    assert!(result.complete);
+    assert_eq!(result.winner, Some(p1));
+
+    assert_ctm_error(&client.try_get_result(&99), Error::GameNotFound);
+}
+
+/// Session IDs on a `get_player_games` page, in the order returned.
+fn page_sessions(env: &Env, page: &GamePage) -> Vec<u32> {
+    let mut sessions = Vec::new(env);
+    for (session_id, _) in page.games.iter() {
+        sessions.push_back(session_id);
+    }
+    sessions
+}
+
+#[test]
+fn test_get_player_games_pages_newest_first() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let p3 = Address::generate(&env);
+    for session_id in 1..=5u32 {
+        client.start_game(&session_id, &p1, &p2, &0, &0);
+    }
+    client.start_game(&6, &p3, &p1, &0, &0);
+
+    let first = client.get_player_games(&p1, &0, &4);
+    assert_eq!(page_sessions(&env, &first), vec![&env, 6u32, 5, 4, 3]);
+    assert_eq!(first.next_cursor, Some(2));
+
+    // A game started between pages does not shift the cursor
+    client.start_game(&7, &p1, &p2, &0, &0);
+    let second = client.get_player_games(&p1, &2, &4);
+    assert_eq!(page_sessions(&env, &second), vec![&env, 2u32, 1]);
+    assert_eq!(second.next_cursor, None);
+
+    // Both seats are indexed
+    let p3_games = client.get_player_games(&p3, &0, &20);
+    assert_eq!(page_sessions(&env, &p3_games), vec![&env, 6u32]);
+    assert_eq!(p3_games.games.get(0).unwrap().1.player2, p1);
+
+    let stranger = client.get_player_games(&Address::generate(&env), &0, &5);
+    assert!(stranger.games.is_empty());
+    assert_eq!(stranger.next_cursor, None);
+
+    assert_ctm_error(&client.try_get_player_games(&p1, &0, &0), Error::InvalidPageSize);
+    assert_ctm_error(&client.try_get_player_games(&p1, &0, &21), Error::InvalidPageSize);
+}
+
+#[test]
+fn test_player_games_cursor_skips_dropped_sessions() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    env.cost_estimate().budget().reset_unlimited();
+    for session_id in 1..=500u32 {
+        client.start_game(&session_id, &p1, &p2, &0, &0);
+    }
+
+    let first = client.get_player_games(&p1, &0, &20);
+    assert_eq!(first.next_cursor, Some(480));
+
+    // The index is full: each new session drops the oldest one
+    client.start_game(&501, &p1, &p2, &0, &0);
+    client.start_game(&502, &p1, &p2, &0, &0);
+    let page = client.get_player_games(&p1, &first.next_cursor.unwrap(), &20);
+    // Picks up below the first page's oldest session, 481
+    let sessions = page_sessions(&env, &page);
+    assert_eq!(sessions.first(), Some(480));
+    assert_eq!(sessions.last(), Some(461));
+    assert_eq!(page.next_cursor, Some(460));
+
+    // Positions that were dropped are skipped rather than repeated
+    let last = client.get_player_games(&p1, &5, &20);
+    assert_eq!(page_sessions(&env, &last), vec![&env, 5u32, 4, 3]);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+fn test_player_games_index_covers_rematches_and_skips_expired() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let session = 1u32;
+    client.start_game(&session, &p1, &p2, &0, &0);
+    play_hands(&env, &client, session, &p1, &p2, 0, 1, 2, 1);
+    play_choices(&env, &client, session, &p1, &p2, 0, 0);
+    client.request_rematch(&session, &p1, &false);
+    let rematch = client.accept_rematch(&session, &p2);
+
+    let page = client.get_player_games(&p2, &0, &20);
+    assert_eq!(page_sessions(&env, &page), vec![&env, rematch, session]);
+
+    // An expired game stays in the index but is left off the page
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&CoreKey::Game(session));
+    });
+    let page = client.get_player_games(&p2, &0, &20);
+    assert_eq!(page_sessions(&env, &page), vec![&env, rematch]);
+}
+
+// ============================================================================
+// Game View
+// ============================================================================
+
+#[test]
+fn test_game_view_redacts_by_phase_and_viewer() {
+    let (env, client, _hub, p1, p2) = setup_test();
+    let spectator = Address::generate(&env);
+    let session = 1u32;
+    client.start_game(&session, &p1, &p2, &100, &100);
+
+    let salt = test_salt(&env);
+    client.commit_hands(&session, &p1, &compute_hands_hash(&env, 0, 1, &salt));
+    let view = client.get_game_view(&session, &p2);
+    assert_eq!(view.phase, Phase::CommitHands);
+    assert!(view.player1.hands_committed);
+    assert!(!view.player2.hands_committed);
+
+    // Phase 2: the first revealer's hands are hidden from the opponent
+    client.commit_hands(&session, &p2, &compute_hands_hash(&env, 2, 1, &salt));
+    client.reveal_hands(&session, &p1, &0, &1, &salt);
+    let view = client.get_game_view(&session, &p2);
+    assert!(view.player1.hands_revealed);
+    assert_eq!(view.player1.left_hand, None);
+    assert_eq!(view.player1.right_hand, None);
+    let own = client.get_game_view(&session, &p1);
+    assert_eq!((own.player1.left_hand, own.player1.right_hand), (Some(0), Some(1)));
+
+    // Phase 3: both hands are public
+    client.reveal_hands(&session, &p2, &2, &1, &salt);
+    let view = client.get_game_view(&session, &spectator);
+    assert_eq!(view.phase, Phase::CommitChoice);
+    assert_eq!((view.player1.left_hand, view.player1.right_hand), (Some(0), Some(1)));
+    assert_eq!((view.player2.left_hand, view.player2.right_hand), (Some(2), Some(1)));
+
+    // Phase 4: the first revealer's kept hand is hidden from the opponent
+    let salt2 = test_salt2(&env);
+    client.commit_choice(&session, &p1, &compute_choice_hash(&env, 0, &salt2));
+    client.commit_choice(&session, &p2, &compute_choice_hash(&env, 0, &salt2));
+    assert!(client.get_game_view(&session, &p1).player2.choice_committed);
+    client.reveal_choice(&session, &p1, &0, &salt2);
+    let view = client.get_game_view(&session, &p2);
+    assert!(view.player1.choice_revealed);
+    assert_eq!(view.player1.kept_hand, None);
+    assert_eq!(client.get_game_view(&session, &spectator).player1.kept_hand, None);
+    assert_eq!(client.get_game_view(&session, &p1).player1.kept_hand, Some(0));
+
+    // Complete: everything is public
+    client.reveal_choice(&session, &p2, &0, &salt2);
+    let view = client.get_game_view(&session, &spectator);
+    assert_eq!(view.phase, Phase::Complete);
+    assert_eq!(view.player1.kept_hand, Some(0));
+    assert_eq!(view.player2.kept_hand, Some(2));
+    assert_eq!(view.winner, Some(p1.clone()));
+    assert_eq!(view.player1.wins, 1);
+    assert_eq!(view.history.len(), 1);
+}
+
+#[test]
+fn test_game_view_not_found() {
+    let (env, client, _hub, _p1, _p2) = setup_test();
+    let result = client.try_get_game_view(&7, &Address::generate(&env));
+    assert_ctm_error(&result, Error::GameNotFound);
 }
@@ -5,36 +5,32 @@
 //! A two-player dice game where each player rolls two dice.
 //! The player with the highest total wins (ties go to player 1).
 //!
+//! **Randomness:**
+//! Each player's `roll` commits `keccak256(secret)` for a private 32-byte
+//! secret, and `reveal_roll` discloses it once both have rolled.  The dice
+//! are seeded from both secrets, so neither player can predict the outcome
+//! before committing, nor steer it after seeing the other's commitment.
+//!
+//! The second player to reveal can work out the result first.  Each player
+//! has until `commit_deadline` to roll and, once both have rolled, until
+//! `reveal_deadline` to reveal; after either deadline a player who made the
+//! move can `claim_timeout` against one who did not.
+//!
+//! **Lobby:**
+//! The admin can register a matchmaking lobby with `set_lobby`.  It starts
+//! sessions with `start_lobby_game` once a posted challenge is accepted and
+//! reads the outcome back with `get_result`.  The lobby holds the stakes, so
+//! lobby sessions lock no Game Hub points.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use game_core::{admin, hub, lobby, session, Admin, CoreError, GameResult, Lobby};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
-    contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -48,6 +44,25 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    HashMismatch = 6,
+    AlreadyRevealed = 7,
+    BothPlayersNotRevealed = 8,
+    SelfPlay = 9,
+    HubNotSet = 10,
+    SessionExists = 11,
+    DeadlineNotReached = 12,
+    CannotClaimTimeout = 13,
+    LobbyNotSet = 14,
+}
+
+impl From<CoreError> for Error {
+    fn from(err: CoreError) -> Self {
+        match err {
+            CoreError::SelfPlay => Error::SelfPlay,
+            CoreError::HubNotSet => Error::HubNotSet,
+            CoreError::SessionExists => Error::SessionExists,
+        }
+    }
 }
 
 // ============================================================================
@@ -63,35 +78,34 @@ pub struct Game {
     pub player2_points: i128,
     pub player1_rolled: bool,
     pub player2_rolled: bool,
+    pub player1_commit: Option<BytesN<32>>,
+    pub player2_commit: Option<BytesN<32>>,
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
     pub player1_die1: Option<u32>,
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
     pub winner: Option<Address>,
+    /// Ledger sequence after which a player who rolled can claim the win
+    /// against one who did not; set when the game starts
+    pub commit_deadline: u32,
+    /// Ledger sequence after which a player who revealed can claim the
+    /// win; 0 until both players have rolled
+    pub reveal_deadline: u32,
 }
 
-#[contracttype]
-#[derive(Clone)]
-pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
-}
-
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Commitment a player submits in `roll`: keccak256(secret)
+fn hash_secret(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&Bytes::from_array(env, &secret.to_array()))
+        .into()
+}
+
 /// Roll a single die (1-6) using deterministic PRNG
 fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
     env.prng().seed(seed.into());
@@ -114,10 +128,7 @@ impl DiceDuelContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -127,11 +138,14 @@ impl DiceDuelContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    ///
+    /// # Returns
+    /// * `u32` - The session ID of the new game
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -139,82 +153,63 @@ impl DiceDuelContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
-        if player1 == player2 {
-            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
-        }
+        session::require_distinct_players(&player1, &player2)?;
 
         // Require authentication from both players (they consent to committing points)
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+        // Use the requested ID unless it already holds a game, or allocate one
+        let session_id = session::claim(&env, session_id)?;
+        Self::create_game(&env, session_id, player1, player2, player1_points, player2_points)?;
 
-        // Create game (dice not rolled yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
-            player1_points,
-            player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
-            player1_die1: None,
-            player1_die2: None,
-            player2_die1: None,
-            player2_die2: None,
-            winner: None,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(session_id)
+    }
 
-        // Event emitted by GameHub contract (GameStarted)
+    /// Start a new game on behalf of the registered lobby.
+    ///
+    /// Only the lobby's auth is required: the players already authorized
+    /// their stakes when posting and accepting the challenge there.  The
+    /// lobby holds those stakes, so no points are locked.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session ID the lobby keyed the challenge by
+    /// * `player1` - Address of the challenge's host
+    /// * `player2` - Address of the player who accepted
+    pub fn start_lobby_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+    ) -> Result<(), Error> {
+        let lobby = lobby::address(&env).ok_or(Error::LobbyNotSet)?;
+        lobby.require_auth();
+        session::require_distinct_players(&player1, &player2)?;
+        // The lobby keys its challenge by this ID, so never reallocate it
+        session::require_new(&env, session_id)?;
 
-        Ok(())
+        Self::create_game(&env, session_id, player1, player2, 0, 0)
     }
 
     /// Commit a roll for the current game.
-    /// Both players must roll before the winner can be revealed.
+    /// Both players must roll before secrets can be revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player rolling the dice
-    pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `secret_hash` - keccak256 of the player's 32-byte secret
+    pub fn roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret_hash: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check game is still active (no winner yet)
         if game.winner.is_some() {
@@ -227,23 +222,132 @@ impl DiceDuelContract {
                 return Err(Error::AlreadyRolled);
             }
             game.player1_rolled = true;
+            game.player1_commit = Some(secret_hash);
         } else if player == game.player2 {
             if game.player2_rolled {
                 return Err(Error::AlreadyRolled);
             }
             game.player2_rolled = true;
+            game.player2_commit = Some(secret_hash);
         } else {
             return Err(Error::NotPlayer);
         }
 
+        // Both commitments are in: the reveal window opens
+        if game.player1_rolled && game.player2_rolled {
+            game.reveal_deadline = session::reveal_deadline(&env);
+        }
+
         // Store updated game in temporary storage
-        env.storage().temporary().set(&key, &game);
+        session::save(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the secret committed in `roll`.
+    /// Only allowed once both players have rolled, so the first secret
+    /// disclosed cannot influence the other player's commitment.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The secret whose keccak256 was committed
+    pub fn reveal_roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.player1_rolled || !game.player2_rolled {
+            return Err(Error::BothPlayersNotRolled);
+        }
+
+        let (commit, slot) = if player == game.player1 {
+            (game.player1_commit.clone(), &mut game.player1_secret)
+        } else if player == game.player2 {
+            (game.player2_commit.clone(), &mut game.player2_secret)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if slot.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if commit != Some(hash_secret(&env, &secret)) {
+            return Err(Error::HashMismatch);
+        }
+        *slot = Some(secret);
+
+        session::save(&env, session_id, &game);
 
         Ok(())
     }
 
+    /// Claim the win after the opponent fails to roll or reveal in time.
+    ///
+    /// Until both players have rolled, only allowed once the ledger sequence
+    /// is past `commit_deadline` and only by a player who has rolled against
+    /// one who has not.  After that, the same applies to revealing with
+    /// `reveal_deadline`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `claimant` - Address of the player who rolled or revealed
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player (the claimant)
+    pub fn claim_timeout(env: Env, session_id: u32, claimant: Address) -> Result<Address, Error> {
+        claimant.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if claimant != game.player1 && claimant != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        // Until both have rolled the game waits on a roll, then on a reveal
+        let (deadline, player1_moved, player2_moved) =
+            if game.player1_rolled && game.player2_rolled {
+                (
+                    game.reveal_deadline,
+                    game.player1_secret.is_some(),
+                    game.player2_secret.is_some(),
+                )
+            } else {
+                (game.commit_deadline, game.player1_rolled, game.player2_rolled)
+            };
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        let (claimant_moved, opponent_moved) = if claimant == game.player1 {
+            (player1_moved, player2_moved)
+        } else {
+            (player2_moved, player1_moved)
+        };
+        if !claimant_moved || opponent_moved {
+            return Err(Error::CannotClaimTimeout);
+        }
+
+        game.winner = Some(claimant.clone());
+        session::save(&env, session_id, &game);
+
+        let player1_won = claimant == game.player1;
+        hub::end_game(&env, session_id, player1_won)?;
+
+        Ok(claimant)
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
+    /// Can only be called after both players have revealed their secrets.
     /// This generates dice rolls for both players, determines the winner, and ends the session.
     ///
     /// # Arguments
@@ -253,34 +357,32 @@ impl DiceDuelContract {
     /// * `Address` - Address of the winning player
     pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check if game already ended (has a winner)
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
 
-        // Check both players have rolled
+        // Check both players have rolled and revealed
         if !game.player1_rolled || !game.player2_rolled {
             return Err(Error::BothPlayersNotRolled);
         }
+        let (Some(secret1), Some(secret2)) = (&game.player1_secret, &game.player2_secret) else {
+            return Err(Error::BothPlayersNotRevealed);
+        };
 
         // Generate deterministic dice rolls (1-6)
         // Seed components (all deterministic and identical between sim/submit):
         // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
+        // 2. Both revealed secrets - neither player controls the result alone
         //
         // Note: We do NOT include ledger sequence or timestamp because those differ
         // between simulation and submission, which would cause different winners.
         let mut seed_bytes = Bytes::new(&env);
         seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
+        seed_bytes.append(&Bytes::from_array(&env, &secret1.to_array()));
+        seed_bytes.append(&Bytes::from_array(&env, &secret2.to_array()));
         let base_seed = env.crypto().keccak256(&seed_bytes);
 
         // Roll dice for both players using unique seeds
@@ -320,23 +422,13 @@ impl DiceDuelContract {
 
         // Update game with winner (this marks the game as ended)
         game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        session::save(&env, session_id, &game);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
         let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        hub::end_game(&env, session_id, player1_won)?;
 
         Ok(winner)
     }
@@ -349,87 +441,90 @@ impl DiceDuelContract {
     /// # Returns
     /// * `Game` - The game state (includes dice after game ends)
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+        session::load(&env, session_id).ok_or(Error::GameNotFound)
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
-
-    /// Set a new admin address
+    /// Get whether a game is complete and who won, without the full state.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
+    /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    /// * `GameResult` - Complete once the dice have been rolled or a timeout claimed
+    pub fn get_result(env: Env, session_id: u32) -> Result<GameResult, Error> {
+        let game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(GameResult {
+            complete: game.winner.is_some(),
+            winner: game.winner,
+        })
     }
 
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
-    }
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Open the Game Hub session and store a new game under `session_id`.
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        hub::start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        // Create game (dice not rolled yet - will be generated in reveal_winner)
+        let game = Game {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            player1_rolled: false,
+            player2_rolled: false,
+            player1_commit: None,
+            player2_commit: None,
+            player1_secret: None,
+            player2_secret: None,
+            player1_die1: None,
+            player1_die2: None,
+            player2_die1: None,
+            player2_die2: None,
+            winner: None,
+            commit_deadline: session::reveal_deadline(env),
+            reveal_deadline: 0,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        session::save(env, session_id, &game);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for DiceDuelContract {}
+
+// get_lobby / set_lobby
+#[contractimpl(contracttrait)]
+impl Lobby for DiceDuelContract {}
+
 // ============================================================================
 // Tests
 // ============================================================================
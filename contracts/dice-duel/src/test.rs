@@ -4,8 +4,9 @@
 // These tests verify game logic independently of the full GameHub system.
 
 use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use game_core::CoreKey;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -28,6 +29,11 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn reserve_session_id(_env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - always allocates the same ID
+        42
+    }
+
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
         // Mock implementation - does nothing
     }
@@ -83,6 +89,35 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Fixed 32-byte secret filled with `b`
+fn secret(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+/// keccak256(secret), matching the contract's commitment
+fn secret_hash(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&Bytes::from_array(env, &secret.to_array()))
+        .into()
+}
+
+/// Both players roll and reveal secrets `s1` / `s2`
+fn roll_and_reveal(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    s1: u8,
+    s2: u8,
+) {
+    let (secret1, secret2) = (secret(env, s1), secret(env, s2));
+    client.roll(&session_id, player1, &secret_hash(env, &secret1));
+    client.roll(&session_id, player2, &secret_hash(env, &secret2));
+    client.reveal_roll(&session_id, player1, &secret1);
+    client.reveal_roll(&session_id, player2, &secret2);
+}
+
 /// Assert that a Result contains a specific dice_duel error
 fn assert_dice_duel_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
@@ -138,16 +173,15 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(!game.player1_rolled);
+    assert!(!game.player2_rolled);
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
     assert!(game.player2_die2.is_none());
 
-    // Players roll
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    // Players roll and reveal their secrets
+    roll_and_reveal(&_env, &client, session_id, &player1, &player2, 1, 2);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
@@ -183,8 +217,9 @@ fn test_cannot_roll_twice() {
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    client.roll(&session_id, &player1);
-    let result = client.try_roll(&session_id, &player1);
+    let hash = secret_hash(&_env, &secret(&_env, 1));
+    client.roll(&session_id, &player1, &hash);
+    let result = client.try_roll(&session_id, &player1, &hash);
     assert_dice_duel_error(&result, Error::AlreadyRolled);
 }
 
@@ -196,7 +231,7 @@ fn test_cannot_reveal_before_both_roll() {
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player1, &secret_hash(&_env, &secret(&_env, 1)));
 
     let result = client.try_reveal_winner(&session_id);
     assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
@@ -212,10 +247,54 @@ fn test_non_player_cannot_roll() {
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
     let non_player = Address::generate(&_env);
-    let result = client.try_roll(&session_id, &non_player);
+    let result = client.try_roll(&session_id, &non_player, &secret_hash(&_env, &secret(&_env, 1)));
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
+#[test]
+fn test_cannot_play_against_yourself() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
+
+    let result = client.try_start_game(&6, &player1, &player1, &100, &100);
+    assert_dice_duel_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_session_exists_rejected() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&20, &player1, &player2, &100, &100);
+    let result = client.try_start_game(&20, &player2, &player1, &100, &100);
+    assert_dice_duel_error(&result, Error::SessionExists);
+    assert_eq!(client.get_game(&20).player1, player1);
+}
+
+#[test]
+fn test_session_id_allocated_by_hub() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&0, &player1, &player2, &100, &100);
+    assert_eq!(session_id, 42);
+    assert_eq!(client.get_game(&42).player1, player1);
+}
+
+#[test]
+fn test_hub_not_set() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&7, &player1, &player2, &100, &100);
+    roll_and_reveal(&env, &client, 7, &player1, &player2, 1, 2);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CoreKey::GameHubAddress);
+    });
+
+    let result = client.try_start_game(&8, &player1, &player2, &100, &100);
+    assert_dice_duel_error(&result, Error::HubNotSet);
+    let result = client.try_reveal_winner(&7);
+    assert_dice_duel_error(&result, Error::HubNotSet);
+    assert_eq!(client.get_game(&7).winner, None);
+}
+
 #[test]
 fn test_cannot_roll_after_game_ended() {
     let (_env, client, _hub, player1, player2) = setup_test();
@@ -224,14 +303,243 @@ fn test_cannot_roll_after_game_ended() {
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    roll_and_reveal(&_env, &client, session_id, &player1, &player2, 1, 2);
     client.reveal_winner(&session_id);
 
-    let result = client.try_roll(&session_id, &player1);
+    let result = client.try_roll(&session_id, &player1, &secret_hash(&_env, &secret(&_env, 3)));
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_roll(&session_id, &player1, &secret(&_env, 1));
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
+// ============================================================================
+// Commit-Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_cannot_reveal_secret_before_both_roll() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 6u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+
+    let result = client.try_reveal_roll(&session_id, &player1, &secret(&env, 1));
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 7u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+    client.roll(&session_id, &player2, &secret_hash(&env, &secret(&env, 2)));
+
+    let result = client.try_reveal_roll(&session_id, &player1, &secret(&env, 2));
+    assert_dice_duel_error(&result, Error::HashMismatch);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_roll(&session_id, &non_player, &secret(&env, 1));
+    assert_dice_duel_error(&result, Error::NotPlayer);
+
+    client.reveal_roll(&session_id, &player1, &secret(&env, 1));
+    let result = client.try_reveal_roll(&session_id, &player1, &secret(&env, 1));
+    assert_dice_duel_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_cannot_reveal_winner_before_both_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 8u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+    client.roll(&session_id, &player2, &secret_hash(&env, &secret(&env, 2)));
+    client.reveal_roll(&session_id, &player1, &secret(&env, 1));
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRevealed);
+
+    client.reveal_roll(&session_id, &player2, &secret(&env, 2));
+    client.reveal_winner(&session_id);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_when_opponent_withholds_secret() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+    assert_eq!(client.get_game(&session_id).reveal_deadline, 0);
+    client.roll(&session_id, &player2, &secret_hash(&env, &secret(&env, 2)));
+    let deadline = client.get_game(&session_id).reveal_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    // Player 2 reveals; player 1 sees the outcome and stalls
+    client.reveal_roll(&session_id, &player2, &secret(&env, 2));
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+
+    assert_eq!(client.claim_timeout(&session_id, &player2), player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert!(game.player1_die1.is_none());
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_roll(&session_id, &player1, &secret(&env, 1));
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_requires_stalled_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 10u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::DeadlineNotReached);
+
+    client.roll(&session_id, &player2, &secret_hash(&env, &secret(&env, 2)));
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+
+    // Nobody revealed: there is no one to award the win to
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+    let result = client.try_claim_timeout(&session_id, &Address::generate(&env));
+    assert_dice_duel_error(&result, Error::NotPlayer);
+
+    // Both revealed, even late: the dice decide
+    client.reveal_roll(&session_id, &player1, &secret(&env, 1));
+    client.reveal_roll(&session_id, &player2, &secret(&env, 2));
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+    client.reveal_winner(&session_id);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_rolls() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 11u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let deadline = client.get_game(&session_id).commit_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    // Neither rolled: there is no one to award the win to
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+
+    // Player 1 rolls late; player 2 never does
+    client.roll(&session_id, &player1, &secret_hash(&env, &secret(&env, 1)));
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::CannotClaimTimeout);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+    let result = client.get_result(&session_id);
+    assert!(result.complete);
+    assert_eq!(result.winner, Some(player1.clone()));
+
+    let result = client.try_roll(&session_id, &player2, &secret_hash(&env, &secret(&env, 2)));
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+/// Play session 1 in a fresh environment and return the four dice.
+fn dice_for_secrets(s1: u8, s2: u8) -> (Address, [u32; 4]) {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    roll_and_reveal(&env, &client, 1, &player1, &player2, s1, s2);
+    client.reveal_winner(&1);
+
+    let game = client.get_game(&1);
+    (
+        player1,
+        [
+            game.player1_die1.unwrap(),
+            game.player1_die2.unwrap(),
+            game.player2_die1.unwrap(),
+            game.player2_die2.unwrap(),
+        ],
+    )
+}
+
+#[test]
+fn test_outcome_depends_on_secrets() {
+    // Same session and players: identical secrets give identical dice...
+    let (player_a, dice) = dice_for_secrets(1, 2);
+    let (player_b, again) = dice_for_secrets(1, 2);
+    assert_eq!(player_a, player_b);
+    assert_eq!(dice, again);
+
+    // ...while either player changing their secret changes the roll
+    let p2_changed = (3..20u8).any(|s2| dice_for_secrets(1, s2).1 != dice);
+    let p1_changed = (3..20u8).any(|s1| dice_for_secrets(s1, 2).1 != dice);
+    assert!(p2_changed);
+    assert!(p1_changed);
+}
+
+// ============================================================================
+// Lobby Tests
+// ============================================================================
+
+#[test]
+fn test_lobby_starts_game_without_player_auth() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby = Address::generate(&env);
+    client.set_lobby(&lobby);
+    assert_eq!(client.get_lobby(), Some(lobby.clone()));
+
+    client.start_lobby_game(&7, &player1, &player2);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, lobby);
+
+    // The lobby holds the stakes, so no points are locked
+    let game = client.get_game(&7);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player1_points, 0);
+    assert!(!client.get_result(&7).complete);
+
+    roll_and_reveal(&env, &client, 7, &player1, &player2, 1, 2);
+    let winner = client.reveal_winner(&7);
+    let result = client.get_result(&7);
+    assert!(result.complete);
+    assert_eq!(result.winner, Some(winner));
+}
+
+#[test]
+fn test_lobby_game_requires_lobby() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let result = client.try_start_lobby_game(&8, &player1, &player2);
+    assert_dice_duel_error(&result, Error::LobbyNotSet);
+
+    client.set_lobby(&Address::generate(&env));
+    let result = client.try_start_lobby_game(&8, &player1, &player1);
+    assert_dice_duel_error(&result, Error::SelfPlay);
+
+    // The lobby's session ID is never reallocated
+    client.start_game(&8, &player1, &player2, &100, &100);
+    let result = client.try_start_lobby_game(&8, &player2, &player1);
+    assert_dice_duel_error(&result, Error::SessionExists);
+
+    env.set_auths(&[]);
+    assert!(client.try_start_lobby_game(&9, &player1, &player2).is_err());
+}
+
 #[test]
 fn test_upgrade_function_exists() {
     let (_env, client, _hub, _player1, _player2) = setup_test();
@@ -0,0 +1,679 @@
+#![no_std]
+
+//! # Game Hub
+//!
+//! Session registry and points ledger shared by every game contract.
+//!
+//! Only game contracts approved by the admin (`add_game`) can open sessions,
+//! and every call is authorized by the game contract itself, so a player
+//! cannot start or settle a session directly.  Opening a session locks both
+//! players' points; `end_game` pays the whole pot to the winner and
+//! `end_game_draw` refunds both stakes.  A session can only be ended once.
+//!
+//! Games call the hub with the same interface the mock hub exposes:
+//! `start_game(game_id, session_id, player1, player2, p1_points, p2_points)`,
+//! `end_game(session_id, player1_won)` and `end_game_draw(session_id)`.
+//! Games that let the hub pick session IDs call `reserve_session_id(game_id)`
+//! first; a reserved ID can only be started by the game that reserved it.
+//!
+//! ## Stats
+//! Every settled session updates persistent per-player `PlayerStats`, both
+//! overall and per game contract.  The leaderboard holds the top
+//! [`LEADERBOARD_SIZE`] players by wins (earlier arrivals first on ties),
+//! each stored with its win count, so keeping it ordered costs a bounded
+//! amount of work per settlement and `get_leaderboard` can page through it
+//! without sorting.
+//!
+//! ## Ratings
+//! Each player also has an Elo rating per game contract, updated on every
+//! settled session (a draw scores ½).  The expected score
+//! `1 / (1 + 10^((Rb - Ra) / 400))` is evaluated in 1e12 fixed point with a
+//! Taylor series for `exp`, since floats are unavailable in `no_std`.
+//! Rating gaps are capped at 800 and the winner's gain always equals the
+//! loser's loss.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, BytesN, Env,
+    Vec,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotApproved    = 1,
+    SessionExists      = 2,
+    SessionNotFound    = 3,
+    SessionEnded       = 4,
+    InvalidPoints      = 5,
+    InsufficientPoints = 6,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct GameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contractevent]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub player1_won: bool,
+}
+
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+// ============================================================================
+// Data types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Open,
+    Player1Won,
+    Player2Won,
+    Draw,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub status: SessionStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Opponent stakes collected from won sessions.
+    pub points_won: i128,
+    /// Own stakes forfeited in lost sessions.
+    pub points_lost: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    /// 1-based position on the leaderboard.
+    pub rank: u32,
+    pub player: Address,
+    pub stats: PlayerStats,
+}
+
+/// A leaderboard position, with the wins it is ordered by.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LeaderboardSlot {
+    player: Address,
+    wins: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Game(Address),
+    Session(u32),
+    Points(Address),
+    Stats(Address),
+    GameStats(Address, Address),
+    Leaderboard,
+    Rating(Address, Address),
+    NextSessionId,
+    Reservation(u32),
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// ~30 days at ~5 s/ledger – matches the game contracts' session TTL.
+const SESSION_TTL_LEDGERS: u32 = 518_400;
+/// Point balances and approvals outlive any single session.
+const PERSISTENT_TTL_LEDGERS: u32 = 518_400;
+/// Upper bound on `get_leaderboard` page size.
+const MAX_LEADERBOARD_PAGE: u32 = 50;
+/// Number of players kept on the leaderboard.
+pub const LEADERBOARD_SIZE: u32 = 100;
+
+/// Rating of a player with no settled sessions in a game.
+pub const INITIAL_RATING: u32 = 1500;
+/// Ratings never drop below this floor.
+pub const MIN_RATING: u32 = 100;
+/// Maximum rating change per session.
+const ELO_K: i128 = 32;
+/// Rating gaps beyond this are treated as this gap.
+const MAX_RATING_GAP: i128 = 800;
+/// Fixed-point scale for the rating math (1.0 == SCALE).
+const SCALE: i128 = 1_000_000_000_000;
+/// ln(10) at `SCALE`.
+const LN_10: i128 = 2_302_585_092_994;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn require_admin(env: &Env) {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("Admin not set");
+    admin.require_auth();
+}
+
+fn points_of(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Points(player.clone()))
+        .unwrap_or(0)
+}
+
+fn set_points(env: &Env, player: &Address, amount: i128) {
+    save_persistent(env, &DataKey::Points(player.clone()), &amount);
+}
+
+fn load_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)
+}
+
+fn save_session(env: &Env, session_id: u32, session: &Session) {
+    let key = DataKey::Session(session_id);
+    env.storage().temporary().set(&key, session);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+/// First session ID at or after `from` that is neither open nor reserved.
+fn first_free_session(env: &Env, from: u32) -> u32 {
+    let mut session_id = from;
+    while env.storage().temporary().has(&DataKey::Session(session_id))
+        || env.storage().temporary().has(&DataKey::Reservation(session_id))
+    {
+        session_id += 1;
+    }
+    session_id
+}
+
+fn stats_of(env: &Env, key: &DataKey) -> PlayerStats {
+    env.storage().persistent().get(key).unwrap_or_default()
+}
+
+fn save_persistent<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(
+    env: &Env,
+    key: &DataKey,
+    value: &V,
+) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_LEDGERS, PERSISTENT_TTL_LEDGERS);
+}
+
+fn load_leaderboard(env: &Env) -> Vec<LeaderboardSlot> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Leaderboard)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Apply one session result to a player's overall and per-game stats.
+/// `delta` is the points won (positive) or lost (negative) this session.
+fn record_stats(env: &Env, player: &Address, game_id: &Address, won: Option<bool>, delta: i128) {
+    let keys = [
+        DataKey::Stats(player.clone()),
+        DataKey::GameStats(player.clone(), game_id.clone()),
+    ];
+    for key in keys.iter() {
+        let mut stats = stats_of(env, key);
+        stats.games_played += 1;
+        match won {
+            Some(true) => stats.wins += 1,
+            Some(false) => stats.losses += 1,
+            None => stats.draws += 1,
+        }
+        if delta > 0 {
+            stats.points_won += delta;
+        } else {
+            stats.points_lost -= delta;
+        }
+        save_persistent(env, key, &stats);
+    }
+}
+
+/// Keep the leaderboard ordered by wins after `player`'s stats changed.
+///
+/// A win only ever moves a player up, so it is shifted past every entry
+/// with strictly fewer wins.  Newcomers start at the bottom; once the
+/// board is full they only get on by having more wins than the last entry,
+/// which they replace.
+fn update_leaderboard(env: &Env, player: &Address) {
+    let slot = LeaderboardSlot {
+        player: player.clone(),
+        wins: stats_of(env, &DataKey::Stats(player.clone())).wins,
+    };
+    let mut board = load_leaderboard(env);
+    let mut idx = match board.iter().position(|s| s.player == *player) {
+        Some(i) => i as u32,
+        None if board.len() < LEADERBOARD_SIZE => {
+            board.push_back(slot.clone());
+            board.len() - 1
+        }
+        None => {
+            let last = board.len() - 1;
+            if board.get_unchecked(last).wins >= slot.wins {
+                return;
+            }
+            last
+        }
+    };
+    board.set(idx, slot.clone());
+    while idx > 0 {
+        let above = board.get_unchecked(idx - 1);
+        if above.wins >= slot.wins {
+            break;
+        }
+        board.set(idx - 1, slot.clone());
+        board.set(idx, above);
+        idx -= 1;
+    }
+    save_persistent(env, &DataKey::Leaderboard, &board);
+}
+
+/// `e^x` for `0 <= x`, both at `SCALE`, by Taylor series.
+fn exp_fixed(x: i128) -> i128 {
+    let mut sum = SCALE;
+    let mut term = SCALE;
+    let mut n = 1;
+    while term > 0 {
+        term = term * x / SCALE / n;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// Expected score of a player rated `ra` against one rated `rb`, at `SCALE`.
+pub(crate) fn expected_score(ra: u32, rb: u32) -> i128 {
+    let gap = (rb as i128 - ra as i128).clamp(-MAX_RATING_GAP, MAX_RATING_GAP);
+    // 10^(|gap| / 400) == e^(|gap| * ln 10 / 400)
+    let pow = exp_fixed(gap.abs() * LN_10 / 400);
+    let weaker = SCALE * SCALE / (SCALE + pow);
+    if gap >= 0 {
+        weaker
+    } else {
+        SCALE - weaker
+    }
+}
+
+/// New ratings after player A scores `score_a` (at `SCALE`) against B.
+///
+/// The change is rounded to the nearest point and applied to both players
+/// with opposite signs, so the pair's total is conserved (unless the floor
+/// kicks in).
+pub(crate) fn elo_update(ra: u32, rb: u32, score_a: i128) -> (u32, u32) {
+    let diff = ELO_K * (score_a - expected_score(ra, rb));
+    let half = if diff >= 0 { SCALE / 2 } else { -SCALE / 2 };
+    let delta = ((diff + half) / SCALE) as i32;
+    (
+        ra.saturating_add_signed(delta).max(MIN_RATING),
+        rb.saturating_add_signed(-delta).max(MIN_RATING),
+    )
+}
+
+fn rating_of(env: &Env, player: &Address, game_id: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Rating(player.clone(), game_id.clone()))
+        .unwrap_or(INITIAL_RATING)
+}
+
+fn update_ratings(env: &Env, session: &Session, p1_score: i128) {
+    let (r1, r2) = elo_update(
+        rating_of(env, &session.player1, &session.game_id),
+        rating_of(env, &session.player2, &session.game_id),
+        p1_score,
+    );
+    save_persistent(
+        env,
+        &DataKey::Rating(session.player1.clone(), session.game_id.clone()),
+        &r1,
+    );
+    save_persistent(
+        env,
+        &DataKey::Rating(session.player2.clone(), session.game_id.clone()),
+        &r2,
+    );
+}
+
+/// Record a settled session in both players' stats, ratings and the
+/// leaderboard.
+fn record_result(env: &Env, session: &Session) {
+    let (p1_won, p1_delta, p2_delta) = match session.status {
+        SessionStatus::Player1Won => (Some(true), session.player2_points, -session.player2_points),
+        SessionStatus::Player2Won => (Some(false), -session.player1_points, session.player1_points),
+        _ => (None, 0, 0),
+    };
+    let p2_won = p1_won.map(|w| !w);
+    record_stats(env, &session.player1, &session.game_id, p1_won, p1_delta);
+    record_stats(env, &session.player2, &session.game_id, p2_won, p2_delta);
+    update_leaderboard(env, &session.player1);
+    update_leaderboard(env, &session.player2);
+
+    let p1_score = match p1_won {
+        Some(true) => SCALE,
+        Some(false) => 0,
+        None => SCALE / 2,
+    };
+    update_ratings(env, session, p1_score);
+}
+
+/// Load an open session and check the calling game authorized the settlement.
+fn open_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+    let session = load_session(env, session_id)?;
+    session.game_id.require_auth();
+    if session.status != SessionStatus::Open {
+        return Err(Error::SessionEnded);
+    }
+    Ok(session)
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct GameHub;
+
+#[contractimpl]
+impl GameHub {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ------------------------------------------------------------ start_game
+
+    /// Open a session for an approved game and lock both players' points.
+    ///
+    /// Must be authorized by `game_id`; session IDs are unique across games.
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+        if !Self::is_game(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotApproved);
+        }
+        let key = DataKey::Session(session_id);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::SessionExists);
+        }
+        let reservation = DataKey::Reservation(session_id);
+        match env.storage().temporary().get::<_, Address>(&reservation) {
+            Some(owner) if owner != game_id => return Err(Error::SessionExists),
+            Some(_) => env.storage().temporary().remove(&reservation),
+            None => {}
+        }
+        if player1_points < 0 || player2_points < 0 {
+            return Err(Error::InvalidPoints);
+        }
+
+        let p1_balance = points_of(&env, &player1);
+        let p2_balance = points_of(&env, &player2);
+        if p1_balance < player1_points || p2_balance < player2_points {
+            return Err(Error::InsufficientPoints);
+        }
+        set_points(&env, &player1, p1_balance - player1_points);
+        set_points(&env, &player2, p2_balance - player2_points);
+
+        let session = Session {
+            game_id: game_id.clone(),
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            status: SessionStatus::Open,
+        };
+        save_session(&env, session_id, &session);
+
+        // Move the allocation counter past IDs taken by this call
+        if session_id == Self::next_session_id(env.clone()) {
+            env.storage()
+                .instance()
+                .set(&DataKey::NextSessionId, &first_free_session(&env, session_id + 1));
+        }
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Allocate the next free session ID to `game_id`.
+    ///
+    /// Games that let the hub pick IDs call this and then `start_game` in
+    /// the same transaction.  Until then no other game can start the ID and
+    /// later calls skip it, so games sharing the hub never collide.  The
+    /// reservation lives in temporary storage at the minimum TTL, so an ID
+    /// that is never started only stays blocked briefly.
+    pub fn reserve_session_id(env: Env, game_id: Address) -> Result<u32, Error> {
+        game_id.require_auth();
+        if !Self::is_game(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotApproved);
+        }
+        let session_id = Self::next_session_id(env.clone());
+        env.storage()
+            .temporary()
+            .set(&DataKey::Reservation(session_id), &game_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSessionId, &first_free_session(&env, session_id + 1));
+        Ok(session_id)
+    }
+
+    /// The session ID `reserve_session_id` would allocate next.
+    ///
+    /// Caller-chosen and reserved IDs are skipped, so the result is never 0
+    /// and never an open session.  Viewing does not reserve the ID.
+    pub fn next_session_id(env: Env) -> u32 {
+        let next = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(1);
+        first_free_session(&env, next)
+    }
+
+    // ------------------------------------------------------------ end_game
+
+    /// Settle a session: the winner receives both locked stakes.
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let mut session = open_session(&env, session_id)?;
+
+        let pot = session.player1_points + session.player2_points;
+        let winner = if player1_won {
+            session.status = SessionStatus::Player1Won;
+            &session.player1
+        } else {
+            session.status = SessionStatus::Player2Won;
+            &session.player2
+        };
+        set_points(&env, winner, points_of(&env, winner) + pot);
+        save_session(&env, session_id, &session);
+        record_result(&env, &session);
+
+        GameEnded {
+            session_id,
+            player1_won,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Settle a session with no winner: both stakes are refunded.
+    pub fn end_game_draw(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut session = open_session(&env, session_id)?;
+
+        set_points(
+            &env,
+            &session.player1,
+            points_of(&env, &session.player1) + session.player1_points,
+        );
+        set_points(
+            &env,
+            &session.player2,
+            points_of(&env, &session.player2) + session.player2_points,
+        );
+        session.status = SessionStatus::Draw;
+        save_session(&env, session_id, &session);
+        record_result(&env, &session);
+
+        GameDrawn { session_id }.publish(&env);
+        Ok(())
+    }
+
+    // ------------------------------------------------------------ queries
+
+    pub fn get_session(env: Env, session_id: u32) -> Result<Session, Error> {
+        load_session(&env, session_id)
+    }
+
+    /// Spendable points; stakes locked in open sessions are excluded.
+    pub fn get_points(env: Env, player: Address) -> i128 {
+        points_of(&env, &player)
+    }
+
+    /// Lifetime stats across every game contract.
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        stats_of(&env, &DataKey::Stats(player))
+    }
+
+    /// Stats for sessions played through one game contract.
+    pub fn get_player_game_stats(env: Env, player: Address, game_id: Address) -> PlayerStats {
+        stats_of(&env, &DataKey::GameStats(player, game_id))
+    }
+
+    /// Elo rating in one game contract; `INITIAL_RATING` until the player
+    /// has settled a session there.
+    pub fn get_rating(env: Env, player: Address, game_id: Address) -> u32 {
+        rating_of(&env, &player, &game_id)
+    }
+
+    /// Page through the top players ordered by wins.  `limit` is capped
+    /// at 50.
+    pub fn get_leaderboard(env: Env, start: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        let board = load_leaderboard(&env);
+        let end = board
+            .len()
+            .min(start.saturating_add(limit.min(MAX_LEADERBOARD_PAGE)));
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            let player = board.get_unchecked(i).player;
+            page.push_back(LeaderboardEntry {
+                rank: i + 1,
+                stats: stats_of(&env, &DataKey::Stats(player.clone())),
+                player,
+            });
+        }
+        page
+    }
+
+    /// Number of players on the leaderboard, at most `LEADERBOARD_SIZE`.
+    pub fn get_leaderboard_len(env: Env) -> u32 {
+        load_leaderboard(&env).len()
+    }
+
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Game(game_id))
+            .unwrap_or(false)
+    }
+
+    // ------------------------------------------------------------ admin
+
+    /// Approve a game contract to open sessions.
+    pub fn add_game(env: Env, game_id: Address) {
+        require_admin(&env);
+        save_persistent(&env, &DataKey::Game(game_id), &true);
+    }
+
+    /// Revoke approval.  Sessions already open can still be ended.
+    pub fn remove_game(env: Env, game_id: Address) {
+        require_admin(&env);
+        env.storage().persistent().remove(&DataKey::Game(game_id));
+    }
+
+    /// Credit points to a player's balance.
+    pub fn add_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        require_admin(&env);
+        if amount <= 0 {
+            return Err(Error::InvalidPoints);
+        }
+        set_points(&env, &player, points_of(&env, &player) + amount);
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        require_admin(&env);
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
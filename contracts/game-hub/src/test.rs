@@ -0,0 +1,496 @@
+#![cfg(test)]
+
+//! Tests for the Game Hub contract.
+
+use crate::{
+    elo_update, expected_score, Error, GameHub, GameHubClient, PlayerStats, SessionStatus,
+    INITIAL_RATING, LEADERBOARD_SIZE, MIN_RATING,
+};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Ledger as _};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, GameHubClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1_441_065_600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(GameHub, (&admin,));
+    let hub = GameHubClient::new(&env, &hub_addr);
+
+    let game = Address::generate(&env);
+    hub.add_game(&game);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    hub.add_points(&player1, &1_000);
+    hub.add_points(&player2, &1_000);
+
+    (env, hub, game, player1, player2)
+}
+
+/// Helper: assert a contract call returned a specific Error.
+fn assert_hub_error<T: core::fmt::Debug, E: core::fmt::Debug>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected: Error,
+) {
+    match result {
+        Err(Ok(actual)) => {
+            assert_eq!(
+                *actual, expected,
+                "Expected {:?} but got {:?}",
+                expected, actual
+            );
+        }
+        other => panic!("Expected Err(Ok({:?})), got {:?}", expected, other),
+    }
+}
+
+// ============================================================================
+// Sessions
+// ============================================================================
+
+#[test]
+fn test_start_locks_points_and_winner_takes_pot() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &1, &p1, &p2, &100, &250);
+    assert_eq!(hub.get_points(&p1), 900);
+    assert_eq!(hub.get_points(&p2), 750);
+
+    let session = hub.get_session(&1);
+    assert_eq!(session.game_id, game);
+    assert_eq!(session.status, SessionStatus::Open);
+
+    hub.end_game(&1, &false);
+    assert_eq!(hub.get_points(&p1), 900);
+    assert_eq!(hub.get_points(&p2), 1_100);
+    assert_eq!(hub.get_session(&1).status, SessionStatus::Player2Won);
+}
+
+#[test]
+fn test_draw_refunds_both_stakes() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &2, &p1, &p2, &100, &250);
+    hub.end_game_draw(&2);
+
+    assert_eq!(hub.get_points(&p1), 1_000);
+    assert_eq!(hub.get_points(&p2), 1_000);
+    assert_eq!(hub.get_session(&2).status, SessionStatus::Draw);
+}
+
+#[test]
+fn test_unapproved_game_rejected() {
+    let (env, hub, _game, p1, p2) = setup_test();
+    let rogue = Address::generate(&env);
+
+    let result = hub.try_start_game(&rogue, &3, &p1, &p2, &100, &100);
+    assert_hub_error(&result, Error::GameNotApproved);
+    assert_eq!(hub.get_points(&p1), 1_000);
+}
+
+#[test]
+fn test_removed_game_can_still_end_open_session() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &4, &p1, &p2, &100, &100);
+    hub.remove_game(&game);
+    assert!(!hub.is_game(&game));
+
+    let result = hub.try_start_game(&game, &5, &p1, &p2, &100, &100);
+    assert_hub_error(&result, Error::GameNotApproved);
+
+    hub.end_game(&4, &true);
+    assert_eq!(hub.get_points(&p1), 1_100);
+}
+
+#[test]
+fn test_duplicate_session_rejected() {
+    let (env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &6, &p1, &p2, &100, &100);
+
+    let other_game = Address::generate(&env);
+    hub.add_game(&other_game);
+    let result = hub.try_start_game(&other_game, &6, &p1, &p2, &100, &100);
+    assert_hub_error(&result, Error::SessionExists);
+
+    // Ending does not free the ID either
+    hub.end_game(&6, &true);
+    let result = hub.try_start_game(&game, &6, &p1, &p2, &100, &100);
+    assert_hub_error(&result, Error::SessionExists);
+}
+
+#[test]
+fn test_next_session_id_skips_taken_ids() {
+    let (_env, hub, game, p1, p2) = setup_test();
+    assert_eq!(hub.next_session_id(), 1);
+
+    // Caller-chosen IDs ahead of the counter are skipped
+    hub.start_game(&game, &2, &p1, &p2, &10, &10);
+    assert_eq!(hub.next_session_id(), 1);
+    hub.start_game(&game, &1, &p1, &p2, &10, &10);
+    assert_eq!(hub.next_session_id(), 3);
+
+    // Viewing does not reserve; starting does
+    assert_eq!(hub.next_session_id(), 3);
+    hub.start_game(&game, &3, &p1, &p2, &10, &10);
+    assert_eq!(hub.next_session_id(), 4);
+
+    // IDs far from the counter do not move it
+    hub.start_game(&game, &500, &p1, &p2, &10, &10);
+    assert_eq!(hub.next_session_id(), 4);
+}
+
+#[test]
+fn test_reserved_session_id_belongs_to_its_game() {
+    let (env, hub, game, p1, p2) = setup_test();
+    let other = Address::generate(&env);
+    hub.add_game(&other);
+
+    // Each reservation moves the counter, so games get distinct IDs
+    assert_eq!(hub.reserve_session_id(&game), 1);
+    assert_eq!(hub.reserve_session_id(&other), 2);
+    assert_eq!(hub.next_session_id(), 3);
+
+    // Only the reserving game can start the ID
+    let result = hub.try_start_game(&other, &1, &p1, &p2, &10, &10);
+    assert_hub_error(&result, Error::SessionExists);
+    hub.start_game(&game, &1, &p1, &p2, &10, &10);
+    hub.start_game(&other, &2, &p1, &p2, &10, &10);
+    assert_eq!(hub.get_session(&2).game_id, other);
+
+    let result = hub.try_reserve_session_id(&Address::generate(&env));
+    assert_hub_error(&result, Error::GameNotApproved);
+}
+
+#[test]
+fn test_duplicate_end_rejected() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &7, &p1, &p2, &100, &100);
+    hub.end_game(&7, &true);
+
+    assert_hub_error(&hub.try_end_game(&7, &true), Error::SessionEnded);
+    assert_hub_error(&hub.try_end_game(&7, &false), Error::SessionEnded);
+    assert_hub_error(&hub.try_end_game_draw(&7), Error::SessionEnded);
+    assert_eq!(hub.get_points(&p1), 1_100);
+    assert_eq!(hub.get_points(&p2), 900);
+
+    hub.start_game(&game, &8, &p1, &p2, &100, &100);
+    hub.end_game_draw(&8);
+    assert_hub_error(&hub.try_end_game(&8, &true), Error::SessionEnded);
+}
+
+#[test]
+fn test_unknown_session_rejected() {
+    let (_env, hub, _game, _p1, _p2) = setup_test();
+
+    assert_hub_error(&hub.try_end_game(&99, &true), Error::SessionNotFound);
+    assert_hub_error(&hub.try_end_game_draw(&99), Error::SessionNotFound);
+    assert_hub_error(&hub.try_get_session(&99), Error::SessionNotFound);
+}
+
+// ============================================================================
+// Points
+// ============================================================================
+
+#[test]
+fn test_insufficient_points_rejected() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    let result = hub.try_start_game(&game, &10, &p1, &p2, &100, &1_001);
+    assert_hub_error(&result, Error::InsufficientPoints);
+    assert_eq!(hub.get_points(&p1), 1_000);
+    assert!(hub.try_get_session(&10).is_err());
+}
+
+#[test]
+fn test_invalid_points_rejected() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    let result = hub.try_start_game(&game, &11, &p1, &p2, &-1, &100);
+    assert_hub_error(&result, Error::InvalidPoints);
+    assert_hub_error(&hub.try_add_points(&p1, &0), Error::InvalidPoints);
+}
+
+#[test]
+fn test_zero_point_session() {
+    let (env, hub, game, _p1, _p2) = setup_test();
+    let p3 = Address::generate(&env);
+    let p4 = Address::generate(&env);
+
+    hub.start_game(&game, &12, &p3, &p4, &0, &0);
+    hub.end_game(&12, &true);
+    assert_eq!(hub.get_points(&p3), 0);
+}
+
+// ============================================================================
+// Stats & leaderboard
+// ============================================================================
+
+#[test]
+fn test_stats_track_results_and_points() {
+    let (env, hub, game, p1, p2) = setup_test();
+    let other_game = Address::generate(&env);
+    hub.add_game(&other_game);
+
+    hub.start_game(&game, &30, &p1, &p2, &100, &250);
+    hub.end_game(&30, &true);
+    hub.start_game(&other_game, &31, &p1, &p2, &50, &50);
+    hub.end_game(&31, &false);
+    hub.start_game(&game, &32, &p1, &p2, &10, &10);
+    hub.end_game_draw(&32);
+
+    assert_eq!(
+        hub.get_player_stats(&p1),
+        PlayerStats {
+            games_played: 3,
+            wins: 1,
+            losses: 1,
+            draws: 1,
+            points_won: 250,
+            points_lost: 50,
+        }
+    );
+    assert_eq!(
+        hub.get_player_stats(&p2),
+        PlayerStats {
+            games_played: 3,
+            wins: 1,
+            losses: 1,
+            draws: 1,
+            points_won: 50,
+            points_lost: 250,
+        }
+    );
+
+    let p1_game = hub.get_player_game_stats(&p1, &game);
+    assert_eq!(p1_game.games_played, 2);
+    assert_eq!(p1_game.wins, 1);
+    assert_eq!(p1_game.draws, 1);
+    assert_eq!(p1_game.points_won, 250);
+
+    let p1_other = hub.get_player_game_stats(&p1, &other_game);
+    assert_eq!(p1_other.games_played, 1);
+    assert_eq!(p1_other.losses, 1);
+    assert_eq!(p1_other.points_lost, 50);
+}
+
+#[test]
+fn test_open_sessions_not_counted() {
+    let (_env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &33, &p1, &p2, &100, &100);
+    assert_eq!(hub.get_player_stats(&p1), PlayerStats::default());
+    assert_eq!(hub.get_leaderboard_len(), 0);
+}
+
+#[test]
+fn test_leaderboard_ordered_by_wins() {
+    let (env, hub, game, p1, p2) = setup_test();
+    let p3 = Address::generate(&env);
+
+    // p2 beats p1, then p3 beats p1 twice
+    hub.start_game(&game, &40, &p1, &p2, &0, &0);
+    hub.end_game(&40, &false);
+    hub.start_game(&game, &41, &p1, &p3, &0, &0);
+    hub.end_game(&41, &false);
+    hub.start_game(&game, &42, &p3, &p1, &0, &0);
+    hub.end_game(&42, &true);
+
+    let board = hub.get_leaderboard(&0, &10);
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap().player, p3);
+    assert_eq!(board.get(0).unwrap().stats.wins, 2);
+    assert_eq!(board.get(1).unwrap().player, p2);
+    assert_eq!(board.get(2).unwrap().player, p1);
+    assert_eq!(board.get(2).unwrap().rank, 3);
+
+    // Ties keep the player who got there first ahead
+    hub.start_game(&game, &43, &p1, &p2, &0, &0);
+    hub.end_game(&43, &false);
+    let board = hub.get_leaderboard(&0, &10);
+    assert_eq!(board.get(0).unwrap().player, p3);
+    assert_eq!(board.get(1).unwrap().player, p2);
+}
+
+#[test]
+fn test_leaderboard_pagination() {
+    let (env, hub, game, _p1, _p2) = setup_test();
+
+    for i in 0..6u32 {
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        hub.start_game(&game, &(50 + i), &a, &b, &0, &0);
+        hub.end_game(&(50 + i), &true);
+    }
+    assert_eq!(hub.get_leaderboard_len(), 12);
+
+    let page = hub.get_leaderboard(&4, &5);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().rank, 5);
+    assert_eq!(page.get(4).unwrap().rank, 9);
+
+    assert_eq!(hub.get_leaderboard(&10, &5).len(), 2);
+    assert_eq!(hub.get_leaderboard(&12, &5).len(), 0);
+    assert_eq!(hub.get_leaderboard(&0, &0).len(), 0);
+    assert_eq!(hub.get_leaderboard(&0, &u32::MAX).len(), 12);
+}
+
+#[test]
+fn test_leaderboard_bounded() {
+    let (env, hub, game, _p1, _p2) = setup_test();
+
+    // Fill the board with winners and losers, then add five more winners
+    let games = LEADERBOARD_SIZE / 2 + 5;
+    let mut losers = Vec::new(&env);
+    for i in 0..games {
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        hub.start_game(&game, &(100 + i), &winner, &loser, &0, &0);
+        hub.end_game(&(100 + i), &true);
+        losers.push_back(loser);
+    }
+    assert_eq!(hub.get_leaderboard_len(), LEADERBOARD_SIZE);
+
+    // Every winner is on the board, ahead of the remaining losers
+    let board = hub.get_leaderboard(&(games - 1), &2);
+    assert_eq!(board.get(0).unwrap().stats.wins, 1);
+    assert_eq!(board.get(1).unwrap().stats.wins, 0);
+
+    // The newest losers had no room; a win puts one back on
+    let late = losers.last().unwrap();
+    let newcomer = Address::generate(&env);
+    hub.start_game(&game, &999, &late, &newcomer, &0, &0);
+    hub.end_game(&999, &true);
+    assert_eq!(hub.get_leaderboard_len(), LEADERBOARD_SIZE);
+    let board = hub.get_leaderboard(&(games - 1), &2);
+    assert_eq!(board.get(1).unwrap().player, late);
+    assert_eq!(board.get(1).unwrap().rank, games + 1);
+}
+
+// ============================================================================
+// Ratings
+// ============================================================================
+
+const SCALE: i128 = 1_000_000_000_000;
+
+#[test]
+fn test_expected_score_fixed_point() {
+    assert_eq!(expected_score(1500, 1500), SCALE / 2);
+
+    // 400 points weaker: 1 / (1 + 10) = 0.090909...
+    let weak = expected_score(1500, 1900);
+    assert!((weak - 90_909_090_909).abs() < 1_000, "{}", weak);
+    assert_eq!(expected_score(1900, 1500) + weak, SCALE);
+
+    // 200 points: 1 / (1 + 10^0.5) = 0.240253...
+    let e = expected_score(1600, 1800);
+    assert!((e - 240_253_073_352).abs() < 1_000, "{}", e);
+
+    // Gaps are capped at 800
+    assert_eq!(expected_score(100, 2500), expected_score(1000, 1800));
+}
+
+#[test]
+fn test_elo_update() {
+    assert_eq!(elo_update(1500, 1500, SCALE), (1516, 1484));
+    assert_eq!(elo_update(1500, 1500, 0), (1484, 1516));
+    assert_eq!(elo_update(1500, 1500, SCALE / 2), (1500, 1500));
+
+    // Favourite gains little, underdog gains a lot
+    assert_eq!(elo_update(1900, 1500, SCALE), (1903, 1497));
+    assert_eq!(elo_update(1500, 1900, SCALE), (1529, 1871));
+
+    // Draw against a stronger player still gains points
+    assert_eq!(elo_update(1500, 1900, SCALE / 2), (1513, 1887));
+
+    // Floor
+    assert_eq!(elo_update(MIN_RATING, 200, 0).0, MIN_RATING);
+}
+
+#[test]
+fn test_ratings_updated_per_game() {
+    let (env, hub, game, p1, p2) = setup_test();
+    let other_game = Address::generate(&env);
+    hub.add_game(&other_game);
+
+    assert_eq!(hub.get_rating(&p1, &game), INITIAL_RATING);
+
+    hub.start_game(&game, &60, &p1, &p2, &100, &100);
+    assert_eq!(hub.get_rating(&p1, &game), INITIAL_RATING);
+    hub.end_game(&60, &true);
+    assert_eq!(hub.get_rating(&p1, &game), 1516);
+    assert_eq!(hub.get_rating(&p2, &game), 1484);
+
+    // Other games keep their own ratings
+    assert_eq!(hub.get_rating(&p1, &other_game), INITIAL_RATING);
+    hub.start_game(&other_game, &61, &p1, &p2, &0, &0);
+    hub.end_game(&61, &false);
+    assert_eq!(hub.get_rating(&p1, &other_game), 1484);
+    assert_eq!(hub.get_rating(&p1, &game), 1516);
+
+    // A draw pulls the two ratings together
+    hub.start_game(&game, &62, &p1, &p2, &0, &0);
+    hub.end_game_draw(&62);
+    assert_eq!(hub.get_rating(&p1, &game), 1515);
+    assert_eq!(hub.get_rating(&p2, &game), 1485);
+}
+
+// ============================================================================
+// Authorization
+// ============================================================================
+
+#[test]
+fn test_game_must_authorize_start_and_end() {
+    let (env, hub, game, p1, p2) = setup_test();
+
+    hub.start_game(&game, &20, &p1, &p2, &100, &100);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game);
+    assert_eq!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract((
+            hub.address.clone(),
+            Symbol::new(&env, "start_game"),
+            (&game, 20u32, &p1, &p2, 100i128, 100i128).into_val(&env),
+        ))
+    );
+
+    hub.end_game(&20, &true);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game);
+}
+
+#[test]
+fn test_unauthorized_caller_rejected() {
+    let (env, hub, game, p1, p2) = setup_test();
+    hub.start_game(&game, &21, &p1, &p2, &100, &100);
+
+    env.set_auths(&[]);
+    assert!(hub.try_start_game(&game, &22, &p1, &p2, &100, &100).is_err());
+    assert!(hub.try_end_game(&21, &false).is_err());
+    assert!(hub.try_add_game(&p1).is_err());
+    assert!(hub.try_add_points(&p1, &1_000_000).is_err());
+
+    assert_eq!(hub.get_session(&21).status, SessionStatus::Open);
+    assert_eq!(hub.get_points(&p2), 900);
+}
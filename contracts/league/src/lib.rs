@@ -0,0 +1,713 @@
+#![no_std]
+
+//! # Round-Robin League
+//!
+//! Weekly leagues for any game registered with the Game Hub.  Every
+//! registered player meets every other player once, and standings follow
+//! the results the hub records on `end_game` / `end_game_draw`.
+//!
+//! 1. **Register** – the creator picks the game contract, a player cap, the
+//!    points for a win, draw and loss, and the tiebreakers.  Players join
+//!    while registration is open.
+//! 2. **Schedule** – starting the league fixes the fixture list with the
+//!    circle method, so each round pairs every player at most once (one
+//!    sits out per round with an odd field).
+//! 3. **Link** – the players start their game on the game contract as
+//!    usual.  While the hub session is still open, one of them (or the
+//!    admin) links it to the fixture of its two players.
+//! 4. **Record** – once the hub reports the session as ended, anyone
+//!    records the result and the standings are updated.
+//!
+//! Linking while the session is open keeps a game played before the
+//! league, or for another fixture, from being counted.  The league ends
+//! when every fixture is recorded, or earlier when the creator closes it.
+
+use game_core::{admin, hub, Admin};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
+    Env, Vec,
+};
+
+// ============================================================================
+// External Interfaces
+// ============================================================================
+
+/// Mirror of the Game Hub's session status.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Open,
+    Player1Won,
+    Player2Won,
+    Draw,
+}
+
+/// Mirror of the Game Hub's session record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubSession {
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub status: SessionStatus,
+}
+
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn get_session(env: Env, session_id: u32) -> HubSession;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    LeagueNotFound     = 1,
+    InvalidPlayerCount = 2,
+    InvalidScoring     = 3,
+    RegistrationClosed = 4,
+    LeagueFull         = 5,
+    AlreadyRegistered  = 6,
+    NotEnoughPlayers   = 7,
+    LeagueNotRunning   = 8,
+    SessionNotFound    = 9,
+    WrongGame          = 10,
+    FixtureNotFound    = 11,
+    FixtureLinked      = 12,
+    SessionNotOpen     = 13,
+    SessionNotLinked   = 14,
+    GameNotFinished    = 15,
+    HubNotSet          = 16,
+    NotAuthorized      = 17,
+    RepeatedTiebreaker = 18,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct LeagueCreated {
+    pub league_id: u32,
+    pub creator: Address,
+    pub game_id: Address,
+    pub max_players: u32,
+}
+
+#[contractevent]
+pub struct PlayerJoined {
+    pub league_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct LeagueStarted {
+    pub league_id: u32,
+    pub fixtures: u32,
+}
+
+#[contractevent]
+pub struct SessionLinked {
+    pub league_id: u32,
+    pub fixture: u32,
+    pub session_id: u32,
+}
+
+/// Published when a fixture's result is recorded; `winner` is `None` for a
+/// draw.
+#[contractevent]
+pub struct ResultRecorded {
+    pub league_id: u32,
+    pub fixture: u32,
+    pub winner: Option<Address>,
+}
+
+#[contractevent]
+pub struct LeagueFinished {
+    pub league_id: u32,
+    pub leader: Address,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeagueStatus {
+    Registration,
+    Running,
+    Finished,
+}
+
+/// League points awarded per result.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Scoring {
+    pub win: u32,
+    pub draw: u32,
+    pub loss: u32,
+}
+
+/// How players level on points are ordered, applied in the configured
+/// order.  Players still level keep registration order.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tiebreaker {
+    /// More wins ranks higher
+    Wins,
+    /// Fewer losses ranks higher
+    FewestLosses,
+    /// The winner of the two players' fixture ranks higher
+    HeadToHead,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct League {
+    pub creator: Address,
+    pub game_id: Address,
+    pub max_players: u32,
+    pub scoring: Scoring,
+    pub tiebreakers: Vec<Tiebreaker>,
+    pub players: Vec<Address>,
+    pub status: LeagueStatus,
+    /// Fixtures with a recorded result.
+    pub recorded: u32,
+}
+
+/// One scheduled pairing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fixture {
+    /// 0-based round of the schedule.
+    pub round: u32,
+    pub player1: Address,
+    pub player2: Address,
+    /// Hub session linked to this fixture.
+    pub session_id: Option<u32>,
+    pub recorded: bool,
+    /// `None` for a draw, or while unrecorded.
+    pub winner: Option<Address>,
+}
+
+/// A player's line in the table.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Standing {
+    pub player: Address,
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub points: u32,
+}
+
+/// League storage.  The admin and the Game Hub address are stored under
+/// `game_core::CoreKey`.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    League(u32),
+    Fixtures(u32),
+    Standings(u32),
+    NextLeagueId,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Largest field a league can hold (120 fixtures).
+const MAX_PLAYERS: u32 = 16;
+/// Kept for ~60 days after the last update.
+const LEAGUE_TTL_LEDGERS: u32 = 1_036_800;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn load_league(env: &Env, league_id: u32) -> Result<League, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::League(league_id))
+        .ok_or(Error::LeagueNotFound)
+}
+
+fn save_league(env: &Env, league_id: u32, league: &League) {
+    let key = DataKey::League(league_id);
+    env.storage().persistent().set(&key, league);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEAGUE_TTL_LEDGERS, LEAGUE_TTL_LEDGERS);
+}
+
+fn load_fixtures(env: &Env, league_id: u32) -> Vec<Fixture> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Fixtures(league_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_fixtures(env: &Env, league_id: u32, fixtures: &Vec<Fixture>) {
+    let key = DataKey::Fixtures(league_id);
+    env.storage().persistent().set(&key, fixtures);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEAGUE_TTL_LEDGERS, LEAGUE_TTL_LEDGERS);
+}
+
+/// Standings in registration order.
+fn load_standings(env: &Env, league_id: u32) -> Vec<Standing> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Standings(league_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_standings(env: &Env, league_id: u32, standings: &Vec<Standing>) {
+    let key = DataKey::Standings(league_id);
+    env.storage().persistent().set(&key, standings);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEAGUE_TTL_LEDGERS, LEAGUE_TTL_LEDGERS);
+}
+
+fn hub_address(env: &Env) -> Result<Address, Error> {
+    hub::address(env).map_err(|_| Error::HubNotSet)
+}
+
+fn load_session(env: &Env, session_id: u32) -> Result<HubSession, Error> {
+    GameHubClient::new(env, &hub_address(env)?)
+        .try_get_session(&session_id)
+        .ok()
+        .and_then(|session| session.ok())
+        .ok_or(Error::SessionNotFound)
+}
+
+/// Every pairing once, by the circle method: the first player stays put
+/// and the rest rotate one seat per round.  With an odd field an empty
+/// seat gives each player one round off.
+fn schedule(env: &Env, players: &Vec<Address>) -> Vec<Fixture> {
+    let mut seats: Vec<Option<Address>> = Vec::new(env);
+    for player in players.iter() {
+        seats.push_back(Some(player));
+    }
+    if seats.len() % 2 == 1 {
+        seats.push_back(None);
+    }
+
+    let seat_count = seats.len();
+    let mut fixtures = Vec::new(env);
+    for round in 0..seat_count - 1 {
+        for i in 0..seat_count / 2 {
+            let (home, away) = (seats.get_unchecked(i), seats.get_unchecked(seat_count - 1 - i));
+            // Alternate the fixed seat's side so nobody is always player 1
+            let (home, away) = if i == 0 && round % 2 == 1 {
+                (away, home)
+            } else {
+                (home, away)
+            };
+            if let (Some(player1), Some(player2)) = (home, away) {
+                fixtures.push_back(Fixture {
+                    round,
+                    player1,
+                    player2,
+                    session_id: None,
+                    recorded: false,
+                    winner: None,
+                });
+            }
+        }
+        let last = seats.pop_back_unchecked();
+        seats.insert(1, last);
+    }
+    fixtures
+}
+
+/// Winner of the fixture between `a` and `b`, if it has been decided.
+fn head_to_head(fixtures: &Vec<Fixture>, a: &Address, b: &Address) -> Option<Address> {
+    fixtures
+        .iter()
+        .find(|f| {
+            (f.player1 == *a && f.player2 == *b) || (f.player1 == *b && f.player2 == *a)
+        })
+        .and_then(|f| f.winner)
+}
+
+/// Whether `a` ranks strictly above `b`: more points, then the league's
+/// tiebreakers in order.
+fn ranks_above(league: &League, fixtures: &Vec<Fixture>, a: &Standing, b: &Standing) -> bool {
+    if a.points != b.points {
+        return a.points > b.points;
+    }
+    for tiebreaker in league.tiebreakers.iter() {
+        match tiebreaker {
+            Tiebreaker::Wins if a.wins != b.wins => return a.wins > b.wins,
+            Tiebreaker::FewestLosses if a.losses != b.losses => return a.losses < b.losses,
+            Tiebreaker::HeadToHead => {
+                if let Some(winner) = head_to_head(fixtures, &a.player, &b.player) {
+                    return winner == a.player;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The table, best first.  A stable insertion sort, so players level on
+/// every criterion keep registration order.
+fn sorted_standings(env: &Env, league_id: u32, league: &League) -> Vec<Standing> {
+    let fixtures = load_fixtures(env, league_id);
+    let mut table: Vec<Standing> = Vec::new(env);
+    for standing in load_standings(env, league_id).iter() {
+        let mut at = table.len();
+        while at > 0 && ranks_above(league, &fixtures, &standing, &table.get_unchecked(at - 1)) {
+            at -= 1;
+        }
+        table.insert(at, standing);
+    }
+    table
+}
+
+/// One player's side of a recorded fixture.
+#[derive(Clone, Copy)]
+enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// Add one result to a player's line.
+fn score(standings: &mut Vec<Standing>, scoring: &Scoring, player: &Address, outcome: Outcome) {
+    let Some(index) = standings.iter().position(|s| s.player == *player) else {
+        return;
+    };
+    let mut standing = standings.get_unchecked(index as u32);
+    standing.played += 1;
+    match outcome {
+        Outcome::Win => {
+            standing.wins += 1;
+            standing.points += scoring.win;
+        }
+        Outcome::Draw => {
+            standing.draws += 1;
+            standing.points += scoring.draw;
+        }
+        Outcome::Loss => {
+            standing.losses += 1;
+            standing.points += scoring.loss;
+        }
+    }
+    standings.set(index as u32, standing);
+}
+
+/// Close the league and announce the leader of the table.
+fn finish(env: &Env, league_id: u32, league: &mut League) {
+    league.status = LeagueStatus::Finished;
+    let leader = sorted_standings(env, league_id, league)
+        .get_unchecked(0)
+        .player;
+    LeagueFinished { league_id, leader }.publish(env);
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct LeagueContract;
+
+#[contractimpl]
+impl LeagueContract {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        admin::init(&env, &admin, &game_hub);
+    }
+
+    // --------------------------------------------------------- create_league
+
+    /// Open registration for a league on `game_id` and return its ID.
+    ///
+    /// `scoring` must not reward a draw over a win or a loss over a draw;
+    /// `tiebreakers` are applied in order and may not repeat.
+    pub fn create_league(
+        env: Env,
+        creator: Address,
+        game_id: Address,
+        max_players: u32,
+        scoring: Scoring,
+        tiebreakers: Vec<Tiebreaker>,
+    ) -> Result<u32, Error> {
+        creator.require_auth();
+
+        if !(2..=MAX_PLAYERS).contains(&max_players) {
+            return Err(Error::InvalidPlayerCount);
+        }
+        if scoring.win < scoring.draw || scoring.draw < scoring.loss {
+            return Err(Error::InvalidScoring);
+        }
+        for (i, tiebreaker) in tiebreakers.iter().enumerate() {
+            if tiebreakers.first_index_of(tiebreaker) != Some(i as u32) {
+                return Err(Error::RepeatedTiebreaker);
+            }
+        }
+
+        let league_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextLeagueId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextLeagueId, &(league_id + 1));
+
+        let league = League {
+            creator: creator.clone(),
+            game_id: game_id.clone(),
+            max_players,
+            scoring,
+            tiebreakers,
+            players: Vec::new(&env),
+            status: LeagueStatus::Registration,
+            recorded: 0,
+        };
+        save_league(&env, league_id, &league);
+
+        LeagueCreated {
+            league_id,
+            creator,
+            game_id,
+            max_players,
+        }
+        .publish(&env);
+        Ok(league_id)
+    }
+
+    // ------------------------------------------------------------------ join
+
+    /// Join a league during registration.
+    pub fn join(env: Env, league_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut league = load_league(&env, league_id)?;
+        if league.status != LeagueStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        if league.players.contains(&player) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if league.players.len() >= league.max_players {
+            return Err(Error::LeagueFull);
+        }
+
+        league.players.push_back(player.clone());
+        save_league(&env, league_id, &league);
+
+        PlayerJoined { league_id, player }.publish(&env);
+        Ok(())
+    }
+
+    // ---------------------------------------------------------- start_league
+
+    /// Close registration and schedule every pairing.
+    pub fn start_league(env: Env, league_id: u32) -> Result<(), Error> {
+        let mut league = load_league(&env, league_id)?;
+        league.creator.require_auth();
+        if league.status != LeagueStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        if league.players.len() < 2 {
+            return Err(Error::NotEnoughPlayers);
+        }
+
+        let fixtures = schedule(&env, &league.players);
+        let mut standings = Vec::new(&env);
+        for player in league.players.iter() {
+            standings.push_back(Standing {
+                player,
+                played: 0,
+                wins: 0,
+                draws: 0,
+                losses: 0,
+                points: 0,
+            });
+        }
+
+        league.status = LeagueStatus::Running;
+        save_fixtures(&env, league_id, &fixtures);
+        save_standings(&env, league_id, &standings);
+        save_league(&env, league_id, &league);
+
+        LeagueStarted {
+            league_id,
+            fixtures: fixtures.len(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // ---------------------------------------------------------- link_session
+
+    /// Attach an open hub session to the fixture of its two players.
+    /// `caller` must be one of the session's players or the admin; returns
+    /// the fixture index.
+    pub fn link_session(
+        env: Env,
+        league_id: u32,
+        session_id: u32,
+        caller: Address,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+
+        let league = load_league(&env, league_id)?;
+        if league.status != LeagueStatus::Running {
+            return Err(Error::LeagueNotRunning);
+        }
+
+        let session = load_session(&env, session_id)?;
+        if session.game_id != league.game_id {
+            return Err(Error::WrongGame);
+        }
+        if session.status != SessionStatus::Open {
+            return Err(Error::SessionNotOpen);
+        }
+        if caller != session.player1 && caller != session.player2 && caller != admin::address(&env)
+        {
+            return Err(Error::NotAuthorized);
+        }
+
+        let mut fixtures = load_fixtures(&env, league_id);
+        let index = fixtures
+            .iter()
+            .position(|f| {
+                (f.player1 == session.player1 && f.player2 == session.player2)
+                    || (f.player1 == session.player2 && f.player2 == session.player1)
+            })
+            .ok_or(Error::FixtureNotFound)? as u32;
+        let mut fixture = fixtures.get_unchecked(index);
+        if fixture.session_id.is_some() {
+            return Err(Error::FixtureLinked);
+        }
+
+        fixture.session_id = Some(session_id);
+        fixtures.set(index, fixture);
+        save_fixtures(&env, league_id, &fixtures);
+
+        SessionLinked {
+            league_id,
+            fixture: index,
+            session_id,
+        }
+        .publish(&env);
+        Ok(index)
+    }
+
+    // --------------------------------------------------------- record_result
+
+    /// Score a linked session once the hub has ended it.  Callable by
+    /// anyone; returns the winner (None on a draw).
+    pub fn record_result(
+        env: Env,
+        league_id: u32,
+        session_id: u32,
+    ) -> Result<Option<Address>, Error> {
+        let mut league = load_league(&env, league_id)?;
+        if league.status != LeagueStatus::Running {
+            return Err(Error::LeagueNotRunning);
+        }
+
+        let mut fixtures = load_fixtures(&env, league_id);
+        let index = fixtures
+            .iter()
+            .position(|f| f.session_id == Some(session_id) && !f.recorded)
+            .ok_or(Error::SessionNotLinked)? as u32;
+
+        let session = load_session(&env, session_id)?;
+        let (winner, first, second) = match session.status {
+            SessionStatus::Open => return Err(Error::GameNotFinished),
+            SessionStatus::Player1Won => (Some(session.player1.clone()), Outcome::Win, Outcome::Loss),
+            SessionStatus::Player2Won => (Some(session.player2.clone()), Outcome::Loss, Outcome::Win),
+            SessionStatus::Draw => (None, Outcome::Draw, Outcome::Draw),
+        };
+
+        let mut standings = load_standings(&env, league_id);
+        score(&mut standings, &league.scoring, &session.player1, first);
+        score(&mut standings, &league.scoring, &session.player2, second);
+
+        let mut fixture = fixtures.get_unchecked(index);
+        fixture.recorded = true;
+        fixture.winner = winner.clone();
+        fixtures.set(index, fixture);
+        league.recorded += 1;
+
+        save_fixtures(&env, league_id, &fixtures);
+        save_standings(&env, league_id, &standings);
+
+        ResultRecorded {
+            league_id,
+            fixture: index,
+            winner: winner.clone(),
+        }
+        .publish(&env);
+
+        if league.recorded == fixtures.len() {
+            finish(&env, league_id, &mut league);
+        }
+        save_league(&env, league_id, &league);
+        Ok(winner)
+    }
+
+    // ---------------------------------------------------------- close_league
+
+    /// End a running league early; unrecorded fixtures stay unplayed.
+    pub fn close_league(env: Env, league_id: u32) -> Result<(), Error> {
+        let mut league = load_league(&env, league_id)?;
+        league.creator.require_auth();
+        if league.status != LeagueStatus::Running {
+            return Err(Error::LeagueNotRunning);
+        }
+
+        finish(&env, league_id, &mut league);
+        save_league(&env, league_id, &league);
+        Ok(())
+    }
+
+    // --------------------------------------------------------------- queries
+
+    pub fn get_league(env: Env, league_id: u32) -> Result<League, Error> {
+        load_league(&env, league_id)
+    }
+
+    /// Every fixture, round by round; empty before the start.
+    pub fn get_fixtures(env: Env, league_id: u32) -> Result<Vec<Fixture>, Error> {
+        load_league(&env, league_id)?;
+        Ok(load_fixtures(&env, league_id))
+    }
+
+    /// The table, best first, under the league's scoring and tiebreakers.
+    pub fn get_standings(env: Env, league_id: u32) -> Result<Vec<Standing>, Error> {
+        let league = load_league(&env, league_id)?;
+        Ok(sorted_standings(&env, league_id, &league))
+    }
+}
+
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for LeagueContract {}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,409 @@
+#![cfg(test)]
+
+//! Tests for the round-robin league, run against the real Game Hub.
+
+use crate::{
+    Error, LeagueContract, LeagueContractClient, LeagueStatus, Scoring, Standing, Tiebreaker,
+};
+use dice_duel::{DiceDuelContract, DiceDuelContractClient};
+use game_hub::{GameHub, GameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    league: LeagueContractClient<'static>,
+    hub: GameHubClient<'static>,
+    /// Stand-in game contract approved on the hub.
+    game: Address,
+    creator: Address,
+    admin: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1_441_065_600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(GameHub, (&admin,));
+    let hub = GameHubClient::new(&env, &hub_addr);
+    let league_addr = env.register(LeagueContract, (&admin, &hub_addr));
+    let league = LeagueContractClient::new(&env, &league_addr);
+
+    let game = Address::generate(&env);
+    hub.add_game(&game);
+    let creator = Address::generate(&env);
+
+    Setup {
+        env,
+        league,
+        hub,
+        game,
+        creator,
+        admin,
+    }
+}
+
+fn standard_scoring() -> Scoring {
+    Scoring {
+        win: 3,
+        draw: 1,
+        loss: 0,
+    }
+}
+
+/// Create a 3-1-0 league on the stand-in game, join `count` players and
+/// start it.
+fn started_league(s: &Setup, count: u32, tiebreakers: Vec<Tiebreaker>) -> (u32, Vec<Address>) {
+    let league_id = s.league.create_league(
+        &s.creator,
+        &s.game,
+        &8,
+        &standard_scoring(),
+        &tiebreakers,
+    );
+    let mut players = Vec::new(&s.env);
+    for _ in 0..count {
+        let player = Address::generate(&s.env);
+        s.league.join(&league_id, &player);
+        players.push_back(player);
+    }
+    s.league.start_league(&league_id);
+    (league_id, players)
+}
+
+/// Play `a` against `b` on the hub and record it.  `winner` is 1 for `a`,
+/// 2 for `b` and 0 for a draw.
+fn play(s: &Setup, league_id: u32, session_id: u32, a: &Address, b: &Address, winner: u32) {
+    s.hub.start_game(&s.game, &session_id, a, b, &0, &0);
+    s.league.link_session(&league_id, &session_id, a);
+    match winner {
+        0 => s.hub.end_game_draw(&session_id),
+        w => s.hub.end_game(&session_id, &(w == 1)),
+    }
+    s.league.record_result(&league_id, &session_id);
+}
+
+fn assert_league_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected contract error {:?}", expected_error),
+    }
+}
+
+// ============================================================================
+// Registration and Scheduling
+// ============================================================================
+
+#[test]
+fn test_create_league_validation() {
+    let s = setup_test();
+
+    for max_players in [1, 17] {
+        let result = s.league.try_create_league(
+            &s.creator,
+            &s.game,
+            &max_players,
+            &standard_scoring(),
+            &Vec::new(&s.env),
+        );
+        assert_league_error(&result, Error::InvalidPlayerCount);
+    }
+
+    let draw_over_win = Scoring {
+        win: 1,
+        draw: 2,
+        loss: 0,
+    };
+    let result =
+        s.league
+            .try_create_league(&s.creator, &s.game, &4, &draw_over_win, &Vec::new(&s.env));
+    assert_league_error(&result, Error::InvalidScoring);
+
+    let repeated = vec![&s.env, Tiebreaker::Wins, Tiebreaker::Wins];
+    let result =
+        s.league
+            .try_create_league(&s.creator, &s.game, &4, &standard_scoring(), &repeated);
+    assert_league_error(&result, Error::RepeatedTiebreaker);
+}
+
+#[test]
+fn test_registration_rules() {
+    let s = setup_test();
+    let league_id =
+        s.league
+            .create_league(&s.creator, &s.game, &2, &standard_scoring(), &Vec::new(&s.env));
+
+    let (p1, p2, p3) = (
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    );
+    assert_league_error(&s.league.try_start_league(&league_id), Error::NotEnoughPlayers);
+
+    s.league.join(&league_id, &p1);
+    assert_league_error(&s.league.try_join(&league_id, &p1), Error::AlreadyRegistered);
+    s.league.join(&league_id, &p2);
+    assert_league_error(&s.league.try_join(&league_id, &p3), Error::LeagueFull);
+
+    s.league.start_league(&league_id);
+    assert_league_error(&s.league.try_join(&league_id, &p3), Error::RegistrationClosed);
+    assert_league_error(&s.league.try_start_league(&league_id), Error::RegistrationClosed);
+    assert_league_error(&s.league.try_get_league(&99), Error::LeagueNotFound);
+}
+
+#[test]
+fn test_schedule_pairs_everyone_once() {
+    let s = setup_test();
+
+    for count in [2u32, 5, 6] {
+        let (league_id, players) = started_league(&s, count, Vec::new(&s.env));
+        let fixtures = s.league.get_fixtures(&league_id);
+        assert_eq!(fixtures.len(), count * (count - 1) / 2);
+
+        for (i, a) in players.iter().enumerate() {
+            for b in players.iter().skip(i + 1) {
+                let meetings = fixtures
+                    .iter()
+                    .filter(|f| {
+                        (f.player1 == a && f.player2 == b) || (f.player1 == b && f.player2 == a)
+                    })
+                    .count();
+                assert_eq!(meetings, 1);
+            }
+        }
+
+        // Nobody plays twice in a round
+        let rounds = if count % 2 == 0 { count - 1 } else { count };
+        for round in 0..rounds {
+            let mut seen: Vec<Address> = Vec::new(&s.env);
+            for f in fixtures.iter().filter(|f| f.round == round) {
+                assert!(!seen.contains(&f.player1) && !seen.contains(&f.player2));
+                seen.push_back(f.player1);
+                seen.push_back(f.player2);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+#[test]
+fn test_results_update_standings_and_finish() {
+    let s = setup_test();
+    let (league_id, p) = started_league(&s, 3, Vec::new(&s.env));
+    let (a, b, c) = (p.get(0).unwrap(), p.get(1).unwrap(), p.get(2).unwrap());
+
+    play(&s, league_id, 1, &a, &b, 1);
+    play(&s, league_id, 2, &c, &a, 0);
+    assert_eq!(s.league.get_league(&league_id).status, LeagueStatus::Running);
+    play(&s, league_id, 3, &b, &c, 2);
+
+    let league = s.league.get_league(&league_id);
+    assert_eq!(league.status, LeagueStatus::Finished);
+    assert_eq!(league.recorded, 3);
+
+    let table = s.league.get_standings(&league_id);
+    assert_eq!(
+        table.get(0).unwrap(),
+        Standing {
+            player: a.clone(),
+            played: 2,
+            wins: 1,
+            draws: 1,
+            losses: 0,
+            points: 4,
+        }
+    );
+    assert_eq!(table.get(1).unwrap().player, c);
+    assert_eq!(table.get(1).unwrap().points, 4);
+    assert_eq!(table.get(2).unwrap().player, b);
+    assert_eq!(table.get(2).unwrap().losses, 2);
+
+    let result = s.league.try_record_result(&league_id, &3);
+    assert_league_error(&result, Error::LeagueNotRunning);
+}
+
+#[test]
+fn test_link_and_record_validation() {
+    let s = setup_test();
+    let (league_id, p) = started_league(&s, 3, Vec::new(&s.env));
+    let (a, b) = (p.get(0).unwrap(), p.get(1).unwrap());
+    let outsider = Address::generate(&s.env);
+
+    assert_league_error(&s.league.try_link_session(&league_id, &7, &a), Error::SessionNotFound);
+
+    // Another game's session
+    let other_game = Address::generate(&s.env);
+    s.hub.add_game(&other_game);
+    s.hub.start_game(&other_game, &1, &a, &b, &0, &0);
+    assert_league_error(&s.league.try_link_session(&league_id, &1, &a), Error::WrongGame);
+
+    // Not a league pairing
+    s.hub.start_game(&s.game, &2, &a, &outsider, &0, &0);
+    assert_league_error(&s.league.try_link_session(&league_id, &2, &a), Error::FixtureNotFound);
+
+    // Played before it was linked
+    s.hub.start_game(&s.game, &3, &a, &b, &0, &0);
+    s.hub.end_game(&3, &true);
+    assert_league_error(&s.league.try_link_session(&league_id, &3, &a), Error::SessionNotOpen);
+
+    s.hub.start_game(&s.game, &4, &b, &a, &0, &0);
+    assert_league_error(&s.league.try_record_result(&league_id, &4), Error::SessionNotLinked);
+    // Only the session's players or the admin may link it
+    let result = s.league.try_link_session(&league_id, &4, &outsider);
+    assert_league_error(&result, Error::NotAuthorized);
+    s.league.link_session(&league_id, &4, &s.admin);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert_league_error(&s.league.try_record_result(&league_id, &4), Error::GameNotFinished);
+
+    s.hub.start_game(&s.game, &5, &a, &b, &0, &0);
+    assert_league_error(&s.league.try_link_session(&league_id, &5, &a), Error::FixtureLinked);
+
+    s.hub.end_game(&4, &true);
+    assert_eq!(s.league.record_result(&league_id, &4), Some(b.clone()));
+    assert_league_error(&s.league.try_record_result(&league_id, &4), Error::SessionNotLinked);
+}
+
+#[test]
+fn test_tiebreakers_in_configured_order() {
+    let s = setup_test();
+
+    // a: W L D (4 pts), b: W W L (6), c: L L W (3), d: D W L (4)
+    // With Wins first a and d are level; head-to-head goes to the a-d winner.
+    let tiebreakers = vec![&s.env, Tiebreaker::Wins, Tiebreaker::HeadToHead];
+    let (league_id, p) = started_league(&s, 4, tiebreakers);
+    let (a, b, c, d) = (
+        p.get(0).unwrap(),
+        p.get(1).unwrap(),
+        p.get(2).unwrap(),
+        p.get(3).unwrap(),
+    );
+    play(&s, league_id, 1, &a, &c, 1);
+    play(&s, league_id, 2, &a, &b, 2);
+    play(&s, league_id, 3, &a, &d, 0);
+    play(&s, league_id, 4, &b, &c, 1);
+    play(&s, league_id, 5, &b, &d, 2);
+    play(&s, league_id, 6, &c, &d, 1);
+
+    let order: Vec<Address> = {
+        let mut order = Vec::new(&s.env);
+        for standing in s.league.get_standings(&league_id).iter() {
+            order.push_back(standing.player);
+        }
+        order
+    };
+    // b 6, then a and d on 4 with a drawn head-to-head: registration order
+    assert_eq!(order, vec![&s.env, b.clone(), a.clone(), d.clone(), c.clone()]);
+}
+
+#[test]
+fn test_head_to_head_breaks_tie() {
+    let s = setup_test();
+    let tiebreakers = vec![&s.env, Tiebreaker::HeadToHead];
+    let (league_id, p) = started_league(&s, 4, tiebreakers);
+    let (a, b, c, d) = (
+        p.get(0).unwrap(),
+        p.get(1).unwrap(),
+        p.get(2).unwrap(),
+        p.get(3).unwrap(),
+    );
+
+    // a and b both finish on 6 points; b won their meeting
+    play(&s, league_id, 1, &a, &b, 2);
+    play(&s, league_id, 2, &a, &c, 1);
+    play(&s, league_id, 3, &a, &d, 1);
+    play(&s, league_id, 4, &b, &c, 2);
+    play(&s, league_id, 5, &b, &d, 1);
+    play(&s, league_id, 6, &c, &d, 0);
+
+    let table = s.league.get_standings(&league_id);
+    assert_eq!(table.get(0).unwrap().player, b);
+    assert_eq!(table.get(1).unwrap().player, a);
+    assert_eq!(table.get(0).unwrap().points, table.get(1).unwrap().points);
+}
+
+#[test]
+fn test_close_league_early() {
+    let s = setup_test();
+    let (league_id, p) = started_league(&s, 3, Vec::new(&s.env));
+    let (a, b) = (p.get(0).unwrap(), p.get(1).unwrap());
+
+    play(&s, league_id, 1, &b, &a, 1);
+    s.league.close_league(&league_id);
+
+    let league = s.league.get_league(&league_id);
+    assert_eq!(league.status, LeagueStatus::Finished);
+    assert_eq!(league.recorded, 1);
+    assert_eq!(s.league.get_standings(&league_id).get(0).unwrap().player, b);
+    assert_league_error(&s.league.try_close_league(&league_id), Error::LeagueNotRunning);
+}
+
+// ============================================================================
+// Real Game
+// ============================================================================
+
+fn secret(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+fn secret_hash(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&Bytes::from_array(env, &secret.to_array()))
+        .into()
+}
+
+#[test]
+fn test_dice_duel_fixture() {
+    let s = setup_test();
+    let admin = Address::generate(&s.env);
+    let dice_addr = s.env.register(DiceDuelContract, (&admin, &s.hub.address));
+    let dice = DiceDuelContractClient::new(&s.env, &dice_addr);
+    s.hub.add_game(&dice_addr);
+
+    let league_id =
+        s.league
+            .create_league(&s.creator, &dice_addr, &2, &standard_scoring(), &Vec::new(&s.env));
+    let (p1, p2) = (Address::generate(&s.env), Address::generate(&s.env));
+    for player in [&p1, &p2] {
+        s.hub.add_points(player, &100);
+        s.league.join(&league_id, player);
+    }
+    s.league.start_league(&league_id);
+
+    let session_id = dice.start_game(&0, &p1, &p2, &10, &10);
+    s.league.link_session(&league_id, &session_id, &p2);
+
+    let (secret1, secret2) = (secret(&s.env, 1), secret(&s.env, 2));
+    dice.roll(&session_id, &p1, &secret_hash(&s.env, &secret1));
+    dice.roll(&session_id, &p2, &secret_hash(&s.env, &secret2));
+    dice.reveal_roll(&session_id, &p1, &secret1);
+    dice.reveal_roll(&session_id, &p2, &secret2);
+    let winner = dice.reveal_winner(&session_id);
+
+    assert_eq!(s.league.record_result(&league_id, &session_id), Some(winner.clone()));
+    let table = s.league.get_standings(&league_id);
+    assert_eq!(table.get(0).unwrap().player, winner);
+    assert_eq!(table.get(0).unwrap().points, 3);
+    assert_eq!(s.league.get_league(&league_id).status, LeagueStatus::Finished);
+}
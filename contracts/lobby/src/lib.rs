@@ -0,0 +1,571 @@
+#![no_std]
+
+//! # Matchmaking Lobby
+//!
+//! Lets two players agree on a session without co-signing one transaction.
+//!
+//! 1. **Post** – the host names a game contract approved by the Game Hub, a
+//!    session ID, stake token and amount, an optional invited opponent and
+//!    an expiry ledger.  The stake is transferred into the lobby.
+//! 2. **Accept** – any player (or only the invitee) matches the stake in a
+//!    separate transaction.  The lobby then calls `start_lobby_game` on the
+//!    game, which trusts the lobby's auth instead of both players'.
+//! 3. **Settle** – once the Game Hub reports the session as ended, anyone
+//!    can settle: the winner receives both stakes, a draw refunds them.
+//!    The session must belong to the challenge's game and players.
+//!
+//! An open challenge can be cancelled by its host at any time, and anyone
+//! can refund it to the host after it expires.  An accepted challenge whose
+//! hub session never opened, or expired before it ended, can be closed by
+//! anyone: it is paid out by the game's own `get_result` if the game has a
+//! winner, and refunded to both players otherwise.
+//!
+//! The lobby holds the stakes, so lobby sessions lock no Game Hub points.
+//! Stakes live in persistent storage so they cannot expire with the game.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, token,
+    Address, BytesN, Env,
+};
+
+// ============================================================================
+// External Interfaces
+// ============================================================================
+
+/// Entry points a game must expose to be started from the lobby.
+#[contractclient(name = "LobbyGameClient")]
+pub trait LobbyGame {
+    fn start_lobby_game(env: Env, session_id: u32, player1: Address, player2: Address);
+    fn get_result(env: Env, session_id: u32) -> GameResult;
+}
+
+/// Mirror of a game's `get_result` record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResult {
+    pub complete: bool,
+    pub winner: Option<Address>,
+}
+
+/// Mirror of the Game Hub's session status.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Open,
+    Player1Won,
+    Player2Won,
+    Draw,
+}
+
+/// Mirror of the Game Hub's session record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubSession {
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub status: SessionStatus,
+}
+
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn get_session(env: Env, session_id: u32) -> HubSession;
+
+    fn is_game(env: Env, game_id: Address) -> bool;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ChallengeNotFound = 1,
+    ChallengeExists   = 2,
+    ChallengeClosed   = 3,
+    ChallengeExpired  = 4,
+    NotExpired        = 5,
+    NotInvited        = 6,
+    CannotAcceptOwn   = 7,
+    InvalidStake      = 8,
+    InvalidExpiry     = 9,
+    GameNotFinished   = 10,
+    HubNotSet         = 11,
+    GameNotApproved   = 12,
+    SessionNotFound   = 13,
+    SessionMismatch   = 14,
+    SessionStarted    = 15,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct ChallengePosted {
+    pub session_id: u32,
+    pub host: Address,
+    pub game_id: Address,
+    pub stake: i128,
+    pub opponent: Option<Address>,
+    pub expires_at: u32,
+}
+
+#[contractevent]
+pub struct ChallengeAccepted {
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct ChallengeCancelled {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct ChallengeExpired {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct ChallengeRefunded {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct ChallengeSettled {
+    pub session_id: u32,
+    pub winner: Option<Address>,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChallengeStatus {
+    Open,
+    Accepted,
+    Cancelled,
+    Expired,
+    Settled,
+    Refunded,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub host: Address,
+    pub game_id: Address,
+    pub token: Address,
+    pub stake: i128,
+    /// Invited opponent while open; the accepting player afterwards.
+    pub opponent: Option<Address>,
+    /// Last ledger sequence at which the challenge can be accepted.
+    pub expires_at: u32,
+    pub status: ChallengeStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Challenge(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Challenges can stay open for at most ~30 days.
+const MAX_CHALLENGE_LEDGERS: u32 = 518_400;
+/// Kept long enough to settle after the longest open window plus a game.
+const CHALLENGE_TTL_LEDGERS: u32 = 2 * MAX_CHALLENGE_LEDGERS;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn load_challenge(env: &Env, session_id: u32) -> Result<Challenge, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Challenge(session_id))
+        .ok_or(Error::ChallengeNotFound)
+}
+
+fn save_challenge(env: &Env, session_id: u32, challenge: &Challenge) {
+    let key = DataKey::Challenge(session_id);
+    env.storage().persistent().set(&key, challenge);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+}
+
+fn hub_address(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .ok_or(Error::HubNotSet)
+}
+
+fn hub_client(env: &Env) -> Result<GameHubClient<'_>, Error> {
+    Ok(GameHubClient::new(env, &hub_address(env)?))
+}
+
+/// The hub session started for an accepted challenge.
+///
+/// Fails with `SessionNotFound` if the hub holds no session under the ID
+/// (it never opened or has expired) and with `SessionMismatch` if the
+/// session belongs to another game or pair of players.
+fn load_session(
+    env: &Env,
+    session_id: u32,
+    challenge: &Challenge,
+    opponent: &Address,
+) -> Result<HubSession, Error> {
+    let session = match hub_client(env)?.try_get_session(&session_id) {
+        Ok(Ok(session)) => session,
+        _ => return Err(Error::SessionNotFound),
+    };
+    if session.game_id != challenge.game_id
+        || session.player1 != challenge.host
+        || session.player2 != *opponent
+    {
+        return Err(Error::SessionMismatch);
+    }
+    Ok(session)
+}
+
+/// Pay `amount` of the challenge token out of the lobby.
+fn pay(env: &Env, challenge: &Challenge, to: &Address, amount: i128) {
+    if amount > 0 {
+        let contract = env.current_contract_address();
+        token::TokenClient::new(env, &challenge.token).transfer(&contract, to, &amount);
+    }
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct LobbyContract;
+
+#[contractimpl]
+impl LobbyContract {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+    }
+
+    // -------------------------------------------------------- post_challenge
+
+    /// Post an open challenge and escrow the host's stake.
+    ///
+    /// `opponent` restricts who may accept; `expires_at` is the last ledger
+    /// sequence at which the challenge can be accepted.
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_challenge(
+        env: Env,
+        host: Address,
+        game_id: Address,
+        session_id: u32,
+        token: Address,
+        stake: i128,
+        opponent: Option<Address>,
+        expires_at: u32,
+    ) -> Result<(), Error> {
+        host.require_auth();
+
+        if stake < 0 {
+            return Err(Error::InvalidStake);
+        }
+        let now = env.ledger().sequence();
+        if expires_at <= now || expires_at - now > MAX_CHALLENGE_LEDGERS {
+            return Err(Error::InvalidExpiry);
+        }
+        if opponent.as_ref() == Some(&host) {
+            return Err(Error::CannotAcceptOwn);
+        }
+        if !hub_client(&env)?.is_game(&game_id) {
+            return Err(Error::GameNotApproved);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Challenge(session_id))
+        {
+            return Err(Error::ChallengeExists);
+        }
+
+        if stake > 0 {
+            let contract = env.current_contract_address();
+            token::TokenClient::new(&env, &token).transfer(&host, &contract, &stake);
+        }
+
+        let challenge = Challenge {
+            host: host.clone(),
+            game_id: game_id.clone(),
+            token,
+            stake,
+            opponent: opponent.clone(),
+            expires_at,
+            status: ChallengeStatus::Open,
+        };
+        save_challenge(&env, session_id, &challenge);
+
+        ChallengePosted {
+            session_id,
+            host,
+            game_id,
+            stake,
+            opponent,
+            expires_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // ------------------------------------------------------ accept_challenge
+
+    /// Match the stake and start the session on the target game.
+    pub fn accept_challenge(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut challenge = load_challenge(&env, session_id)?;
+        if challenge.status != ChallengeStatus::Open {
+            return Err(Error::ChallengeClosed);
+        }
+        if env.ledger().sequence() > challenge.expires_at {
+            return Err(Error::ChallengeExpired);
+        }
+        if player == challenge.host {
+            return Err(Error::CannotAcceptOwn);
+        }
+        if let Some(invited) = &challenge.opponent {
+            if *invited != player {
+                return Err(Error::NotInvited);
+            }
+        }
+
+        if challenge.stake > 0 {
+            let contract = env.current_contract_address();
+            token::TokenClient::new(&env, &challenge.token).transfer(
+                &player,
+                &contract,
+                &challenge.stake,
+            );
+        }
+
+        challenge.opponent = Some(player.clone());
+        challenge.status = ChallengeStatus::Accepted;
+        save_challenge(&env, session_id, &challenge);
+
+        LobbyGameClient::new(&env, &challenge.game_id).start_lobby_game(
+            &session_id,
+            &challenge.host,
+            &player,
+        );
+
+        ChallengeAccepted { session_id, player }.publish(&env);
+        Ok(())
+    }
+
+    // ------------------------------------------------------ cancel / expire
+
+    /// Withdraw an open challenge and refund the host.
+    pub fn cancel_challenge(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut challenge = load_challenge(&env, session_id)?;
+        challenge.host.require_auth();
+        if challenge.status != ChallengeStatus::Open {
+            return Err(Error::ChallengeClosed);
+        }
+
+        challenge.status = ChallengeStatus::Cancelled;
+        save_challenge(&env, session_id, &challenge);
+        pay(&env, &challenge, &challenge.host, challenge.stake);
+
+        ChallengeCancelled { session_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Refund the host of a challenge nobody accepted in time.
+    /// Callable by anyone.
+    pub fn refund_expired(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut challenge = load_challenge(&env, session_id)?;
+        if challenge.status != ChallengeStatus::Open {
+            return Err(Error::ChallengeClosed);
+        }
+        if env.ledger().sequence() <= challenge.expires_at {
+            return Err(Error::NotExpired);
+        }
+
+        challenge.status = ChallengeStatus::Expired;
+        save_challenge(&env, session_id, &challenge);
+        pay(&env, &challenge, &challenge.host, challenge.stake);
+
+        ChallengeExpired { session_id }.publish(&env);
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- settle
+
+    /// Pay out an accepted challenge once the Game Hub has ended its
+    /// session.  Callable by anyone; returns the winner (None on a draw).
+    ///
+    /// The session must have been started by the challenge's game between
+    /// the host (player 1) and the accepting player (player 2).
+    pub fn settle(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let mut challenge = load_challenge(&env, session_id)?;
+        if challenge.status != ChallengeStatus::Accepted {
+            return Err(Error::ChallengeClosed);
+        }
+        let opponent = challenge.opponent.clone().expect("Accepted without opponent");
+
+        let session = load_session(&env, session_id, &challenge, &opponent)?;
+
+        let winner = match session.status {
+            SessionStatus::Open => return Err(Error::GameNotFinished),
+            SessionStatus::Player1Won => Some(challenge.host.clone()),
+            SessionStatus::Player2Won => Some(opponent.clone()),
+            SessionStatus::Draw => None,
+        };
+
+        challenge.status = ChallengeStatus::Settled;
+        save_challenge(&env, session_id, &challenge);
+        match &winner {
+            Some(w) => pay(&env, &challenge, w, challenge.stake * 2),
+            None => {
+                pay(&env, &challenge, &challenge.host, challenge.stake);
+                pay(&env, &challenge, &opponent, challenge.stake);
+            }
+        }
+
+        ChallengeSettled {
+            session_id,
+            winner: winner.clone(),
+        }
+        .publish(&env);
+        Ok(winner)
+    }
+
+    /// Close an accepted challenge that can never be settled: the hub
+    /// holds no session for it (the game never opened one, or it expired
+    /// before ending) or the session under its ID belongs to another game
+    /// or pair of players.  Callable by anyone.
+    ///
+    /// The game may have finished before its hub session expired, so its
+    /// `get_result` is checked first: if it names one of the players as the
+    /// winner they receive both stakes, otherwise both players are
+    /// refunded.  A game still in progress can no longer end without its
+    /// hub session, so it is refunded too.  Returns the winner paid, if any.
+    pub fn refund_orphaned(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let mut challenge = load_challenge(&env, session_id)?;
+        if challenge.status != ChallengeStatus::Accepted {
+            return Err(Error::ChallengeClosed);
+        }
+        let opponent = challenge.opponent.clone().expect("Accepted without opponent");
+
+        match load_session(&env, session_id, &challenge, &opponent) {
+            Ok(_) => return Err(Error::SessionStarted),
+            Err(Error::SessionNotFound) | Err(Error::SessionMismatch) => {}
+            Err(e) => return Err(e),
+        }
+
+        let game = LobbyGameClient::new(&env, &challenge.game_id);
+        let winner = match game.try_get_result(&session_id) {
+            Ok(Ok(result)) if result.complete => result
+                .winner
+                .filter(|w| *w == challenge.host || *w == opponent),
+            // Unfinished, or the game holds no session either
+            _ => None,
+        };
+
+        if let Some(w) = &winner {
+            challenge.status = ChallengeStatus::Settled;
+            save_challenge(&env, session_id, &challenge);
+            pay(&env, &challenge, w, challenge.stake * 2);
+
+            ChallengeSettled {
+                session_id,
+                winner: winner.clone(),
+            }
+            .publish(&env);
+            return Ok(winner);
+        }
+
+        challenge.status = ChallengeStatus::Refunded;
+        save_challenge(&env, session_id, &challenge);
+        pay(&env, &challenge, &challenge.host, challenge.stake);
+        pay(&env, &challenge, &opponent, challenge.stake);
+
+        ChallengeRefunded { session_id }.publish(&env);
+        Ok(None)
+    }
+
+    // --------------------------------------------------------------- queries
+
+    pub fn get_challenge(env: Env, session_id: u32) -> Result<Challenge, Error> {
+        load_challenge(&env, session_id)
+    }
+
+    // ----------------------------------------------------------------- admin
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn get_hub(env: Env) -> Result<Address, Error> {
+        hub_address(&env)
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,479 @@
+#![cfg(test)]
+
+//! Tests for the matchmaking lobby, run against the real Game Hub and CTM.
+
+use crate::{ChallengeStatus, DataKey, Error, GameResult, LobbyContract, LobbyContractClient};
+use ctm::{CtmContract, CtmContractClient, DrawPolicy, Phase};
+use game_hub::{DataKey as HubKey, GameHub, GameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+
+/// An approved game whose `start_lobby_game` never opens a session.
+#[contract]
+pub struct IdleGame;
+
+#[contractimpl]
+impl IdleGame {
+    pub fn start_lobby_game(_env: Env, _session_id: u32, _player1: Address, _player2: Address) {}
+
+    pub fn get_result(_env: Env, _session_id: u32) -> GameResult {
+        panic!("Game not found")
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    lobby: LobbyContractClient<'static>,
+    hub: GameHubClient<'static>,
+    ctm: CtmContractClient<'static>,
+    token: TokenClient<'static>,
+    host: Address,
+    guest: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1_441_065_600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(GameHub, (&admin,));
+    let hub = GameHubClient::new(&env, &hub_addr);
+    let ctm_addr = env.register(CtmContract, (&admin, &hub_addr));
+    let ctm = CtmContractClient::new(&env, &ctm_addr);
+    let lobby_addr = env.register(LobbyContract, (&admin, &hub_addr));
+    let lobby = LobbyContractClient::new(&env, &lobby_addr);
+    hub.add_game(&ctm_addr);
+    ctm.set_lobby(&lobby_addr);
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let asset = StellarAssetClient::new(&env, &sac.address());
+    let token = TokenClient::new(&env, &sac.address());
+
+    let host = Address::generate(&env);
+    let guest = Address::generate(&env);
+    for player in [&host, &guest] {
+        asset.mint(player, &1_000);
+        hub.add_points(player, &1_000);
+    }
+
+    Setup {
+        env,
+        lobby,
+        hub,
+        ctm,
+        token,
+        host,
+        guest,
+    }
+}
+
+/// Post a 100-token challenge for CTM expiring 1 000 ledgers from now.
+fn post(s: &Setup, session_id: u32, opponent: Option<Address>) {
+    s.lobby.post_challenge(
+        &s.host,
+        &s.ctm.address,
+        &session_id,
+        &s.token.address,
+        &100,
+        &opponent,
+        &(s.env.ledger().sequence() + 1_000),
+    );
+}
+
+/// Helper: assert a contract call returned a specific Error.
+fn assert_lobby_error<T: core::fmt::Debug, E: core::fmt::Debug>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected: Error,
+) {
+    match result {
+        Err(Ok(actual)) => {
+            assert_eq!(
+                *actual, expected,
+                "Expected {:?} but got {:?}",
+                expected, actual
+            );
+        }
+        other => panic!("Expected Err(Ok({:?})), got {:?}", expected, other),
+    }
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn salt(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+fn hands_hash(env: &Env, left: u32, right: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(left as u8);
+    pre.push_back(right as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+fn choice_hash(env: &Env, choice: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(choice as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+// ============================================================================
+// Post / accept
+// ============================================================================
+
+#[test]
+fn test_post_and_accept_starts_game() {
+    let s = setup_test();
+
+    post(&s, 1, None);
+    assert_eq!(s.token.balance(&s.host), 900);
+    assert_eq!(s.lobby.get_challenge(&1).status, ChallengeStatus::Open);
+
+    s.lobby.accept_challenge(&1, &s.guest);
+
+    // Only the accepting player signs the second transaction
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, s.guest);
+
+    assert_eq!(s.token.balance(&s.guest), 900);
+    assert_eq!(s.token.balance(&s.lobby.address), 200);
+
+    let challenge = s.lobby.get_challenge(&1);
+    assert_eq!(challenge.status, ChallengeStatus::Accepted);
+    assert_eq!(challenge.opponent, Some(s.guest.clone()));
+
+    let game = s.ctm.get_game(&1);
+    assert_eq!(game.player1, s.host);
+    assert_eq!(game.player2, s.guest);
+    assert_eq!(game.phase, Phase::CommitHands);
+
+    // The lobby holds the stakes, so no hub points are locked as well
+    assert_eq!(game.player1_points, 0);
+    assert_eq!(s.hub.get_points(&s.host), 1_000);
+}
+
+#[test]
+fn test_invited_opponent_only() {
+    let s = setup_test();
+    let stranger = Address::generate(&s.env);
+
+    post(&s, 2, Some(s.guest.clone()));
+    assert_lobby_error(
+        &s.lobby.try_accept_challenge(&2, &stranger),
+        Error::NotInvited,
+    );
+    s.lobby.accept_challenge(&2, &s.guest);
+}
+
+#[test]
+fn test_cannot_accept_own_or_closed_challenge() {
+    let s = setup_test();
+
+    post(&s, 3, None);
+    assert_lobby_error(
+        &s.lobby.try_accept_challenge(&3, &s.host),
+        Error::CannotAcceptOwn,
+    );
+    s.lobby.accept_challenge(&3, &s.guest);
+
+    let third = Address::generate(&s.env);
+    assert_lobby_error(
+        &s.lobby.try_accept_challenge(&3, &third),
+        Error::ChallengeClosed,
+    );
+    assert_lobby_error(&s.lobby.try_accept_challenge(&4, &third), Error::ChallengeNotFound);
+}
+
+#[test]
+fn test_post_validation() {
+    let s = setup_test();
+    let now = s.env.ledger().sequence();
+
+    let post_with = |session_id: u32, stake: i128, opponent: Option<Address>, expires_at: u32| {
+        s.lobby.try_post_challenge(
+            &s.host,
+            &s.ctm.address,
+            &session_id,
+            &s.token.address,
+            &stake,
+            &opponent,
+            &expires_at,
+        )
+    };
+
+    assert_lobby_error(&post_with(5, -1, None, now + 10), Error::InvalidStake);
+    assert_lobby_error(&post_with(5, 100, None, now), Error::InvalidExpiry);
+    assert_lobby_error(&post_with(5, 100, None, now + 518_401), Error::InvalidExpiry);
+    assert_lobby_error(
+        &post_with(5, 100, Some(s.host.clone()), now + 10),
+        Error::CannotAcceptOwn,
+    );
+
+    let unapproved = s.env.register(IdleGame, ());
+    assert_lobby_error(
+        &s.lobby.try_post_challenge(
+            &s.host,
+            &unapproved,
+            &5,
+            &s.token.address,
+            &100,
+            &None,
+            &(now + 10),
+        ),
+        Error::GameNotApproved,
+    );
+
+    post(&s, 5, None);
+    assert_lobby_error(&post_with(5, 100, None, now + 10), Error::ChallengeExists);
+}
+
+// ============================================================================
+// Cancel / expiry
+// ============================================================================
+
+#[test]
+fn test_cancel_refunds_host() {
+    let s = setup_test();
+
+    post(&s, 10, None);
+    s.lobby.cancel_challenge(&10);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.lobby.get_challenge(&10).status, ChallengeStatus::Cancelled);
+
+    assert_lobby_error(&s.lobby.try_cancel_challenge(&10), Error::ChallengeClosed);
+    assert_lobby_error(
+        &s.lobby.try_accept_challenge(&10, &s.guest),
+        Error::ChallengeClosed,
+    );
+}
+
+#[test]
+fn test_cannot_cancel_accepted_challenge() {
+    let s = setup_test();
+
+    post(&s, 11, None);
+    s.lobby.accept_challenge(&11, &s.guest);
+    assert_lobby_error(&s.lobby.try_cancel_challenge(&11), Error::ChallengeClosed);
+    assert_eq!(s.token.balance(&s.lobby.address), 200);
+}
+
+#[test]
+fn test_expired_challenge_refund() {
+    let s = setup_test();
+
+    post(&s, 12, None);
+    assert_lobby_error(&s.lobby.try_refund_expired(&12), Error::NotExpired);
+
+    advance_ledgers(&s.env, 1_001);
+    assert_lobby_error(
+        &s.lobby.try_accept_challenge(&12, &s.guest),
+        Error::ChallengeExpired,
+    );
+
+    s.lobby.refund_expired(&12);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.lobby.get_challenge(&12).status, ChallengeStatus::Expired);
+    assert_lobby_error(&s.lobby.try_refund_expired(&12), Error::ChallengeClosed);
+}
+
+// ============================================================================
+// Settlement
+// ============================================================================
+
+#[test]
+fn test_settle_pays_winner() {
+    let s = setup_test();
+
+    post(&s, 20, None);
+    s.lobby.accept_challenge(&20, &s.guest);
+    assert_lobby_error(&s.lobby.try_settle(&20), Error::GameNotFinished);
+
+    // Guest stalls; host wins by timeout
+    s.ctm
+        .commit_hands(&20, &s.host, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    advance_ledgers(&s.env, 17_281);
+    s.ctm.claim_timeout(&20, &s.host);
+
+    assert_eq!(s.lobby.settle(&20), Some(s.host.clone()));
+    assert_eq!(s.token.balance(&s.host), 1_100);
+    assert_eq!(s.token.balance(&s.guest), 900);
+    assert_eq!(s.token.balance(&s.lobby.address), 0);
+    assert_eq!(s.lobby.get_challenge(&20).status, ChallengeStatus::Settled);
+
+    assert_lobby_error(&s.lobby.try_settle(&20), Error::ChallengeClosed);
+}
+
+#[test]
+fn test_settle_draw_refunds_both() {
+    let s = setup_test();
+    let env = &s.env;
+    s.ctm.set_draw_policy(&DrawPolicy::TrueDraw);
+
+    post(&s, 21, None);
+    s.lobby.accept_challenge(&21, &s.guest);
+
+    let (s1, s2) = (salt(env, 1), salt(env, 2));
+    s.ctm.commit_hands(&21, &s.host, &hands_hash(env, 0, 1, &s1));
+    s.ctm.commit_hands(&21, &s.guest, &hands_hash(env, 0, 2, &s1));
+    s.ctm.reveal_hands(&21, &s.host, &0, &1, &s1);
+    s.ctm.reveal_hands(&21, &s.guest, &0, &2, &s1);
+    s.ctm.commit_choice(&21, &s.host, &choice_hash(env, 0, &s2));
+    s.ctm.commit_choice(&21, &s.guest, &choice_hash(env, 0, &s2));
+    s.ctm.reveal_choice(&21, &s.host, &0, &s2);
+    s.ctm.reveal_choice(&21, &s.guest, &0, &s2);
+
+    assert_eq!(s.lobby.settle(&21), None);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.token.balance(&s.guest), 1_000);
+}
+
+#[test]
+fn test_settle_without_hub() {
+    let s = setup_test();
+
+    post(&s, 22, None);
+    s.lobby.accept_challenge(&22, &s.guest);
+    s.env.as_contract(&s.lobby.address, || {
+        s.env.storage().instance().remove(&DataKey::GameHubAddress);
+    });
+
+    assert_lobby_error(&s.lobby.try_get_hub(), Error::HubNotSet);
+    assert_lobby_error(&s.lobby.try_settle(&22), Error::HubNotSet);
+}
+
+// ============================================================================
+// Orphaned sessions
+// ============================================================================
+
+/// Post a challenge for an approved game that never opens its session, and
+/// have the guest accept it.
+fn accept_idle(s: &Setup, session_id: u32) {
+    let idle = s.env.register(IdleGame, ());
+    s.hub.add_game(&idle);
+    s.lobby.post_challenge(
+        &s.host,
+        &idle,
+        &session_id,
+        &s.token.address,
+        &100,
+        &None,
+        &(s.env.ledger().sequence() + 1_000),
+    );
+    s.lobby.accept_challenge(&session_id, &s.guest);
+}
+
+#[test]
+fn test_settle_rejects_unrelated_session() {
+    let s = setup_test();
+    accept_idle(&s, 30);
+
+    // An unrelated CTM session under the same ID, won by player 1
+    let stranger = Address::generate(&s.env);
+    s.hub.add_points(&stranger, &1_000);
+    s.ctm.start_game(&30, &s.host, &stranger, &10, &10);
+    s.ctm
+        .commit_hands(&30, &s.host, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    advance_ledgers(&s.env, 17_281);
+    s.ctm.claim_timeout(&30, &s.host);
+
+    assert_lobby_error(&s.lobby.try_settle(&30), Error::SessionMismatch);
+    assert_eq!(s.token.balance(&s.lobby.address), 200);
+
+    s.lobby.refund_orphaned(&30);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.token.balance(&s.guest), 1_000);
+    assert_eq!(s.lobby.get_challenge(&30).status, ChallengeStatus::Refunded);
+    assert_lobby_error(&s.lobby.try_refund_orphaned(&30), Error::ChallengeClosed);
+}
+
+#[test]
+fn test_refund_session_never_opened() {
+    let s = setup_test();
+    accept_idle(&s, 31);
+
+    assert_lobby_error(&s.lobby.try_settle(&31), Error::SessionNotFound);
+    s.lobby.refund_orphaned(&31);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.token.balance(&s.guest), 1_000);
+    assert_eq!(s.token.balance(&s.lobby.address), 0);
+}
+
+#[test]
+fn test_cannot_refund_started_session() {
+    let s = setup_test();
+
+    post(&s, 32, None);
+    assert_lobby_error(&s.lobby.try_refund_orphaned(&32), Error::ChallengeClosed);
+    s.lobby.accept_challenge(&32, &s.guest);
+    assert_lobby_error(&s.lobby.try_refund_orphaned(&32), Error::SessionStarted);
+    assert_eq!(s.token.balance(&s.lobby.address), 200);
+}
+
+/// Drop a session from the hub's storage, as if it had expired.
+fn expire_hub_session(s: &Setup, session_id: u32) {
+    s.env.as_contract(&s.hub.address, || {
+        s.env.storage().temporary().remove(&HubKey::Session(session_id));
+    });
+}
+
+#[test]
+fn test_orphaned_session_paid_by_game_result() {
+    let s = setup_test();
+
+    // Host wins by timeout, then the hub session expires before settling
+    post(&s, 33, None);
+    s.lobby.accept_challenge(&33, &s.guest);
+    s.ctm
+        .commit_hands(&33, &s.host, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    advance_ledgers(&s.env, 17_281);
+    s.ctm.claim_timeout(&33, &s.host);
+    expire_hub_session(&s, 33);
+    assert_lobby_error(&s.lobby.try_settle(&33), Error::SessionNotFound);
+
+    // The game's own result decides the payout
+
+    assert_eq!(s.lobby.refund_orphaned(&33), Some(s.host.clone()));
+    assert_eq!(s.token.balance(&s.host), 1_100);
+    assert_eq!(s.token.balance(&s.guest), 900);
+    assert_eq!(s.lobby.get_challenge(&33).status, ChallengeStatus::Settled);
+}
+
+#[test]
+fn test_orphaned_unfinished_game_refunds_both() {
+    let s = setup_test();
+
+    // Without its hub session the game can no longer end
+    post(&s, 34, None);
+    s.lobby.accept_challenge(&34, &s.guest);
+    s.ctm
+        .commit_hands(&34, &s.host, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    expire_hub_session(&s, 34);
+    advance_ledgers(&s.env, 17_281);
+    assert!(s.ctm.try_claim_timeout(&34, &s.host).is_err());
+
+    assert_eq!(s.lobby.refund_orphaned(&34), None);
+    assert_eq!(s.token.balance(&s.host), 1_000);
+    assert_eq!(s.token.balance(&s.guest), 1_000);
+    assert_eq!(s.lobby.get_challenge(&34).status, ChallengeStatus::Refunded);
+}
@@ -1,12 +1,14 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, symbol_short, Address, Env, Symbol};
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
 /// (start_game, end_game) but does nothing internally. It exists purely
 /// for game contracts to compile and integrate during development.
+///
+/// The only state is a counter so `reserve_session_id` hands out fresh IDs.
 #[contract]
 pub struct MockGameHub;
 
@@ -26,6 +28,13 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+const NEXT_SESSION_ID: Symbol = symbol_short!("next_id");
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -56,10 +65,34 @@ impl MockGameHub {
             player2_points,
         }
         .publish(&env);
+        let next: u32 = env.storage().instance().get(&NEXT_SESSION_ID).unwrap_or(1);
+        if session_id >= next {
+            env.storage()
+                .instance()
+                .set(&NEXT_SESSION_ID, &session_id.saturating_add(1));
+        }
         // bump instance ttl if required
         env.storage().instance().extend_ttl(17_280, 518_400);
     }
 
+    /// Allocate a session ID: one past the highest ID started or reserved
+    /// so far
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract reserving the ID (ignored in mock)
+    pub fn reserve_session_id(env: Env, _game_id: Address) -> u32 {
+        let session_id = Self::next_session_id(env.clone());
+        env.storage()
+            .instance()
+            .set(&NEXT_SESSION_ID, &session_id.saturating_add(1));
+        session_id
+    }
+
+    /// Session ID `reserve_session_id` would allocate next
+    pub fn next_session_id(env: Env) -> u32 {
+        env.storage().instance().get(&NEXT_SESSION_ID).unwrap_or(1)
+    }
+
     /// End a game session and declare winner
     ///
     /// # Arguments
@@ -77,6 +110,15 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session with no winner
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameDrawn { session_id }.publish(&env);
+    }
 }
 
 #[cfg(test)]
@@ -86,12 +128,18 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
         let player2 = Address::generate(&env);
+        assert_eq!(client.next_session_id(), 1);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        assert_eq!(client.next_session_id(), 2);
         client.end_game(&1, &true);
+        client.start_game(&game_id, &2, &player1, &player2, &1000, &1000);
+        client.end_game_draw(&2);
+        assert_eq!(client.reserve_session_id(&game_id), 3);
+        assert_eq!(client.next_session_id(), 4);
     }
 }
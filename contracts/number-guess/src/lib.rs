@@ -5,35 +5,34 @@
 //! A simple two-player guessing game where players guess a number between 1 and 10.
 //! The player whose guess is closest to the randomly generated number wins.
 //!
+//! **Commit-Reveal:**
+//! `make_guess` stores only `keccak256(guess || salt)`, so neither player can
+//! see the other's guess.  Once both have committed, each reveals the guess
+//! and salt with `reveal_guess`.  The winning number is seeded from both
+//! salts, so it cannot be computed until both players have revealed.
+//!
+//! Each player has until `commit_deadline` to commit and, once both have
+//! committed, until `reveal_deadline` to reveal a guess between 1 and 10.
+//! After either deadline `claim_timeout` settles the game: a player who made
+//! the move beats one who did not, and if neither did the session ends as a
+//! draw.  A commitment to an out-of-range guess can never be revealed, so it
+//! loses the same way.
+//!
+//! **Lobby:**
+//! The admin can register a matchmaking lobby with `set_lobby`.  It starts
+//! sessions with `start_lobby_game` once a posted challenge is accepted and
+//! reads the outcome back with `get_result`.  The lobby holds the stakes, so
+//! lobby sessions lock no Game Hub points.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use game_core::{admin, hub, lobby, session, Admin, CoreError, GameResult, Lobby};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -47,6 +46,26 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    HashMismatch = 6,
+    AlreadyRevealed = 7,
+    BothPlayersNotRevealed = 8,
+    InvalidGuess = 9,
+    SelfPlay = 10,
+    HubNotSet = 11,
+    SessionExists = 12,
+    DeadlineNotReached = 13,
+    CannotClaimTimeout = 14,
+    LobbyNotSet = 15,
+}
+
+impl From<CoreError> for Error {
+    fn from(err: CoreError) -> Self {
+        match err {
+            CoreError::SelfPlay => Error::SelfPlay,
+            CoreError::HubNotSet => Error::HubNotSet,
+            CoreError::SessionExists => Error::SessionExists,
+        }
+    }
 }
 
 // ============================================================================
@@ -60,29 +79,44 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub player1_commit: Option<BytesN<32>>,
+    pub player2_commit: Option<BytesN<32>>,
+    /// Revealed guesses (None until `reveal_guess`)
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
+    pub player1_salt: Option<BytesN<32>>,
+    pub player2_salt: Option<BytesN<32>>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
-}
-
-#[contracttype]
-#[derive(Clone)]
-pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
+    /// Ledger sequence after which `claim_timeout` can settle a game still
+    /// missing a commitment; set when the game starts
+    pub commit_deadline: u32,
+    /// Ledger sequence after which `claim_timeout` can settle a game still
+    /// missing a reveal; 0 until both players have committed
+    pub reveal_deadline: u32,
+    /// Neither player committed or revealed in time and the session was drawn
+    pub forfeited: bool,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Helper Functions
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Build the keccak-256 hash used for a guess commitment.
+///
+/// `preimage = guess(1 byte) || salt(32 bytes)`, the same layout as CTM's
+/// choice commitment.
+fn hash_guess(env: &Env, guess: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(guess as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+/// Whether the game has been settled, with a winner or as a double forfeit.
+fn has_ended(game: &Game) -> bool {
+    game.winner.is_some() || game.forfeited
+}
 
 // ============================================================================
 // Contract Definition
@@ -100,10 +134,7 @@ impl NumberGuessContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -113,11 +144,14 @@ impl NumberGuessContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    ///
+    /// # Returns
+    /// * `u32` - The session ID of the new game
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -125,116 +159,232 @@ impl NumberGuessContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
-        if player1 == player2 {
-            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
-        }
+        session::require_distinct_players(&player1, &player2)?;
 
         // Require authentication from both players (they consent to committing points)
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game (winning_number not set yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
-            player1_points,
-            player2_points,
-            player1_guess: None,
-            player2_guess: None,
-            winning_number: None,
-            winner: None,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        // Use the requested ID unless it already holds a game, or allocate one
+        let session_id = session::claim(&env, session_id)?;
+        Self::create_game(&env, session_id, player1, player2, player1_points, player2_points)?;
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(session_id)
+    }
 
-        // Event emitted by the Game Hub contract (GameStarted)
+    /// Start a new game on behalf of the registered lobby.
+    ///
+    /// Only the lobby's auth is required: the players already authorized
+    /// their stakes when posting and accepting the challenge there.  The
+    /// lobby holds those stakes, so no points are locked.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session ID the lobby keyed the challenge by
+    /// * `player1` - Address of the challenge's host
+    /// * `player2` - Address of the player who accepted
+    pub fn start_lobby_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+    ) -> Result<(), Error> {
+        let lobby = lobby::address(&env).ok_or(Error::LobbyNotSet)?;
+        lobby.require_auth();
+        session::require_distinct_players(&player1, &player2)?;
+        // The lobby keys its challenge by this ID, so never reallocate it
+        session::require_new(&env, session_id)?;
 
-        Ok(())
+        Self::create_game(&env, session_id, player1, player2, 0, 0)
     }
 
-    /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Commit a guess for the current game.
+    /// Players guess a number between 1 and 10; only the hash is stored.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player making the guess
-    /// * `guess` - The guessed number (1-10)
-    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+    /// * `guess_hash` - keccak256(guess(1 byte) || salt(32 bytes))
+    pub fn make_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess_hash: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
-        // Validate guess is in range
-        if guess < 1 || guess > 10 {
-            panic!("Guess must be between 1 and 10");
-        }
-
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update guess for the appropriate player
+        // Store the commitment for the appropriate player
         if player == game.player1 {
-            if game.player1_guess.is_some() {
+            if game.player1_commit.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.player1_guess = Some(guess);
+            game.player1_commit = Some(guess_hash);
         } else if player == game.player2 {
-            if game.player2_guess.is_some() {
+            if game.player2_commit.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.player2_guess = Some(guess);
+            game.player2_commit = Some(guess_hash);
         } else {
             return Err(Error::NotPlayer);
         }
 
+        // Both commitments are in: the reveal window opens
+        if game.player1_commit.is_some() && game.player2_commit.is_some() {
+            game.reveal_deadline = session::reveal_deadline(&env);
+        }
+
         // Store updated game in temporary storage
-        env.storage().temporary().set(&key, &game);
+        session::save(&env, session_id, &game);
 
         // No event emitted - game state can be queried via get_game()
 
         Ok(())
     }
 
+    /// Reveal a committed guess.
+    /// Only allowed once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `guess` - The committed guess (1-10)
+    /// * `salt` - The salt used in the commitment
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.player1_commit.is_none() || game.player2_commit.is_none() {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+
+        let (commit, revealed, stored_salt) = if player == game.player1 {
+            (
+                game.player1_commit.clone(),
+                &mut game.player1_guess,
+                &mut game.player1_salt,
+            )
+        } else if player == game.player2 {
+            (
+                game.player2_commit.clone(),
+                &mut game.player2_guess,
+                &mut game.player2_salt,
+            )
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if commit != Some(hash_guess(&env, guess, &salt)) {
+            return Err(Error::HashMismatch);
+        }
+
+        // Validate guess is in range
+        if !(1..=10).contains(&guess) {
+            return Err(Error::InvalidGuess);
+        }
+
+        *revealed = Some(guess);
+        *stored_salt = Some(salt);
+
+        session::save(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Settle a game whose deadline has passed without both commitments
+    /// or, once both are in, without both guesses revealed.
+    ///
+    /// A player who committed (or revealed) wins against one who did not; if
+    /// neither did, the session is ended as a draw and both stakes refunded.
+    /// Only allowed once the ledger sequence is past `commit_deadline`, or
+    /// `reveal_deadline` once both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `claimant` - Address of either player
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winner, or None after a double forfeit
+    pub fn claim_timeout(
+        env: Env,
+        session_id: u32,
+        claimant: Address,
+    ) -> Result<Option<Address>, Error> {
+        claimant.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if claimant != game.player1 && claimant != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        // Until both have committed the game waits on a commitment, then on a reveal
+        let (deadline, player1_moved, player2_moved) =
+            if game.player1_commit.is_some() && game.player2_commit.is_some() {
+                (
+                    game.reveal_deadline,
+                    game.player1_guess.is_some(),
+                    game.player2_guess.is_some(),
+                )
+            } else {
+                (
+                    game.commit_deadline,
+                    game.player1_commit.is_some(),
+                    game.player2_commit.is_some(),
+                )
+            };
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let winner = match (player1_moved, player2_moved) {
+            (true, true) => return Err(Error::CannotClaimTimeout),
+            (true, false) => Some(game.player1.clone()),
+            (false, true) => Some(game.player2.clone()),
+            (false, false) => None,
+        };
+
+        match &winner {
+            Some(winner) => {
+                game.winner = Some(winner.clone());
+                session::save(&env, session_id, &game);
+                hub::end_game(&env, session_id, *winner == game.player1)?;
+            }
+            None => {
+                game.forfeited = true;
+                session::save(&env, session_id, &game);
+                hub::end_game_draw(&env, session_id)?;
+            }
+        }
+
+        Ok(winner)
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have made their guesses.
+    /// Can only be called after both players have revealed their guesses.
     /// This generates the winning number, determines the winner, and ends the session.
     ///
     /// # Arguments
@@ -244,48 +394,42 @@ impl NumberGuessContract {
     /// * `Address` - Address of the winning player
     pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check if game already ended (has a winner)
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
+        if game.forfeited {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Check both players have guessed
-        let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
-        let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
+        // Check both players have guessed and revealed
+        if game.player1_commit.is_none() || game.player2_commit.is_none() {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+        let (Some(guess1), Some(guess2), Some(salt1), Some(salt2)) = (
+            game.player1_guess,
+            game.player2_guess,
+            &game.player1_salt,
+            &game.player2_salt,
+        ) else {
+            return Err(Error::BothPlayersNotRevealed);
+        };
 
         // Generate random winning number between 1 and 10 using seeded PRNG
-        // This is done AFTER both players have committed their guesses
+        // This is done AFTER both players have revealed their guesses
         //
         // Seed components (all deterministic and identical between sim/submit):
         // 1. Session ID - unique per game, same between simulation and submission
-        // 2. Player addresses - both players contribute, same between sim/submit
-        // 3. Guesses - committed before reveal, same between sim/submit
+        // 2. Both revealed salts - secret until reveal, so neither player can
+        //    compute the number before committing
         //
         // Note: We do NOT include ledger sequence or timestamp because those differ
         // between simulation and submission, which would cause different winners.
-        //
-        // This ensures:
-        // - Same result between simulation and submission (fully deterministic)
-        // - Cannot be easily gamed (both players contribute to randomness)
-
-        // Build seed more efficiently using native arrays where possible
-        // Total: 12 bytes of fixed data (session_id + 2 guesses)
-        let mut fixed_data = [0u8; 12];
-        fixed_data[0..4].copy_from_slice(&session_id.to_be_bytes());
-        fixed_data[4..8].copy_from_slice(&guess1.to_be_bytes());
-        fixed_data[8..12].copy_from_slice(&guess2.to_be_bytes());
-
-        // Only use Bytes for the final concatenation with player addresses
-        let mut seed_bytes = Bytes::from_array(&env, &fixed_data);
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
+        let mut seed_bytes = Bytes::from_array(&env, &session_id.to_be_bytes());
+        seed_bytes.append(&Bytes::from_slice(&env, &salt1.to_array()));
+        seed_bytes.append(&Bytes::from_slice(&env, &salt2.to_array()));
 
         let seed = env.crypto().keccak256(&seed_bytes);
         env.prng().seed(seed.into());
@@ -293,17 +437,9 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
+        let distance1 = guess1.abs_diff(winning_number);
 
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance2 = guess2.abs_diff(winning_number);
 
         // Determine winner (if equal distance, player1 wins)
         let winner = if distance1 <= distance2 {
@@ -314,23 +450,13 @@ impl NumberGuessContract {
 
         // Update game with winner (this marks the game as ended)
         game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        session::save(&env, session_id, &game);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
         let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        hub::end_game(&env, session_id, player1_won)?;
 
         Ok(winner)
     }
@@ -343,87 +469,88 @@ impl NumberGuessContract {
     /// # Returns
     /// * `Game` - The game state (includes winning number after game ends)
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
-    }
-
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        session::load(&env, session_id).ok_or(Error::GameNotFound)
     }
 
-    /// Set a new admin address
+    /// Get whether a game is complete and who won, without the full state.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
+    /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    /// * `GameResult` - Complete once a winner is known or both players forfeited
+    pub fn get_result(env: Env, session_id: u32) -> Result<GameResult, Error> {
+        let game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(GameResult {
+            complete: has_ended(&game),
+            winner: game.winner,
+        })
     }
 
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
-    }
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Open the Game Hub session and store a new game under `session_id`.
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Call Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        hub::start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        // Create game (winning_number not set yet - will be generated in reveal_winner)
+        let game = Game {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            player1_commit: None,
+            player2_commit: None,
+            player1_guess: None,
+            player2_guess: None,
+            player1_salt: None,
+            player2_salt: None,
+            winning_number: None,
+            winner: None,
+            commit_deadline: session::reveal_deadline(env),
+            reveal_deadline: 0,
+            forfeited: false,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        session::save(env, session_id, &game);
+
+        // Event emitted by the Game Hub contract (GameStarted)
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for NumberGuessContract {}
+
+// get_lobby / set_lobby
+#[contractimpl(contracttrait)]
+impl Lobby for NumberGuessContract {}
+
 // ============================================================================
 // Tests
 // ============================================================================
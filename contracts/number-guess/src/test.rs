@@ -7,8 +7,9 @@
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
 use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use game_core::CoreKey;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -31,10 +32,19 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn reserve_session_id(_env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - always allocates the same ID
+        42
+    }
+
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -86,6 +96,46 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Salt used for a test commitment (distinct per guess value)
+fn test_salt(env: &Env, guess: u32) -> BytesN<32> {
+    BytesN::from_array(env, &[guess as u8 + 100; 32])
+}
+
+/// keccak256(guess(1 byte) || salt(32 bytes)), matching the contract logic
+fn guess_hash(env: &Env, guess: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(guess as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+/// Commit `guess` for `player`
+fn commit_guess(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player: &Address,
+    guess: u32,
+) {
+    client.make_guess(&session_id, player, &guess_hash(env, guess, &test_salt(env, guess)));
+}
+
+/// Both players commit, then both reveal
+fn play_guesses(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player1: &Address,
+    guess1: u32,
+    player2: &Address,
+    guess2: u32,
+) {
+    commit_guess(env, client, session_id, player1, guess1);
+    commit_guess(env, client, session_id, player2, guess2);
+    client.reveal_guess(&session_id, player1, &guess1, &test_salt(env, guess1));
+    client.reveal_guess(&session_id, player2, &guess2, &test_salt(env, guess2));
+}
+
 /// Assert that a Result contains a specific number_guess error
 ///
 /// This helper provides type-safe error assertions following Stellar/Soroban best practices.
@@ -96,7 +146,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
+/// let result = client.try_make_guess(&session_id, &player, &hash);
 /// assert_number_guess_error(&result, Error::AlreadyGuessed);
 /// ```
 ///
@@ -145,7 +195,7 @@ fn assert_number_guess_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
@@ -163,8 +213,7 @@ fn test_complete_game() {
     assert_eq!(game.player2_points, points);
 
     // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
@@ -176,19 +225,18 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
 fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
     client.reveal_winner(&session_id);
 
     let game = client.get_game(&session_id);
@@ -196,7 +244,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -227,15 +275,14 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses closer (1 away from any number between 1-10)
     // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
 
@@ -244,16 +291,8 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
 
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
@@ -268,14 +307,13 @@ fn test_closest_guess_wins() {
 
 #[test]
 fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
 
     let winner = client.reveal_winner(&session_id);
     assert_eq!(winner, player1, "Player1 should win in a tie");
@@ -283,31 +321,22 @@ fn test_tie_game_player1_wins() {
 
 #[test]
 fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses 5 (middle), player2 guesses 10 (edge)
     // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
     } else {
@@ -322,28 +351,29 @@ fn test_exact_guess_wins() {
 
 #[test]
 fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+    commit_guess(&env, &client, session_id, &player1, 5);
 
     // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
+    let hash = guess_hash(&env, 6, &test_salt(&env, 6));
+    let result = client.try_make_guess(&session_id, &player1, &hash);
     assert_number_guess_error(&result, Error::AlreadyGuessed);
 }
 
 #[test]
 fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    commit_guess(&env, &client, session_id, &player1, 5);
 
     // Try to reveal winner - should fail
     let result = client.try_reveal_winner(&session_id);
@@ -351,39 +381,75 @@ fn test_cannot_reveal_before_both_guesses() {
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    // Commit 0 (below range) - revealing it should fail
+    commit_guess(&env, &client, session_id, &player1, 0);
+    commit_guess(&env, &client, session_id, &player2, 5);
+    let result = client.try_reveal_guess(&session_id, &player1, &0, &test_salt(&env, 0));
+    assert_number_guess_error(&result, Error::InvalidGuess);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Commit 11 (above range) - revealing it should fail
+    commit_guess(&env, &client, session_id, &player1, 11);
+    commit_guess(&env, &client, session_id, &player2, 5);
+    let result = client.try_reveal_guess(&session_id, &player1, &11, &test_salt(&env, 11));
+    assert_number_guess_error(&result, Error::InvalidGuess);
+}
+
+#[test]
+fn test_cannot_play_against_yourself() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
+
+    let result = client.try_start_game(&12, &player1, &player1, &100, &100);
+    assert_number_guess_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_session_exists_rejected() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&20, &player1, &player2, &100, &100);
+    let result = client.try_start_game(&20, &player2, &player1, &100, &100);
+    assert_number_guess_error(&result, Error::SessionExists);
+    assert_eq!(client.get_game(&20).player1, player1);
+}
+
+#[test]
+fn test_session_id_allocated_by_hub() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&0, &player1, &player2, &100, &100);
+    assert_eq!(session_id, 42);
+    assert_eq!(client.get_game(&42).player1, player1);
+}
+
+#[test]
+fn test_hub_not_set() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&13, &player1, &player2, &100, &100);
+    play_guesses(&env, &client, 13, &player1, 3, &player2, 7);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CoreKey::GameHubAddress);
+    });
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    let result = client.try_start_game(&14, &player1, &player2, &100, &100);
+    assert_number_guess_error(&result, Error::HubNotSet);
+    let result = client.try_reveal_winner(&13);
+    assert_number_guess_error(&result, Error::HubNotSet);
+    assert_eq!(client.get_game(&13).winner, None);
 }
 
 #[test]
@@ -395,7 +461,8 @@ fn test_non_player_cannot_guess() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
+    let hash = guess_hash(&env, 5, &test_salt(&env, 5));
+    let result = client.try_make_guess(&session_id, &non_player, &hash);
     assert_number_guess_error(&result, Error::NotPlayer);
 }
 
@@ -409,32 +476,31 @@ fn test_cannot_reveal_nonexistent_game() {
 
 #[test]
 fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // Reveal winner - game ends
     let _winner = client.reveal_winner(&session_id);
 
     // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
+    let hash = guess_hash(&env, 3, &test_salt(&env, 3));
+    let result = client.try_make_guess(&session_id, &player1, &hash);
     assert_number_guess_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 14u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // First reveal succeeds
     let winner = client.reveal_winner(&session_id);
@@ -445,6 +511,211 @@ fn test_cannot_reveal_twice() {
     assert_eq!(winner, winner2);
 }
 
+// ============================================================================
+// Commit-Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_guess_hidden_until_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_commit, Some(guess_hash(&env, 5, &test_salt(&env, 5))));
+    assert!(game.player1_guess.is_none());
+    assert!(game.player1_salt.is_none());
+}
+
+#[test]
+fn test_cannot_reveal_guess_before_both_commit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env, 5));
+    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 32u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+    commit_guess(&env, &client, session_id, &player2, 7);
+
+    // Wrong guess, right salt
+    let result = client.try_reveal_guess(&session_id, &player1, &6, &test_salt(&env, 5));
+    assert_number_guess_error(&result, Error::HashMismatch);
+    // Right guess, wrong salt
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env, 6));
+    assert_number_guess_error(&result, Error::HashMismatch);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_guess(&session_id, &non_player, &5, &test_salt(&env, 5));
+    assert_number_guess_error(&result, Error::NotPlayer);
+
+    client.reveal_guess(&session_id, &player1, &5, &test_salt(&env, 5));
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env, 5));
+    assert_number_guess_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_cannot_reveal_winner_before_both_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 33u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+    commit_guess(&env, &client, session_id, &player2, 7);
+    client.reveal_guess(&session_id, &player1, &5, &test_salt(&env, 5));
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::BothPlayersNotRevealed);
+    assert!(client.get_game(&session_id).winning_number.is_none());
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_out_of_range_commit_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 11);
+    assert_eq!(client.get_game(&session_id).reveal_deadline, 0);
+    commit_guess(&env, &client, session_id, &player2, 5);
+    let deadline = client.get_game(&session_id).reveal_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    // Player 1 has no valid reveal; player 2 reveals and waits it out
+    client.reveal_guess(&session_id, &player2, &5, &test_salt(&env, 5));
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    assert_eq!(client.claim_timeout(&session_id, &player1), Some(player2.clone()));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert!(game.winning_number.is_none());
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.reveal_winner(&session_id), player2);
+}
+
+#[test]
+fn test_double_forfeit_is_a_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 0);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+    commit_guess(&env, &client, session_id, &player2, 11);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    let result = client.try_claim_timeout(&session_id, &Address::generate(&env));
+    assert_number_guess_error(&result, Error::NotPlayer);
+    assert_eq!(client.claim_timeout(&session_id, &player2), None);
+
+    let game = client.get_game(&session_id);
+    assert!(game.forfeited);
+    assert!(game.winner.is_none());
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_guess(&session_id, &player1, &0, &test_salt(&env, 0));
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_commits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let deadline = client.get_game(&session_id).commit_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    commit_guess(&env, &client, session_id, &player2, 4);
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    assert_eq!(client.claim_timeout(&session_id, &player1), Some(player2.clone()));
+    let result = client.get_result(&session_id);
+    assert!(result.complete);
+    assert_eq!(result.winner, Some(player2.clone()));
+
+    let hash = guess_hash(&env, 4, &test_salt(&env, 4));
+    let result = client.try_make_guess(&session_id, &player1, &hash);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_neither_commits_is_a_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 44u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_eq!(client.claim_timeout(&session_id, &player1), None);
+    let game = client.get_game(&session_id);
+    assert!(game.forfeited);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_no_timeout_once_both_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    play_guesses(&env, &client, session_id, &player1, 3, &player2, 8);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_number_guess_error(&result, Error::CannotClaimTimeout);
+    client.reveal_winner(&session_id);
+}
+
+/// Play session 1 in a fresh environment with player 1 using `salt_byte`.
+fn winning_number_for_salt(salt_byte: u8) -> (Address, u32) {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let salt = BytesN::from_array(&env, &[salt_byte; 32]);
+
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    client.make_guess(&1, &player1, &guess_hash(&env, 5, &salt));
+    commit_guess(&env, &client, 1, &player2, 5);
+    client.reveal_guess(&1, &player1, &5, &salt);
+    client.reveal_guess(&1, &player2, &5, &test_salt(&env, 5));
+    client.reveal_winner(&1);
+
+    (player1, client.get_game(&1).winning_number.unwrap())
+}
+
+#[test]
+fn test_winning_number_depends_on_salts() {
+    // Same session, players and guesses: only the salt changes the number
+    let (player_a, number) = winning_number_for_salt(0);
+    let (player_b, again) = winning_number_for_salt(0);
+    assert_eq!(player_a, player_b);
+    assert_eq!(number, again);
+
+    assert!((1..20u8).any(|b| winning_number_for_salt(b).1 != number));
+}
+
 // ============================================================================
 // Multiple Games Tests
 // ============================================================================
@@ -463,10 +734,14 @@ fn test_multiple_games_independent() {
     client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
 
     // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    commit_guess(&env, &client, session1, &player1, 3);
+    commit_guess(&env, &client, session2, &player3, 8);
+    commit_guess(&env, &client, session1, &player2, 7);
+    commit_guess(&env, &client, session2, &player4, 2);
+    client.reveal_guess(&session1, &player1, &3, &test_salt(&env, 3));
+    client.reveal_guess(&session2, &player3, &8, &test_salt(&env, 8));
+    client.reveal_guess(&session1, &player2, &7, &test_salt(&env, 7));
+    client.reveal_guess(&session2, &player4, &2, &test_salt(&env, 2));
 
     // Reveal both winners
     let winner1 = client.reveal_winner(&session1);
@@ -489,7 +764,7 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 15u32;
     let points1 = 200_0000000;
@@ -501,8 +776,7 @@ fn test_asymmetric_points() {
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
     client.reveal_winner(&session_id);
 
     // Game completes successfully with asymmetric points
@@ -510,6 +784,71 @@ fn test_asymmetric_points() {
     assert!(final_game.winner.is_some()); // Game has ended
 }
 
+// ============================================================================
+// Lobby Tests
+// ============================================================================
+
+#[test]
+fn test_lobby_starts_game_without_player_auth() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let lobby = Address::generate(&env);
+    client.set_lobby(&lobby);
+    assert_eq!(client.get_lobby(), Some(lobby.clone()));
+
+    client.start_lobby_game(&7, &player1, &player2);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, lobby);
+
+    // The lobby holds the stakes, so no points are locked
+    let game = client.get_game(&7);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player1_points, 0);
+    assert!(!client.get_result(&7).complete);
+
+    play_guesses(&env, &client, 7, &player1, 3, &player2, 8);
+    let winner = client.reveal_winner(&7);
+    let result = client.get_result(&7);
+    assert!(result.complete);
+    assert_eq!(result.winner, Some(winner));
+}
+
+#[test]
+fn test_lobby_result_after_double_forfeit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_lobby(&Address::generate(&env));
+
+    client.start_lobby_game(&8, &player1, &player2);
+    commit_guess(&env, &client, 8, &player1, 0);
+    commit_guess(&env, &client, 8, &player2, 11);
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    client.claim_timeout(&8, &player1);
+
+    let result = client.get_result(&8);
+    assert!(result.complete);
+    assert_eq!(result.winner, None);
+}
+
+#[test]
+fn test_lobby_game_requires_lobby() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let result = client.try_start_lobby_game(&9, &player1, &player2);
+    assert_number_guess_error(&result, Error::LobbyNotSet);
+
+    client.set_lobby(&Address::generate(&env));
+    let result = client.try_start_lobby_game(&9, &player1, &player1);
+    assert_number_guess_error(&result, Error::SelfPlay);
+
+    // The lobby's session ID is never reallocated
+    client.start_game(&9, &player1, &player2, &100, &100);
+    let result = client.try_start_lobby_game(&9, &player2, &player1);
+    assert_number_guess_error(&result, Error::SessionExists);
+
+    env.set_auths(&[]);
+    assert!(client.try_start_lobby_game(&10, &player1, &player2).is_err());
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
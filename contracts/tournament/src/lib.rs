@@ -0,0 +1,719 @@
+#![no_std]
+
+//! # CTM Tournament
+//!
+//! Single-elimination brackets for CTM, which only knows two players per
+//! session.
+//!
+//! 1. **Register** – the creator names the CTM contract (which must be
+//!    registered with the Game Hub), an entry fee in a SEP-41 token and the
+//!    player cap.  Each player pays the fee into the prize pool when they
+//!    register.
+//! 2. **Start** – the creator closes registration.  Players are seeded by
+//!    their Game Hub rating in CTM (ties keep registration order) into a
+//!    bracket sized to the next power of two; the missing seeds are byes
+//!    for the top seeds.  Each first-round pairing is opened on CTM with
+//!    `start_tournament_game`.
+//! 3. **Resolve** – once a match's CTM game is complete, anyone can resolve
+//!    it.  The winner is read with `get_result` and moves on; as soon as
+//!    both halves of the next pairing are known its session is opened.
+//! 4. **Payout** – resolving the final pays the whole pool to the champion.
+//!
+//! A player who stalls is forfeited inside CTM with `claim_timeout`.  If
+//! nobody has won a match `MATCH_TIMEOUT_LEDGERS` after it opened, or its
+//! CTM game has expired from storage, both players are out and their next
+//! opponent gets a bye; a tournament left without a champion refunds every
+//! entry fee.  A CTM draw sends the higher seed through, whichever side of
+//! the pairing they reached it on.
+//!
+//! Registration can be cancelled by the creator, refunding every entry fee.
+//! Tournaments live in persistent storage so the pool cannot expire.
+
+use game_core::{admin, hub, Admin};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, token,
+    vec, Address, Env, Vec,
+};
+
+// ============================================================================
+// External Interfaces
+// ============================================================================
+
+/// Entry points a game must expose to host tournament matches.
+#[contractclient(name = "TournamentGameClient")]
+pub trait TournamentGame {
+    fn start_tournament_game(env: Env, player1: Address, player2: Address) -> u32;
+    fn get_result(env: Env, session_id: u32) -> GameResult;
+}
+
+/// Mirror of CTM's `get_result` record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResult {
+    pub complete: bool,
+    pub winner: Option<Address>,
+}
+
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn get_rating(env: Env, player: Address, game_id: Address) -> u32;
+
+    fn is_game(env: Env, game_id: Address) -> bool;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    TournamentNotFound   = 1,
+    InvalidEntryFee      = 2,
+    InvalidPlayerCount   = 3,
+    RegistrationClosed   = 4,
+    TournamentFull       = 5,
+    AlreadyRegistered    = 6,
+    NotEnoughPlayers     = 7,
+    TournamentNotRunning = 8,
+    MatchNotFound        = 9,
+    MatchNotReady        = 10,
+    MatchDecided         = 11,
+    MatchNotFinished     = 12,
+    HubNotSet            = 13,
+    GameNotApproved      = 14,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct TournamentCreated {
+    pub tournament_id: u32,
+    pub creator: Address,
+    pub game_id: Address,
+    pub entry_fee: i128,
+    pub max_players: u32,
+}
+
+#[contractevent]
+pub struct PlayerRegistered {
+    pub tournament_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct TournamentStarted {
+    pub tournament_id: u32,
+    pub players: u32,
+}
+
+#[contractevent]
+pub struct MatchStarted {
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub session_id: u32,
+}
+
+/// Published when a match is decided; `winner` is `None` after a double
+/// no-show.
+#[contractevent]
+pub struct MatchDecided {
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub winner: Option<Address>,
+}
+
+#[contractevent]
+pub struct TournamentFinished {
+    pub tournament_id: u32,
+    pub champion: Option<Address>,
+    pub prize: i128,
+}
+
+#[contractevent]
+pub struct TournamentCancelled {
+    pub tournament_id: u32,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TournamentStatus {
+    Registration,
+    Running,
+    Finished,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub creator: Address,
+    pub game_id: Address,
+    pub token: Address,
+    pub entry_fee: i128,
+    pub max_players: u32,
+    /// Registration order until the start, seed order afterwards.
+    pub players: Vec<Address>,
+    pub status: TournamentStatus,
+    pub champion: Option<Address>,
+}
+
+/// One pairing in the bracket.  An empty slot is a bye, or a player slot
+/// whose feeding match has not been decided yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub player1: Option<Address>,
+    pub player2: Option<Address>,
+    /// CTM session, once both players are known.
+    pub session_id: Option<u32>,
+    /// Ledger sequence after which an unfinished match eliminates both.
+    pub deadline: u32,
+    pub decided: bool,
+    pub winner: Option<Address>,
+}
+
+/// Tournament storage.  The admin and the Game Hub address are stored under
+/// `game_core::CoreKey`.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Tournament(u32),
+    Bracket(u32),
+    NextTournamentId,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Largest bracket a tournament can fill.
+const MAX_PLAYERS: u32 = 32;
+/// Ledgers a match may stay undecided (7 days ≈ 120 960 ledgers), enough
+/// for CTM's per-phase timeouts to run out.  CTM does not replay draws in
+/// tournament games, so a match has at most four phases.
+const MATCH_TIMEOUT_LEDGERS: u32 = 120_960;
+/// Kept for ~60 days after the last update.
+const TOURNAMENT_TTL_LEDGERS: u32 = 1_036_800;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn load_tournament(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tournament(tournament_id))
+        .ok_or(Error::TournamentNotFound)
+}
+
+fn save_tournament(env: &Env, tournament_id: u32, tournament: &Tournament) {
+    let key = DataKey::Tournament(tournament_id);
+    env.storage().persistent().set(&key, tournament);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TOURNAMENT_TTL_LEDGERS, TOURNAMENT_TTL_LEDGERS);
+}
+
+fn load_bracket(env: &Env, tournament_id: u32) -> Vec<Vec<Match>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bracket(tournament_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_bracket(env: &Env, tournament_id: u32, bracket: &Vec<Vec<Match>>) {
+    let key = DataKey::Bracket(tournament_id);
+    env.storage().persistent().set(&key, bracket);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TOURNAMENT_TTL_LEDGERS, TOURNAMENT_TTL_LEDGERS);
+}
+
+fn hub_address(env: &Env) -> Result<Address, Error> {
+    hub::address(env).map_err(|_| Error::HubNotSet)
+}
+
+fn hub_client(env: &Env) -> Result<GameHubClient<'_>, Error> {
+    Ok(GameHubClient::new(env, &hub_address(env)?))
+}
+
+/// Pay `amount` of the tournament token out of the contract.
+fn pay(env: &Env, tournament: &Tournament, to: &Address, amount: i128) {
+    if amount > 0 {
+        let contract = env.current_contract_address();
+        token::TokenClient::new(env, &tournament.token).transfer(&contract, to, &amount);
+    }
+}
+
+/// Order players by Game Hub rating in the tournament's game, highest
+/// first.  Equal ratings keep registration order.
+fn seed_players(env: &Env, tournament: &Tournament) -> Result<Vec<Address>, Error> {
+    let hub = hub_client(env)?;
+    let mut seeded: Vec<Address> = Vec::new(env);
+    let mut ratings: Vec<u32> = Vec::new(env);
+
+    for player in tournament.players.iter() {
+        let rating = hub.get_rating(&player, &tournament.game_id);
+        let mut at = seeded.len();
+        while at > 0 && ratings.get_unchecked(at - 1) < rating {
+            at -= 1;
+        }
+        seeded.insert(at, player);
+        ratings.insert(at, rating);
+    }
+    Ok(seeded)
+}
+
+/// Standard bracket order of seeds 1..=size, so that seed 1 meets seed
+/// `size` first and the top two seeds can only meet in the final.
+fn seed_order(env: &Env, size: u32) -> Vec<u32> {
+    let mut order = vec![env, 1u32];
+    while order.len() < size {
+        let sum = 2 * order.len() + 1;
+        let mut next = Vec::new(env);
+        for seed in order.iter() {
+            next.push_back(seed);
+            next.push_back(sum - seed);
+        }
+        order = next;
+    }
+    order
+}
+
+/// Whichever of the match's players was seeded higher.
+fn higher_seed(tournament: &Tournament, m: &Match) -> Option<Address> {
+    let seed = |player: &Option<Address>| {
+        player
+            .as_ref()
+            .and_then(|p| tournament.players.first_index_of(p))
+            .unwrap_or(u32::MAX)
+    };
+    if seed(&m.player1) <= seed(&m.player2) {
+        m.player1.clone()
+    } else {
+        m.player2.clone()
+    }
+}
+
+fn empty_match() -> Match {
+    Match {
+        player1: None,
+        player2: None,
+        session_id: None,
+        deadline: 0,
+        decided: false,
+        winner: None,
+    }
+}
+
+/// Open the CTM session for a pairing whose two players are known.
+fn start_match(
+    env: &Env,
+    tournament_id: u32,
+    tournament: &Tournament,
+    round: u32,
+    index: u32,
+    m: &mut Match,
+) {
+    let (Some(player1), Some(player2)) = (&m.player1, &m.player2) else {
+        return;
+    };
+    let session_id = TournamentGameClient::new(env, &tournament.game_id)
+        .start_tournament_game(player1, player2);
+    m.session_id = Some(session_id);
+    m.deadline = env.ledger().sequence().saturating_add(MATCH_TIMEOUT_LEDGERS);
+
+    MatchStarted {
+        tournament_id,
+        round,
+        index,
+        session_id,
+    }
+    .publish(env);
+}
+
+/// Record a match result and carry it up the bracket.
+///
+/// Once both feeders of the next pairing are decided it is started, or,
+/// when a side is empty, decided on the spot as a bye.  Deciding the final
+/// finishes the tournament.
+fn decide(
+    env: &Env,
+    tournament_id: u32,
+    tournament: &mut Tournament,
+    bracket: &mut Vec<Vec<Match>>,
+    mut round: u32,
+    mut index: u32,
+    mut winner: Option<Address>,
+) {
+    loop {
+        let mut matches = bracket.get_unchecked(round);
+        let mut m = matches.get_unchecked(index);
+        m.decided = true;
+        m.winner = winner.clone();
+        matches.set(index, m);
+        bracket.set(round, matches.clone());
+
+        MatchDecided {
+            tournament_id,
+            round,
+            index,
+            winner: winner.clone(),
+        }
+        .publish(env);
+
+        if round + 1 == bracket.len() {
+            finish(env, tournament_id, tournament, winner);
+            return;
+        }
+
+        let (next_round, next_index) = (round + 1, index / 2);
+        let mut next_matches = bracket.get_unchecked(next_round);
+        let mut next = next_matches.get_unchecked(next_index);
+        if index.is_multiple_of(2) {
+            next.player1 = winner.clone();
+        } else {
+            next.player2 = winner.clone();
+        }
+
+        // Wait for the other half of the pairing
+        if !matches.get_unchecked(index ^ 1).decided {
+            next_matches.set(next_index, next);
+            bracket.set(next_round, next_matches);
+            return;
+        }
+
+        if next.player1.is_some() && next.player2.is_some() {
+            start_match(env, tournament_id, tournament, next_round, next_index, &mut next);
+            next_matches.set(next_index, next);
+            bracket.set(next_round, next_matches);
+            return;
+        }
+
+        winner = next.player1.clone().or(next.player2.clone());
+        next_matches.set(next_index, next);
+        bracket.set(next_round, next_matches);
+        round = next_round;
+        index = next_index;
+    }
+}
+
+/// Close the tournament and pay out the pool: all of it to the champion,
+/// or each entry fee back when nobody is left.
+fn finish(env: &Env, tournament_id: u32, tournament: &mut Tournament, champion: Option<Address>) {
+    tournament.status = TournamentStatus::Finished;
+    tournament.champion = champion.clone();
+
+    let prize = tournament.entry_fee * tournament.players.len() as i128;
+    match &champion {
+        Some(winner) => pay(env, tournament, winner, prize),
+        None => {
+            for player in tournament.players.iter() {
+                pay(env, tournament, &player, tournament.entry_fee);
+            }
+        }
+    }
+
+    TournamentFinished {
+        tournament_id,
+        champion,
+        prize,
+    }
+    .publish(env);
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct TournamentContract;
+
+#[contractimpl]
+impl TournamentContract {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        admin::init(&env, &admin, &game_hub);
+    }
+
+    // ----------------------------------------------------- create_tournament
+
+    /// Open registration for a tournament on `game_id` and return its ID.
+    ///
+    /// `entry_fee` of `token` is paid by every player into the prize pool;
+    /// `max_players` caps registration (2 to 32).  The game must be
+    /// registered with the Game Hub.
+    pub fn create_tournament(
+        env: Env,
+        creator: Address,
+        game_id: Address,
+        token: Address,
+        entry_fee: i128,
+        max_players: u32,
+    ) -> Result<u32, Error> {
+        creator.require_auth();
+
+        if entry_fee < 0 {
+            return Err(Error::InvalidEntryFee);
+        }
+        if !(2..=MAX_PLAYERS).contains(&max_players) {
+            return Err(Error::InvalidPlayerCount);
+        }
+        if !hub_client(&env)?.is_game(&game_id) {
+            return Err(Error::GameNotApproved);
+        }
+
+        let tournament_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTournamentId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTournamentId, &(tournament_id + 1));
+
+        let tournament = Tournament {
+            creator: creator.clone(),
+            game_id: game_id.clone(),
+            token,
+            entry_fee,
+            max_players,
+            players: Vec::new(&env),
+            status: TournamentStatus::Registration,
+            champion: None,
+        };
+        save_tournament(&env, tournament_id, &tournament);
+
+        TournamentCreated {
+            tournament_id,
+            creator,
+            game_id,
+            entry_fee,
+            max_players,
+        }
+        .publish(&env);
+        Ok(tournament_id)
+    }
+
+    // -------------------------------------------------------------- register
+
+    /// Join a tournament during registration and pay the entry fee.
+    pub fn register(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        if tournament.players.contains(&player) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if tournament.players.len() >= tournament.max_players {
+            return Err(Error::TournamentFull);
+        }
+
+        if tournament.entry_fee > 0 {
+            let contract = env.current_contract_address();
+            token::TokenClient::new(&env, &tournament.token).transfer(
+                &player,
+                &contract,
+                &tournament.entry_fee,
+            );
+        }
+
+        tournament.players.push_back(player.clone());
+        save_tournament(&env, tournament_id, &tournament);
+
+        PlayerRegistered {
+            tournament_id,
+            player,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // ----------------------------------------------------- cancel_tournament
+
+    /// Call off a tournament still in registration and refund every player.
+    pub fn cancel_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        tournament.creator.require_auth();
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+
+        tournament.status = TournamentStatus::Cancelled;
+        save_tournament(&env, tournament_id, &tournament);
+        for player in tournament.players.iter() {
+            pay(&env, &tournament, &player, tournament.entry_fee);
+        }
+
+        TournamentCancelled { tournament_id }.publish(&env);
+        Ok(())
+    }
+
+    // ------------------------------------------------------ start_tournament
+
+    /// Close registration, seed the bracket and open the first-round games.
+    /// Top seeds without an opponent advance on a bye.
+    pub fn start_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        tournament.creator.require_auth();
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        let players = tournament.players.len();
+        if players < 2 {
+            return Err(Error::NotEnoughPlayers);
+        }
+
+        tournament.players = seed_players(&env, &tournament)?;
+        tournament.status = TournamentStatus::Running;
+
+        // Empty bracket, halving from the first round down to the final
+        let size = players.next_power_of_two();
+        let mut bracket: Vec<Vec<Match>> = Vec::new(&env);
+        let mut matches = size / 2;
+        while matches > 0 {
+            let mut round = Vec::new(&env);
+            for _ in 0..matches {
+                round.push_back(empty_match());
+            }
+            bracket.push_back(round);
+            matches /= 2;
+        }
+
+        let order = seed_order(&env, size);
+        let mut first = bracket.get_unchecked(0);
+        for index in 0..first.len() {
+            let mut m = first.get_unchecked(index);
+            m.player1 = tournament.players.get(order.get_unchecked(2 * index) - 1);
+            m.player2 = tournament.players.get(order.get_unchecked(2 * index + 1) - 1);
+            start_match(&env, tournament_id, &tournament, 0, index, &mut m);
+            first.set(index, m);
+        }
+        bracket.set(0, first);
+
+        TournamentStarted {
+            tournament_id,
+            players,
+        }
+        .publish(&env);
+
+        // Byes only ever sit opposite a top seed, never another bye
+        for index in 0..size / 2 {
+            let m = bracket.get_unchecked(0).get_unchecked(index);
+            if m.player2.is_none() {
+                decide(
+                    &env,
+                    tournament_id,
+                    &mut tournament,
+                    &mut bracket,
+                    0,
+                    index,
+                    m.player1,
+                );
+            }
+        }
+
+        save_bracket(&env, tournament_id, &bracket);
+        save_tournament(&env, tournament_id, &tournament);
+        Ok(())
+    }
+
+    // --------------------------------------------------------- resolve_match
+
+    /// Advance the winner of a finished match.  Callable by anyone.
+    ///
+    /// The result comes from the game's `get_result`; a drawn game sends
+    /// the higher seed through.  After the match deadline an unfinished
+    /// game eliminates both players, as does a game that has expired from
+    /// the game's storage.  Returns the player who advanced.
+    pub fn resolve_match(
+        env: Env,
+        tournament_id: u32,
+        round: u32,
+        index: u32,
+    ) -> Result<Option<Address>, Error> {
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::Running {
+            return Err(Error::TournamentNotRunning);
+        }
+        let mut bracket = load_bracket(&env, tournament_id);
+        let m = bracket
+            .get(round)
+            .and_then(|matches| matches.get(index))
+            .ok_or(Error::MatchNotFound)?;
+        if m.decided {
+            return Err(Error::MatchDecided);
+        }
+        let session_id = m.session_id.ok_or(Error::MatchNotReady)?;
+
+        let game = TournamentGameClient::new(&env, &tournament.game_id);
+        let winner = match game.try_get_result(&session_id) {
+            Ok(Ok(result)) if result.complete => {
+                result.winner.or_else(|| higher_seed(&tournament, &m))
+            }
+            Ok(Ok(_)) if env.ledger().sequence() <= m.deadline => {
+                return Err(Error::MatchNotFinished);
+            }
+            // Past the deadline, or the game is gone: a no-show by both
+            _ => None,
+        };
+
+        decide(
+            &env,
+            tournament_id,
+            &mut tournament,
+            &mut bracket,
+            round,
+            index,
+            winner.clone(),
+        );
+
+        save_bracket(&env, tournament_id, &bracket);
+        save_tournament(&env, tournament_id, &tournament);
+        Ok(winner)
+    }
+
+    // --------------------------------------------------------------- queries
+
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        load_tournament(&env, tournament_id)
+    }
+
+    /// The bracket by round, first round first; empty before the start.
+    pub fn get_bracket(env: Env, tournament_id: u32) -> Result<Vec<Vec<Match>>, Error> {
+        load_tournament(&env, tournament_id)?;
+        Ok(load_bracket(&env, tournament_id))
+    }
+
+    /// One pairing; `round` and `index` are 0-based.
+    pub fn get_match(env: Env, tournament_id: u32, round: u32, index: u32) -> Result<Match, Error> {
+        load_tournament(&env, tournament_id)?;
+        load_bracket(&env, tournament_id)
+            .get(round)
+            .and_then(|matches| matches.get(index))
+            .ok_or(Error::MatchNotFound)
+    }
+}
+
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for TournamentContract {}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
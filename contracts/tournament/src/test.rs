@@ -0,0 +1,493 @@
+#![cfg(test)]
+
+//! Tests for the tournament bracket, run against the real Game Hub and CTM.
+
+use crate::{Error, TournamentContract, TournamentContractClient, TournamentStatus};
+use ctm::{CtmContract, CtmContractClient, DrawPolicy};
+use game_core::CoreKey;
+use game_hub::{GameHub, GameHubClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    tournament: TournamentContractClient<'static>,
+    hub: GameHubClient<'static>,
+    ctm: CtmContractClient<'static>,
+    token: TokenClient<'static>,
+    asset: StellarAssetClient<'static>,
+    creator: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1_441_065_600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(GameHub, (&admin,));
+    let hub = GameHubClient::new(&env, &hub_addr);
+    let ctm_addr = env.register(CtmContract, (&admin, &hub_addr));
+    let ctm = CtmContractClient::new(&env, &ctm_addr);
+    let tournament_addr = env.register(TournamentContract, (&admin, &hub_addr));
+    let tournament = TournamentContractClient::new(&env, &tournament_addr);
+    hub.add_game(&ctm_addr);
+    ctm.set_tournament(&tournament_addr);
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let asset = StellarAssetClient::new(&env, &sac.address());
+    let token = TokenClient::new(&env, &sac.address());
+
+    Setup {
+        env: env.clone(),
+        tournament,
+        hub,
+        ctm,
+        token,
+        asset,
+        creator: Address::generate(&env),
+    }
+}
+
+/// Create a tournament with a 100-token entry fee for up to `max_players`.
+fn create(s: &Setup, max_players: u32) -> u32 {
+    s.tournament
+        .create_tournament(&s.creator, &s.ctm.address, &s.token.address, &100, &max_players)
+}
+
+/// Register `count` funded players, in order.
+fn register(s: &Setup, tournament_id: u32, count: u32) -> Vec<Address> {
+    let mut players = Vec::new(&s.env);
+    for _ in 0..count {
+        let player = Address::generate(&s.env);
+        s.asset.mint(&player, &1_000);
+        s.tournament.register(&tournament_id, &player);
+        players.push_back(player);
+    }
+    players
+}
+
+/// Helper: assert a contract call returned a specific Error.
+fn assert_tournament_error<T: core::fmt::Debug, E: core::fmt::Debug>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected: Error,
+) {
+    match result {
+        Err(Ok(actual)) => {
+            assert_eq!(
+                *actual, expected,
+                "Expected {:?} but got {:?}",
+                expected, actual
+            );
+        }
+        other => panic!("Expected Err(Ok({:?})), got {:?}", expected, other),
+    }
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn salt(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+fn hands_hash(env: &Env, left: u32, right: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(left as u8);
+    pre.push_back(right as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+fn choice_hash(env: &Env, choice: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut pre = Bytes::new(env);
+    pre.push_back(choice as u8);
+    pre.append(&Bytes::from_slice(env, &salt.to_array()));
+    env.crypto().keccak256(&pre).into()
+}
+
+/// Play a match's CTM game to the end.  Both keep their left hand: Rock
+/// beats Scissors for whoever should win, equal hands draw.
+fn play(s: &Setup, tournament_id: u32, round: u32, index: u32, player1_wins: Option<bool>) {
+    let env = &s.env;
+    let session = s
+        .tournament
+        .get_match(&tournament_id, &round, &index)
+        .session_id
+        .unwrap();
+    let game = s.ctm.get_game(&session);
+    let (left1, left2) = match player1_wins {
+        Some(true) => (0, 2),
+        Some(false) => (2, 0),
+        None => (0, 0),
+    };
+
+    let (s1, s2) = (salt(env, 1), salt(env, 2));
+    s.ctm
+        .commit_hands(&session, &game.player1, &hands_hash(env, left1, 1, &s1));
+    s.ctm
+        .commit_hands(&session, &game.player2, &hands_hash(env, left2, 1, &s1));
+    s.ctm.reveal_hands(&session, &game.player1, &left1, &1, &s1);
+    s.ctm.reveal_hands(&session, &game.player2, &left2, &1, &s1);
+    s.ctm
+        .commit_choice(&session, &game.player1, &choice_hash(env, 0, &s2));
+    s.ctm
+        .commit_choice(&session, &game.player2, &choice_hash(env, 0, &s2));
+    s.ctm.reveal_choice(&session, &game.player1, &0, &s2);
+    s.ctm.reveal_choice(&session, &game.player2, &0, &s2);
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+#[test]
+fn test_register_and_cancel_refunds() {
+    let s = setup_test();
+    let id = create(&s, 4);
+    assert_eq!(id, 1);
+    assert_eq!(create(&s, 4), 2);
+
+    let players = register(&s, id, 3);
+    assert_eq!(s.token.balance(&s.tournament.address), 300);
+    let tournament = s.tournament.get_tournament(&id);
+    assert_eq!(tournament.players, players);
+    assert_eq!(tournament.status, TournamentStatus::Registration);
+
+    s.tournament.cancel_tournament(&id);
+    for player in players.iter() {
+        assert_eq!(s.token.balance(&player), 1_000);
+    }
+    assert_eq!(
+        s.tournament.get_tournament(&id).status,
+        TournamentStatus::Cancelled
+    );
+    assert_tournament_error(
+        &s.tournament.try_register(&id, &Address::generate(&s.env)),
+        Error::RegistrationClosed,
+    );
+}
+
+#[test]
+fn test_registration_validation() {
+    let s = setup_test();
+    let create_with = |entry_fee: i128, max_players: u32| {
+        s.tournament.try_create_tournament(
+            &s.creator,
+            &s.ctm.address,
+            &s.token.address,
+            &entry_fee,
+            &max_players,
+        )
+    };
+    assert_tournament_error(&create_with(-1, 4), Error::InvalidEntryFee);
+    assert_tournament_error(&create_with(100, 1), Error::InvalidPlayerCount);
+    assert_tournament_error(&create_with(100, 33), Error::InvalidPlayerCount);
+
+    let id = create(&s, 2);
+    let players = register(&s, id, 1);
+    assert_tournament_error(
+        &s.tournament.try_register(&id, &players.get_unchecked(0)),
+        Error::AlreadyRegistered,
+    );
+    assert_tournament_error(&s.tournament.try_start_tournament(&id), Error::NotEnoughPlayers);
+
+    register(&s, id, 1);
+    assert_tournament_error(
+        &s.tournament.try_register(&id, &Address::generate(&s.env)),
+        Error::TournamentFull,
+    );
+    assert_tournament_error(&s.tournament.try_get_tournament(&9), Error::TournamentNotFound);
+
+    s.tournament.start_tournament(&id);
+    assert_tournament_error(&s.tournament.try_start_tournament(&id), Error::RegistrationClosed);
+    assert_tournament_error(&s.tournament.try_cancel_tournament(&id), Error::RegistrationClosed);
+}
+
+#[test]
+fn test_unregistered_game_refused() {
+    let s = setup_test();
+    let unregistered = Address::generate(&s.env);
+    assert_tournament_error(
+        &s.tournament.try_create_tournament(
+            &s.creator,
+            &unregistered,
+            &s.token.address,
+            &100,
+            &4,
+        ),
+        Error::GameNotApproved,
+    );
+
+    s.hub.remove_game(&s.ctm.address);
+    assert_tournament_error(
+        &s.tournament.try_create_tournament(
+            &s.creator,
+            &s.ctm.address,
+            &s.token.address,
+            &100,
+            &4,
+        ),
+        Error::GameNotApproved,
+    );
+}
+
+// ============================================================================
+// Bracket
+// ============================================================================
+
+#[test]
+fn test_four_player_bracket_pays_champion() {
+    let s = setup_test();
+    let id = create(&s, 4);
+    let players = register(&s, id, 4);
+    s.tournament.start_tournament(&id);
+
+    // Seeds 1v4 and 2v3, each with its own CTM session
+    let bracket = s.tournament.get_bracket(&id);
+    assert_eq!(bracket.len(), 2);
+    let first = bracket.get_unchecked(0);
+    assert_eq!(first.get_unchecked(0).player1, players.get(0));
+    assert_eq!(first.get_unchecked(0).player2, players.get(3));
+    assert_eq!(first.get_unchecked(1).player1, players.get(1));
+    assert_eq!(first.get_unchecked(1).player2, players.get(2));
+    assert_eq!(first.get_unchecked(0).session_id, Some(1));
+    assert_eq!(first.get_unchecked(1).session_id, Some(2));
+    assert_eq!(s.tournament.get_match(&id, &1, &0).session_id, None);
+
+    play(&s, id, 0, 0, Some(false));
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), players.get(3));
+    // The final waits for the other semi-final
+    assert_eq!(s.tournament.get_match(&id, &1, &0).session_id, None);
+
+    play(&s, id, 0, 1, Some(true));
+    assert_eq!(s.tournament.resolve_match(&id, &0, &1), players.get(1));
+    let last = s.tournament.get_match(&id, &1, &0);
+    assert_eq!(last.player1, players.get(3));
+    assert_eq!(last.player2, players.get(1));
+    assert_eq!(last.session_id, Some(3));
+
+    play(&s, id, 1, 0, Some(true));
+    let champion = players.get_unchecked(3);
+    assert_eq!(s.tournament.resolve_match(&id, &1, &0), Some(champion.clone()));
+
+    let tournament = s.tournament.get_tournament(&id);
+    assert_eq!(tournament.status, TournamentStatus::Finished);
+    assert_eq!(tournament.champion, Some(champion.clone()));
+    assert_eq!(s.token.balance(&champion), 1_300);
+    assert_eq!(s.token.balance(&s.tournament.address), 0);
+    assert_eq!(s.hub.get_player_stats(&champion).wins, 2);
+}
+
+#[test]
+fn test_byes_for_top_seeds() {
+    let s = setup_test();
+    let id = create(&s, 8);
+    let players = register(&s, id, 5);
+    s.tournament.start_tournament(&id);
+
+    // Eight slots: seeds 1, 2 and 3 face the missing seeds 8, 7 and 6
+    let first = s.tournament.get_bracket(&id).get_unchecked(0);
+    assert_eq!(first.len(), 4);
+    for index in [0, 2, 3] {
+        let m = first.get_unchecked(index);
+        assert!(m.decided);
+        assert_eq!(m.player2, None);
+        assert_eq!(m.session_id, None);
+    }
+    assert_eq!(first.get_unchecked(1).session_id, Some(1));
+
+    // Seeds 2 and 3 meet straight away; seed 1 waits for 4v5
+    let semi = s.tournament.get_match(&id, &1, &1);
+    assert_eq!(semi.player1, players.get(1));
+    assert_eq!(semi.player2, players.get(2));
+    assert_eq!(semi.session_id, Some(2));
+    let semi = s.tournament.get_match(&id, &1, &0);
+    assert_eq!(semi.player1, players.get(0));
+    assert_eq!(semi.player2, None);
+    assert_tournament_error(&s.tournament.try_resolve_match(&id, &1, &0), Error::MatchNotReady);
+    assert_tournament_error(&s.tournament.try_resolve_match(&id, &0, &0), Error::MatchDecided);
+
+    play(&s, id, 0, 1, Some(true));
+    s.tournament.resolve_match(&id, &0, &1);
+    assert_eq!(s.tournament.get_match(&id, &1, &0).session_id, Some(3));
+}
+
+#[test]
+fn test_seeded_by_rating() {
+    let s = setup_test();
+
+    // The last player to register has already won a CTM game
+    let id = create(&s, 4);
+    let players = register(&s, id, 4);
+    let rated = players.get_unchecked(3);
+    let other = Address::generate(&s.env);
+    s.ctm.start_game(&50, &rated, &other, &0, &0);
+    s.ctm
+        .commit_hands(&50, &rated, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    advance_ledgers(&s.env, 17_281);
+    s.ctm.claim_timeout(&50, &rated);
+    assert!(s.hub.get_rating(&rated, &s.ctm.address) > 1500);
+
+    s.tournament.start_tournament(&id);
+    let tournament = s.tournament.get_tournament(&id);
+    assert_eq!(tournament.players.get(0), Some(rated.clone()));
+    assert_eq!(tournament.players.get(1), players.get(0));
+
+    // Seed 1 meets seed 4, the last of the unrated players
+    let m = s.tournament.get_match(&id, &0, &0);
+    assert_eq!(m.player1, Some(rated));
+    assert_eq!(m.player2, players.get(2));
+}
+
+#[test]
+fn test_draw_sends_higher_seed_through() {
+    let s = setup_test();
+    s.ctm.set_draw_policy(&DrawPolicy::TrueDraw);
+    let id = create(&s, 2);
+    let players = register(&s, id, 2);
+    s.tournament.start_tournament(&id);
+
+    play(&s, id, 0, 0, None);
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), players.get(0));
+    assert_eq!(s.token.balance(&players.get_unchecked(0)), 1_100);
+}
+
+#[test]
+fn test_replay_policy_draw_sends_higher_seed_through() {
+    let s = setup_test();
+    s.ctm.set_draw_policy(&DrawPolicy::ReplayRound);
+    let id = create(&s, 2);
+    let players = register(&s, id, 2);
+    s.tournament.start_tournament(&id);
+
+    // The drawn round is not replayed, so the game ends before the deadline
+    play(&s, id, 0, 0, None);
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), players.get(0));
+    assert_eq!(s.token.balance(&players.get_unchecked(0)), 1_100);
+}
+
+#[test]
+fn test_draw_in_later_round_sends_higher_seed_through() {
+    let s = setup_test();
+    s.ctm.set_draw_policy(&DrawPolicy::TrueDraw);
+    let id = create(&s, 4);
+    let players = register(&s, id, 4);
+    s.tournament.start_tournament(&id);
+
+    // Seed 4 upsets seed 1 and takes the player 1 side of the final
+    play(&s, id, 0, 0, Some(false));
+    s.tournament.resolve_match(&id, &0, &0);
+    play(&s, id, 0, 1, Some(true));
+    s.tournament.resolve_match(&id, &0, &1);
+    let last = s.tournament.get_match(&id, &1, &0);
+    assert_eq!(last.player1, players.get(3));
+    assert_eq!(last.player2, players.get(1));
+
+    play(&s, id, 1, 0, None);
+    assert_eq!(s.tournament.resolve_match(&id, &1, &0), players.get(1));
+    assert_eq!(s.tournament.get_tournament(&id).champion, players.get(1));
+}
+
+// ============================================================================
+// No-shows
+// ============================================================================
+
+#[test]
+fn test_no_show_forfeited_through_ctm_timeout() {
+    let s = setup_test();
+    let id = create(&s, 2);
+    let players = register(&s, id, 2);
+    s.tournament.start_tournament(&id);
+
+    let session = s.tournament.get_match(&id, &0, &0).session_id.unwrap();
+    let present = players.get_unchecked(1);
+    s.ctm
+        .commit_hands(&session, &present, &hands_hash(&s.env, 0, 1, &salt(&s.env, 1)));
+    assert_tournament_error(&s.tournament.try_resolve_match(&id, &0, &0), Error::MatchNotFinished);
+
+    advance_ledgers(&s.env, 17_281);
+    s.ctm.claim_timeout(&session, &present);
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), Some(present.clone()));
+    assert_eq!(s.token.balance(&present), 1_100);
+}
+
+#[test]
+fn test_double_no_show_eliminates_both() {
+    let s = setup_test();
+    let id = create(&s, 4);
+    let players = register(&s, id, 4);
+    s.tournament.start_tournament(&id);
+
+    // Seeds 2 and 3 never play; seed 4 upsets seed 1
+    play(&s, id, 0, 0, Some(false));
+    s.tournament.resolve_match(&id, &0, &0);
+    advance_ledgers(&s.env, 120_961);
+    assert_eq!(s.tournament.resolve_match(&id, &0, &1), None);
+
+    // The final is a bye for the only player left
+    let last = s.tournament.get_match(&id, &1, &0);
+    assert!(last.decided);
+    assert_eq!(last.winner, players.get(3));
+    let tournament = s.tournament.get_tournament(&id);
+    assert_eq!(tournament.status, TournamentStatus::Finished);
+    assert_eq!(s.token.balance(&players.get_unchecked(3)), 1_300);
+    assert_tournament_error(
+        &s.tournament.try_resolve_match(&id, &1, &0),
+        Error::TournamentNotRunning,
+    );
+}
+
+#[test]
+fn test_expired_game_is_a_no_show() {
+    let s = setup_test();
+    let id = create(&s, 2);
+    let players = register(&s, id, 2);
+    s.tournament.start_tournament(&id);
+
+    // The CTM game drops out of temporary storage before anyone resolves it
+    let session = s.tournament.get_match(&id, &0, &0).session_id.unwrap();
+    s.env.as_contract(&s.ctm.address, || {
+        s.env.storage().temporary().remove(&CoreKey::Game(session));
+    });
+
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), None);
+    assert_eq!(s.tournament.get_tournament(&id).status, TournamentStatus::Finished);
+    for player in players.iter() {
+        assert_eq!(s.token.balance(&player), 1_000);
+    }
+}
+
+#[test]
+fn test_no_champion_refunds_entry_fees() {
+    let s = setup_test();
+    let id = create(&s, 2);
+    let players = register(&s, id, 2);
+    s.tournament.start_tournament(&id);
+
+    assert_tournament_error(&s.tournament.try_resolve_match(&id, &0, &1), Error::MatchNotFound);
+    advance_ledgers(&s.env, 120_961);
+    assert_eq!(s.tournament.resolve_match(&id, &0, &0), None);
+
+    assert_eq!(s.tournament.get_tournament(&id).champion, None);
+    for player in players.iter() {
+        assert_eq!(s.token.balance(&player), 1_000);
+    }
+}
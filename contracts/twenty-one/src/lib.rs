@@ -5,33 +5,72 @@
 //! A two-player card game where players try to get as close to 21 as possible without going over.
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
+//! Each card is one byte: the rank (1-13) in the low nibble and the suit
+//! (0 = clubs, 1 = diamonds, 2 = hearts, 3 = spades) in the high nibble.
+//!
+//! **Shoe:**
+//! With `Rules::decks` set to 1-8, cards come from a shoe of that many 52-card
+//! decks and are dealt without replacement. The shoe is not shuffled up front
+//! (any order fixed in advance would be predictable); instead every card is picked
+//! uniformly from the undealt cards with that draw's seed. The shoe carries over
+//! when a tie starts a new round and is rebuilt at the start of a round once fewer
+//! than `Rules::reshuffle_below` cards remain, or mid-round if it runs out (cards
+//! in play stay out of the new shoe). `decks = 0` keeps the original infinite
+//! deck. Face-down cards in hidden-hand games always come from an infinite deck:
+//! removing them from a public shoe would reveal them.
+//!
+//! **Rules:**
+//! Each session picks its `Rules` at start. With `soft_aces`, one Ace counts as 11
+//! when that does not bust the hand, and a two-card 21 (a natural) beats a drawn 21.
+//! With `house`, a contract-driven dealer gets a face-up card on every deal and,
+//! once both players stick, draws its hole card and keeps drawing until it reaches
+//! 17. Each player is then first judged against the dealer (win, push or loss);
+//! only when both players get the same result do their hands decide the game.
+//!
+//! **Card Entropy:**
+//! Cards are not derived from public data. At `start_game` each player commits the
+//! anchor of a private hash chain (`keccak256` applied N times to a random seed).
+//! Every draw - the initial deal, each hit and each redeal after a tie - needs the
+//! next link of both chains, i.e. the preimage of the last link the contract has
+//! verified. The card seed is `keccak256(session_id || link1 || link2)`, so neither
+//! player can predict a card before the other player has revealed their link, and
+//! simulation and submission still compute identical cards.
+//!
+//! A player who refuses to reveal cannot change a card. Once one player has
+//! revealed for a draw, the other has until `draw_deadline` to follow; after that
+//! the player who revealed can `claim_timeout` and wins by forfeit. Chains must be
+//! long enough for the whole game (one link per draw); 256 links is plenty.
+//!
+//! **Hidden Hands:**
+//! Games started with `start_hidden_game` keep hands private. Each player also
+//! commits the anchor of a second "hand" chain; link `r` of it is the player's hand
+//! secret for round `r`. Only the first card of each hand is dealt face up. Every
+//! other card is seeded with `keccak256(hand_secret || draw_seed || player)`, so the
+//! holder can compute it from the public draw seed but nobody else can. The contract
+//! stores just the draw seeds. After both players stick, each calls `reveal_hand`
+//! with their hand secret; the contract checks it against the hand chain and
+//! recomputes the hidden cards, so the revealed total cannot be forged. Busts are
+//! only known at showdown: a bust loses, and two busts are a tie. Once both players
+//! stick (and the dealer has played), `showdown_deadline` gives them time to reveal.
+//! The second player to reveal sees the first one's total, so the first reveal
+//! restarts it; a player who revealed can `claim_timeout` once it passes. If neither
+//! player reveals in time, either can `claim_timeout` to end the session as a draw
+//! and both stakes are refunded.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
+//!
+//! Unlike the other games, Twenty-One has no `start_lobby_game`: each player's
+//! chain anchor must be signed by that player at start, and the matchmaking lobby
+//! only carries the accepting player's auth.
 
+use game_core::{admin, hub, session, Admin, CoreError};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
-    contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror, contractimpl,
+    contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -47,10 +86,33 @@ pub enum Error {
     PlayerBusted = 5,
     BothPlayersNotStuck = 6,
     OpponentNotStuck = 7,
+    /// No longer returned: a tie deals a new round. Kept so code 8 is never reused.
     Draw = 8,
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    NoPendingDraw = 12,
+    DrawPending = 13,
+    HashMismatch = 14,
+    AlreadyRevealed = 15,
+    NotHiddenGame = 16,
+    HandNotRevealed = 17,
+    InvalidRules = 18,
+    ShoeExhausted = 19,
+    HubNotSet = 20,
+    SessionExists = 21,
+    DeadlineNotReached = 22,
+    CannotClaimTimeout = 23,
+}
+
+impl From<CoreError> for Error {
+    fn from(err: CoreError) -> Self {
+        match err {
+            CoreError::SelfPlay => Error::SelfPlay,
+            CoreError::HubNotSet => Error::HubNotSet,
+            CoreError::SessionExists => Error::SessionExists,
+        }
+    }
 }
 
 // ============================================================================
@@ -68,6 +130,36 @@ pub enum Error {
 // Data Types
 // ============================================================================
 
+/// Rule set chosen per session at start
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub soft_aces: bool, // Aces count 1 or 11; a two-card 21 is a natural
+    pub house: bool,     // A dealer draws to 17 and both players play against it
+    pub decks: u32,      // Decks in the shoe (0 = infinite deck, max 8)
+    pub reshuffle_below: u32, // Rebuild the shoe at a new round when fewer cards remain
+}
+
+/// Chain anchors a player commits to in a hidden-hand game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Anchors {
+    pub draw: BytesN<32>, // Last link of the draw chain (as in `start_game`)
+    pub hand: BytesN<32>, // Last link of the hand chain
+}
+
+/// A draw waiting for both players to reveal their next chain link
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Draw {
+    None = 0,       // No draw pending
+    Deal = 1,       // Two cards to each player (start of a round)
+    Player1Hit = 2, // One card to player 1
+    Player2Hit = 3, // One card to player 2
+    Dealer = 4,     // Dealer's hole card and draws to 17 (house mode, after both stick)
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -75,63 +167,199 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_hand: Bytes,  // Each byte represents a card (1-13)
-    pub player2_hand: Bytes,  // Each byte represents a card (1-13)
+    pub player1_hand: Bytes,  // Each byte represents a card (suit << 4 | rank)
+    pub player2_hand: Bytes,  // Each byte represents a card (suit << 4 | rank)
     pub player1_stuck: bool,
     pub player2_stuck: bool,
     pub winner: Option<Address>,
     pub round: u32,
-}
-
-#[contracttype]
-#[derive(Clone)]
-pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
+    pub player1_link: BytesN<32>, // Last verified link of player 1's hash chain
+    pub player2_link: BytesN<32>, // Last verified link of player 2's hash chain
+    pub pending_draw: Draw,
+    pub player1_revealed: bool, // Player 1 revealed a link for the pending draw
+    pub player2_revealed: bool, // Player 2 revealed a link for the pending draw
+    pub draws: u32,             // Completed draws (= links consumed from each chain)
+    pub draw_deadline: u32,     // Ledger after which the first revealer can claim the pending draw
+    pub hidden: bool,           // Hidden-hand mode: hands hold face-up cards only
+    pub player1_hand_link: Option<BytesN<32>>, // Last verified link of player 1's hand chain
+    pub player2_hand_link: Option<BytesN<32>>, // Last verified link of player 2's hand chain
+    pub player1_hidden: Vec<BytesN<32>>, // Draw seeds of player 1's face-down cards
+    pub player2_hidden: Vec<BytesN<32>>, // Draw seeds of player 2's face-down cards
+    pub showdown_deadline: u32, // Ledger after which hands not yet revealed are forfeited
+    pub forfeited: bool,        // Neither hand was revealed in time; ended as a draw
+    pub rules: Rules,
+    pub dealer_hand: Bytes, // Dealer's cards (house mode only)
+    pub shoe: Bytes,        // Undealt cards (finite shoe only)
 }
 
 // ============================================================================
-// Storage TTL Management
+// Constants
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// The dealer stands once its hand is worth at least this much
+const DEALER_STANDS_ON: u32 = 17;
+
+/// Seat index used for the dealer's card seeds (players are 1 and 2)
+const DEALER: u8 = 3;
+
+/// Largest shoe a session can use
+const MAX_DECKS: u32 = 8;
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Convert card number to point value.
-/// Cards 1-13 where: Ace=1, 2-10=face value, Jack/Queen/King=10
-fn card_value(card: u32) -> u32 {
-    if card >= 10 {
+/// Build a card byte from a suit (0-3) and a rank (1-13)
+fn make_card(suit: u8, rank: u8) -> u8 {
+    (suit << 4) | rank
+}
+
+/// Rank (1-13) of a card byte
+fn card_rank(card: u8) -> u32 {
+    (card & 0x0F) as u32
+}
+
+/// Convert card rank to point value.
+/// Ranks 1-13 where: Ace=1, 2-10=face value, Jack/Queen/King=10
+fn card_value(rank: u32) -> u32 {
+    if rank >= 10 {
         10 // Jack (11), Queen (12), King (13) all worth 10
     } else {
-        card // Ace (1) through 10 are face value
+        rank // Ace (1) through 10 are face value
     }
 }
 
-/// Calculate the total value of a hand (stored as Bytes where each byte is a card 1-13)
-fn calculate_hand_value(hand: &Bytes) -> Result<u32, Error> {
+/// Calculate the total value of a hand (stored as Bytes where each byte is a card).
+/// With `soft_aces`, one Ace counts as 11 instead of 1 if the hand stays at or below 21.
+fn calculate_hand_value(hand: &Bytes, soft_aces: bool) -> Result<u32, Error> {
     let mut total = 0u32;
+    let mut has_ace = false;
     for i in 0..hand.len() {
-        let card = hand.get(i).ok_or(Error::InvalidHandData)? as u32;
+        let rank = card_rank(hand.get(i).ok_or(Error::InvalidHandData)?);
+        has_ace |= rank == 1;
         total = total
-            .checked_add(card_value(card))
+            .checked_add(card_value(rank))
             .ok_or(Error::InvalidHandData)?;
     }
+    if soft_aces && has_ace && total + 10 <= 21 {
+        total += 10;
+    }
     Ok(total)
 }
 
-/// Deal a card (1-13) using deterministic PRNG
+/// Showdown rank of a hand as `(value, natural)`; a bust ranks `(0, false)`.
+/// Tuples compare value first, so a natural only beats another 21.
+fn hand_rank(hand: &Bytes, rules: &Rules) -> Result<(u32, bool), Error> {
+    let value = calculate_hand_value(hand, rules.soft_aces)?;
+    if value > 21 {
+        return Ok((0, false));
+    }
+    let natural = rules.soft_aces && hand.len() == 2 && value == 21;
+    Ok((value, natural))
+}
+
+/// Result of a hand against the dealer: 2 = win, 1 = push, 0 = loss.
+/// A busted player loses even if the dealer busts too.
+fn against_dealer(player: (u32, bool), dealer: (u32, bool)) -> u32 {
+    if player.0 == 0 {
+        return 0;
+    }
+    match player.cmp(&dealer) {
+        core::cmp::Ordering::Greater => 2,
+        core::cmp::Ordering::Equal => 1,
+        core::cmp::Ordering::Less => 0,
+    }
+}
+
+/// Deal a card from an infinite deck using deterministic PRNG
 /// The seed is passed in (as Hash from keccak256)
 fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
     env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=13) as u8
+    let n = env.prng().gen_range::<u64>(0..52) as u8;
+    make_card(n / 13, n % 13 + 1)
+}
+
+/// A fresh shoe of `decks` 52-card decks
+fn new_shoe(env: &Env, decks: u32) -> Bytes {
+    let mut shoe = Bytes::new(env);
+    for _ in 0..decks {
+        for suit in 0..4 {
+            for rank in 1..=13 {
+                shoe.push_back(make_card(suit, rank));
+            }
+        }
+    }
+    shoe
+}
+
+/// Remove one copy of `card` from `cards`, if present
+fn remove_card(cards: &mut Bytes, card: u8) {
+    for i in 0..cards.len() {
+        if cards.get(i) == Some(card) {
+            cards.remove(i);
+            return;
+        }
+    }
+}
+
+/// Deal a face-down card for `player` (1 or 2) from the holder's hand secret
+fn deal_hidden(env: &Env, hand_secret: &BytesN<32>, draw_seed: &BytesN<32>, player: u8) -> u8 {
+    let mut card_seed_bytes = Bytes::new(env);
+    card_seed_bytes.append(&Bytes::from(hand_secret.clone()));
+    card_seed_bytes.append(&Bytes::from(draw_seed.clone()));
+    card_seed_bytes.append(&Bytes::from_array(env, &[player]));
+    let card_seed = env.crypto().keccak256(&card_seed_bytes);
+    deal_card(env, card_seed.into())
+}
+
+/// Deal the card at `index` for `seat` (1, 2 or `DEALER`) from a draw's base seed.
+/// Finite shoes deal without replacement and are rebuilt if they run out.
+fn deal_from(env: &Env, game: &mut Game, base_seed: &BytesN<32>, index: u8, seat: u8) -> Result<u8, Error> {
+    let mut card_seed_bytes = Bytes::new(env);
+    card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+    card_seed_bytes.append(&Bytes::from_array(env, &[index, seat])); // [card_index, seat]
+    let card_seed = env.crypto().keccak256(&card_seed_bytes);
+
+    if game.rules.decks == 0 {
+        return Ok(deal_card(env, card_seed.into()));
+    }
+
+    if game.shoe.is_empty() {
+        // Out of cards mid-round: everything not in play goes back in
+        game.shoe = new_shoe(env, game.rules.decks);
+        for hand in [&game.player1_hand, &game.player2_hand, &game.dealer_hand] {
+            for card in hand.iter() {
+                remove_card(&mut game.shoe, card);
+            }
+        }
+        if game.shoe.is_empty() {
+            return Err(Error::ShoeExhausted);
+        }
+    }
+
+    // Pick uniformly among the undealt cards; swap-remove keeps this O(1)
+    env.prng().seed(card_seed.into());
+    let pick = env.prng().gen_range::<u64>(0..game.shoe.len() as u64) as u32;
+    let last = game.shoe.len() - 1;
+    let card = game.shoe.get(pick).ok_or(Error::InvalidHandData)?;
+    let last_card = game.shoe.get(last).ok_or(Error::InvalidHandData)?;
+    game.shoe.set(pick, last_card);
+    game.shoe.pop_back();
+
+    Ok(card)
+}
+
+/// Whether the game is over: won, or forfeited by both players at showdown
+fn has_ended(game: &Game) -> bool {
+    game.winner.is_some() || game.forfeited
+}
+
+/// Start the hidden-hand showdown clock once both players have stuck and no
+/// dealer draw is pending
+fn start_showdown(env: &Env, game: &mut Game) {
+    if game.hidden && game.player1_stuck && game.player2_stuck && game.pending_draw == Draw::None {
+        game.showdown_deadline = session::reveal_deadline(env);
+    }
 }
 
 // ============================================================================
@@ -150,146 +378,141 @@ impl TwentyOneContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
-    /// Each player is dealt 2 cards to start.
+    /// Each player commits the anchor of their hash chain; the initial 2-card hands
+    /// are dealt once both players have revealed their first link with `reveal_link`.
     ///
     /// **CRITICAL:** This method requires authorization from THIS contract (not players).
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount committed by player 1
-    /// * `player2_points` - Points amount committed by player 2
+    /// * `points` - Points amounts committed by player 1 and player 2
+    /// * `anchors` - Last links of player 1's and player 2's hash chains
+    /// * `rules` - Rule set for this session
+    ///
+    /// # Returns
+    /// * `u32` - The session ID of the new game
     pub fn start_game(
         env: Env,
         session_id: u32,
         player1: Address,
         player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    ) -> Result<(), Error> {
+        points: (i128, i128),
+        anchors: (BytesN<32>, BytesN<32>),
+        rules: Rules,
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
-        if player1 == player2 {
-            return Err(Error::SelfPlay);
-        }
-
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Create game
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        session::require_distinct_players(&player1, &player2)?;
+        let (player1_points, player2_points) = points;
+
+        // Require authentication from both players (they consent to committing points
+        // and to their own chain anchor)
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            anchors.0.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            anchors.1.clone().into_val(&env),
+        ]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
-            player1_stuck: false,
-            player2_stuck: false,
-            winner: None,
-            round: 1,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-        // Event emitted by GameHub contract (GameStarted)
+            anchors,
+            None,
+            rules,
+        )
+    }
 
-        Ok(())
+    /// Start a new hidden-hand game (see module docs).
+    /// Same as `start_game`, but each player also commits the anchor of their hand
+    /// chain, and only the first card of each hand is dealt face up.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `points` - Points amounts committed by player 1 and player 2
+    /// * `anchors` - Player 1's and player 2's draw and hand chain anchors
+    /// * `rules` - Rule set for this session
+    ///
+    /// # Returns
+    /// * `u32` - The session ID of the new game
+    pub fn start_hidden_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        points: (i128, i128),
+        anchors: (Anchors, Anchors),
+        rules: Rules,
+    ) -> Result<u32, Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        session::require_distinct_players(&player1, &player2)?;
+        let (player1_points, player2_points) = points;
+        let (player1_anchors, player2_anchors) = anchors;
+
+        // Require authentication from both players (they consent to committing points
+        // and to their own chain anchors)
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            player1_anchors.clone().into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            player2_anchors.clone().into_val(&env),
+        ]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            (player1_anchors.draw, player2_anchors.draw),
+            Some((player1_anchors.hand, player2_anchors.hand)),
+            rules,
+        )
     }
 
     /// Player draws another card ("hit").
-    /// If the player's hand value exceeds 21, they bust and lose immediately.
+    /// The player reveals their next chain link with the request; the card is dealt
+    /// when the opponent reveals theirs with `reveal_link`. If the player's hand
+    /// value then exceeds 21, they bust and lose immediately.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player drawing a card
-    pub fn hit(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `link` - The player's next hash chain link
+    pub fn hit(env: Env, session_id: u32, player: Address, link: BytesN<32>) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -309,64 +532,125 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
+        // Only one draw can be in flight at a time
+        if game.pending_draw != Draw::None {
+            return Err(Error::DrawPending);
+        }
 
-        let card_count = if is_player1 {
-            game.player1_hand.len()
+        game.pending_draw = if is_player1 {
+            Draw::Player1Hit
         } else {
-            game.player2_hand.len()
+            Draw::Player2Hit
         };
+        Self::accept_link(&env, &mut game, is_player1, link)?;
+
+        // Store updated game
+        session::save(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the next link of a player's hash chain for the pending draw.
+    /// The link must hash to the last link the contract verified for this player
+    /// (the anchor for the first draw). Once both players have revealed, the
+    /// pending cards are dealt; a bust ends the game like in `hit`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `link` - The player's next hash chain link
+    pub fn reveal_link(env: Env, session_id: u32, player: Address, link: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
 
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Add card to player's hand
-        if is_player1 {
-            game.player1_hand.push_back(new_card);
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
 
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player1_hand)?;
-            if hand_value > 21 {
-                // Player 1 busted, player 2 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
+        let draw = game.pending_draw;
+        if draw == Draw::None {
+            return Err(Error::NoPendingDraw);
+        }
+        Self::accept_link(&env, &mut game, is_player1, link)?;
 
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player2.clone());
-                env.storage().temporary().set(&key, &game);
+        if game.player1_revealed && game.player2_revealed {
+            Self::complete_draw(&env, session_id, &mut game, draw)?;
+        }
 
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        session::save(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Settle a game whose opponent failed to reveal in time.
+    ///
+    /// Covers a pending draw (past `draw_deadline`, the claimant revealed their link
+    /// and the opponent did not) and a hidden-hand showdown (past `showdown_deadline`,
+    /// the opponent has not revealed their hand). A claimant who revealed their hand
+    /// wins; if neither player revealed, the session ends as a draw in the Game Hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `claimant` - Address of the player claiming
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winning player (the claimant), or `None` if neither
+    ///   hand was revealed and both stakes were refunded
+    pub fn claim_timeout(env: Env, session_id: u32, claimant: Address) -> Result<Option<Address>, Error> {
+        claimant.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = claimant == game.player1;
+        let (claimant_link, opponent_link, claimant_hidden, opponent_hidden) = if is_player1 {
+            (game.player1_revealed, game.player2_revealed, &game.player1_hidden, &game.player2_hidden)
+        } else if claimant == game.player2 {
+            (game.player2_revealed, game.player1_revealed, &game.player2_hidden, &game.player1_hidden)
         } else {
-            game.player2_hand.push_back(new_card);
+            return Err(Error::NotPlayer);
+        };
 
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player2_hand)?;
-            if hand_value > 21 {
-                // Player 2 busted, player 1 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
+        let showdown = game.hidden && game.player1_stuck && game.player2_stuck && game.pending_draw == Draw::None;
+        if game.pending_draw == Draw::None && !showdown {
+            return Err(Error::NoPendingDraw);
+        }
 
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player1.clone());
-                env.storage().temporary().set(&key, &game);
+        let (deadline, neither_revealed) = if game.pending_draw != Draw::None && claimant_link && !opponent_link {
+            (game.draw_deadline, false)
+        } else if showdown && !opponent_hidden.is_empty() {
+            (game.showdown_deadline, !claimant_hidden.is_empty())
+        } else {
+            return Err(Error::CannotClaimTimeout);
+        };
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::DeadlineNotReached);
+        }
 
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        // Neither hand can be judged, so both stakes are refunded
+        if neither_revealed {
+            hub::end_game_draw(&env, session_id)?;
+            game.forfeited = true;
+            session::save(&env, session_id, &game);
+            return Ok(None);
         }
 
-        // Store updated game
-        env.storage().temporary().set(&key, &game);
+        // Call GameHub FIRST (before setting winner)
+        Self::end_game_with_hub(&env, session_id, is_player1)?;
 
-        Ok(())
+        game.winner = Some(claimant.clone());
+        session::save(&env, session_id, &game);
+
+        Ok(Some(claimant))
     }
 
     /// Player chooses to stick (end their turn with current hand).
@@ -379,18 +663,18 @@ impl TwentyOneContract {
         player.require_auth();
 
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // A player cannot stick while a card is waiting to be dealt
+        if game.pending_draw != Draw::None {
+            return Err(Error::DrawPending);
+        }
+
         // Mark player as stuck
         if player == game.player1 {
             if game.player1_stuck {
@@ -406,34 +690,102 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        // In house mode the dealer plays once both players have stuck
+        if game.rules.house && game.player1_stuck && game.player2_stuck {
+            game.pending_draw = Draw::Dealer;
+        }
+        start_showdown(&env, &mut game);
+
         // Store updated game
-        env.storage().temporary().set(&key, &game);
+        session::save(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Reveal a player's face-down cards in a hidden-hand game.
+    /// Can only be called after both players have stuck. The secret must hash to the
+    /// last verified link of the player's hand chain; the contract recomputes every
+    /// face-down card from it and adds them to the player's hand.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `hand_secret` - The player's hand secret for the current round
+    pub fn reveal_hand(env: Env, session_id: u32, player: Address, hand_secret: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        if !game.player1_stuck || !game.player2_stuck {
+            return Err(Error::BothPlayersNotStuck);
+        }
+
+        let (hand, hidden, hand_link, player_index, opponent_hidden) = if is_player1 {
+            (&mut game.player1_hand, &mut game.player1_hidden, &mut game.player1_hand_link, 1, &game.player2_hidden)
+        } else {
+            (&mut game.player2_hand, &mut game.player2_hidden, &mut game.player2_hand_link, 2, &game.player1_hidden)
+        };
+
+        if hidden.is_empty() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(hand_secret.clone())).into();
+        if Some(hash) != *hand_link {
+            return Err(Error::HashMismatch);
+        }
+
+        for draw_seed in hidden.iter() {
+            hand.push_back(deal_hidden(&env, &hand_secret, &draw_seed, player_index));
+        }
+        *hidden = Vec::new(&env);
+        *hand_link = Some(hand_secret);
+
+        // The opponent now knows this total: they must reveal theirs in time
+        if !opponent_hidden.is_empty() {
+            game.showdown_deadline = session::reveal_deadline(&env);
+        }
+
+        session::save(&env, session_id, &game);
 
         Ok(())
     }
 
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have stuck.
+    /// Can only be called after both players have stuck (and, in hidden-hand games,
+    /// revealed their hands).
     /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// and handles ties by starting a new round.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` after a tie
+    ///   (the new round is dealt once both players reveal their next links)
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         // Check if game already ended (has a winner)
         if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+            return Ok(Some(winner.clone()));
+        }
+        if game.forfeited {
+            return Err(Error::GameAlreadyEnded);
         }
 
         // Check both players have stuck
@@ -441,16 +793,37 @@ impl TwentyOneContract {
             return Err(Error::BothPlayersNotStuck);
         }
 
-        // Calculate hand values
-        let player1_value = calculate_hand_value(&game.player1_hand)?;
-        let player2_value = calculate_hand_value(&game.player2_hand)?;
+        // The dealer must have played (house mode)
+        if game.pending_draw != Draw::None {
+            return Err(Error::DrawPending);
+        }
+
+        // Hidden hands must be revealed before they can be compared
+        if !game.player1_hidden.is_empty() || !game.player2_hidden.is_empty() {
+            return Err(Error::HandNotRevealed);
+        }
+
+        // Rank hands (a bust ranks lowest, a natural beats a drawn 21)
+        // Note: Public busts are already handled when cards are dealt; hidden busts rank 0
+        let player1_rank = hand_rank(&game.player1_hand, &game.rules)?;
+        let player2_rank = hand_rank(&game.player2_hand, &game.rules)?;
+
+        // In house mode, the result against the dealer comes first
+        let (player1_score, player2_score) = if game.rules.house {
+            let dealer_rank = hand_rank(&game.dealer_hand, &game.rules)?;
+            (
+                (against_dealer(player1_rank, dealer_rank), player1_rank),
+                (against_dealer(player2_rank, dealer_rank), player2_rank),
+            )
+        } else {
+            ((0, player1_rank), (0, player2_rank))
+        };
 
         // Determine winner (closest to 21 without going over)
-        // Note: Bust conditions are already handled in hit(), so values should be <= 21
-        let winner = if player1_value > player2_value {
+        let winner = if player1_score > player2_score {
             // Player 1 is closer to 21
             game.player1.clone()
-        } else if player2_value > player1_value {
+        } else if player2_score > player1_score {
             // Player 2 is closer to 21
             game.player2.clone()
         } else {
@@ -464,37 +837,21 @@ impl TwentyOneContract {
             // Clear hands
             game.player1_hand = Bytes::new(&env);
             game.player2_hand = Bytes::new(&env);
+            game.dealer_hand = Bytes::new(&env);
+            game.showdown_deadline = 0;
 
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
+            // The shoe carries over unless it is running low
+            if game.rules.decks > 0 && game.shoe.len() < game.rules.reshuffle_below {
+                game.shoe = new_shoe(&env, game.rules.decks);
             }
 
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
+            // New hands are dealt once both players reveal their next links
+            game.pending_draw = Draw::Deal;
 
-            // Store updated game and return error to indicate draw
-            env.storage().temporary().set(&key, &game);
+            // Store updated game; returning an error here would roll the new round back
+            session::save(&env, session_id, &game);
 
-            return Err(Error::Draw);
+            return Ok(None);
         };
 
         // Call GameHub FIRST (before setting winner)
@@ -503,9 +860,9 @@ impl TwentyOneContract {
 
         // Only update game with winner AFTER GameHub succeeds
         game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+        session::save(&env, session_id, &game);
 
-        Ok(winner)
+        Ok(Some(winner))
     }
 
     /// Get game information.
@@ -516,14 +873,11 @@ impl TwentyOneContract {
     /// # Returns
     /// * `Game` - The game state (includes hands and winner after game ends)
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+        session::load(&env, session_id).ok_or(Error::GameNotFound)
     }
 
     /// Get the current hand value for a player.
+    /// In hidden-hand games this only counts face-up cards until `reveal_hand`.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -532,17 +886,12 @@ impl TwentyOneContract {
     /// # Returns
     /// * `u32` - The total value of the player's hand
     pub fn get_hand_value(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
-        let key = DataKey::Game(session_id);
-        let game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let game: Game = session::load(&env, session_id).ok_or(Error::GameNotFound)?;
 
         if player == game.player1 {
-            calculate_hand_value(&game.player1_hand)
+            calculate_hand_value(&game.player1_hand, game.rules.soft_aces)
         } else if player == game.player2 {
-            calculate_hand_value(&game.player2_hand)
+            calculate_hand_value(&game.player2_hand, game.rules.soft_aces)
         } else {
             Err(Error::NotPlayer)
         }
@@ -552,100 +901,217 @@ impl TwentyOneContract {
     // Internal Helper Functions
     // ========================================================================
 
-    /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+    /// Open the Game Hub session and store a new game awaiting its initial deal.
+    /// `hand_anchors` is `Some` for hidden-hand games.
+    #[allow(clippy::too_many_arguments)]
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        anchors: (BytesN<32>, BytesN<32>),
+        hand_anchors: Option<(BytesN<32>, BytesN<32>)>,
+        rules: Rules,
+    ) -> Result<u32, Error> {
+        if rules.decks > MAX_DECKS {
+            return Err(Error::InvalidRules);
+        }
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        // Use the requested ID unless it already holds a game, or allocate one
+        let session_id = session::claim(env, session_id)?;
 
-        // Call the Game Hub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        hub::start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        )?;
 
-        Ok(())
-    }
+        let (player1_hand_link, player2_hand_link) = match hand_anchors {
+            Some((hand1, hand2)) => (Some(hand1), Some(hand2)),
+            None => (None, None),
+        };
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+        // Create game with empty hands; the initial deal waits for both players' links
+        let game = Game {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            player1_hand: Bytes::new(env),
+            player2_hand: Bytes::new(env),
+            player1_stuck: false,
+            player2_stuck: false,
+            winner: None,
+            round: 1,
+            player1_link: anchors.0,
+            player2_link: anchors.1,
+            pending_draw: Draw::Deal,
+            player1_revealed: false,
+            player2_revealed: false,
+            draws: 0,
+            draw_deadline: 0,
+            hidden: player1_hand_link.is_some(),
+            player1_hand_link,
+            player2_hand_link,
+            player1_hidden: Vec::new(env),
+            player2_hidden: Vec::new(env),
+            showdown_deadline: 0,
+            forfeited: false,
+            rules,
+            dealer_hand: Bytes::new(env),
+            shoe: new_shoe(env, rules.decks),
+        };
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
+        // Store game in temporary storage with 30-day TTL
+        session::save(env, session_id, &game);
 
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(session_id)
     }
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    /// Verify `link` against the player's last chain link and record the reveal
+    fn accept_link(env: &Env, game: &mut Game, is_player1: bool, link: BytesN<32>) -> Result<(), Error> {
+        let (last_link, revealed) = if is_player1 {
+            (&mut game.player1_link, &mut game.player1_revealed)
+        } else {
+            (&mut game.player2_link, &mut game.player2_revealed)
+        };
+
+        if *revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(link.clone())).into();
+        if hash != *last_link {
+            return Err(Error::HashMismatch);
+        }
+
+        *last_link = link;
+        *revealed = true;
+
+        // The first link of a draw starts the clock for the other player
+        if game.player1_revealed != game.player2_revealed {
+            game.draw_deadline = session::reveal_deadline(env);
+        }
+
+        Ok(())
     }
 
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+    /// Deal the cards of a draw once both players have revealed their links
+    fn complete_draw(env: &Env, session_id: u32, game: &mut Game, draw: Draw) -> Result<(), Error> {
+        // Seed from the freshly revealed links only (identical between sim/submit)
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&Bytes::from(game.player1_link.clone()));
+        seed_bytes.append(&Bytes::from(game.player2_link.clone()));
+        let base_seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+        game.pending_draw = Draw::None;
+        game.player1_revealed = false;
+        game.player2_revealed = false;
+        game.draw_deadline = 0;
+        game.draws = game.draws.checked_add(1).ok_or(Error::RoundOverflow)?;
+
+        match draw {
+            Draw::None => {}
+            Draw::Deal => {
+                if game.hidden {
+                    // Hidden mode: one face-up card each, the second one face down.
+                    // Only the holder can compute a face-down card.
+                    let card = deal_from(env, game, &base_seed, 0, 1)?;
+                    game.player1_hand.push_back(card);
+                    let card = deal_from(env, game, &base_seed, 0, 2)?;
+                    game.player2_hand.push_back(card);
+                    game.player1_hidden.push_back(base_seed.clone());
+                    game.player2_hidden.push_back(base_seed.clone());
+                } else {
+                    // Deal 2 cards to each player
+                    for i in 0..2 {
+                        let card = deal_from(env, game, &base_seed, i, 1)?;
+                        game.player1_hand.push_back(card);
+                        let card = deal_from(env, game, &base_seed, i, 2)?;
+                        game.player2_hand.push_back(card);
+                    }
+                }
+
+                // The dealer's face-up card
+                if game.rules.house {
+                    let card = deal_from(env, game, &base_seed, 0, DEALER)?;
+                    game.dealer_hand.push_back(card);
+                }
+            }
+            // Hidden mode: hits are face down and busts wait for the showdown
+            Draw::Player1Hit if game.hidden => game.player1_hidden.push_back(base_seed),
+            Draw::Player2Hit if game.hidden => game.player2_hidden.push_back(base_seed),
+            Draw::Player1Hit => {
+                let card = deal_from(env, game, &base_seed, 0, 1)?;
+                game.player1_hand.push_back(card);
+
+                // Check if player busted
+                if calculate_hand_value(&game.player1_hand, game.rules.soft_aces)? > 21 {
+                    // Player 1 busted, player 2 wins
+                    // Call GameHub FIRST (before setting winner)
+                    Self::end_game_with_hub(env, session_id, false)?;
+                    game.winner = Some(game.player2.clone());
+                }
+            }
+            Draw::Player2Hit => {
+                let card = deal_from(env, game, &base_seed, 0, 2)?;
+                game.player2_hand.push_back(card);
+
+                // Check if player busted
+                if calculate_hand_value(&game.player2_hand, game.rules.soft_aces)? > 21 {
+                    // Player 2 busted, player 1 wins
+                    // Call GameHub FIRST (before setting winner)
+                    Self::end_game_with_hub(env, session_id, true)?;
+                    game.winner = Some(game.player1.clone());
+                }
+            }
+            Draw::Dealer => {
+                // Hole card, then draw until the dealer stands
+                let mut index = 0u8;
+                loop {
+                    let card = deal_from(env, game, &base_seed, index, DEALER)?;
+                    game.dealer_hand.push_back(card);
+                    index += 1;
+                    if calculate_hand_value(&game.dealer_hand, game.rules.soft_aces)? >= DEALER_STANDS_ON {
+                        break;
+                    }
+                }
+                start_showdown(env, game);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Helper to end game with the Game Hub
+    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        // Call the Game Hub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameEnded)
+        hub::end_game(env, session_id, player1_won)?;
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+// get_admin / set_admin / get_hub / set_hub / upgrade
+#[contractimpl(contracttrait)]
+impl Admin for TwentyOneContract {}
+
 // ============================================================================
 // Tests
 // ============================================================================
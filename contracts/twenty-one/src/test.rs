@@ -7,7 +7,11 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+use crate::{
+    against_dealer, calculate_hand_value, hand_rank, Anchors, Draw, Error, Rules,
+    TwentyOneContract, TwentyOneContractClient,
+};
+use game_core::CoreKey;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
@@ -32,10 +36,19 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn reserve_session_id(_env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - always allocates the same ID
+        42
+    }
+
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_draw(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -97,7 +110,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_hit(&session_id, &player);
+/// let result = client.try_hit(&session_id, &player, &link);
 /// assert_twenty_one_error(&result, Error::AlreadyStuck);
 /// ```
 ///
@@ -144,26 +157,178 @@ fn assert_twenty_one_error<T, E>(
 fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     let mut total = 0u32;
     for i in 0..hand.len() {
-        let card = hand.get(i).unwrap() as u32;
+        let card = (hand.get(i).unwrap() & 0x0F) as u32;
         let value = if card >= 10 { 10 } else { card };
         total += value;
     }
     total
 }
 
+/// Ace is 1, no dealer, infinite deck
+const CLASSIC: Rules = Rules {
+    soft_aces: false,
+    house: false,
+    decks: 0,
+    reshuffle_below: 0,
+};
+
+/// Number of links in each test hash chain
+const CHAIN_LEN: u32 = 64;
+
+/// Link `depth` of the hash chain built from seed `[tag; 32]`.
+/// Depth 0 is the anchor committed at start; each draw consumes the next depth.
+fn chain_link(env: &Env, tag: u8, depth: u32) -> BytesN<32> {
+    let mut link = BytesN::from_array(env, &[tag; 32]);
+    for _ in depth..CHAIN_LEN {
+        link = env.crypto().keccak256(&Bytes::from(link)).into();
+    }
+    link
+}
+
+/// The link `player` must reveal for the next draw (player 1 uses tag 1, player 2 tag 2)
+fn next_link(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player: &Address,
+) -> BytesN<32> {
+    let game = client.get_game(session_id);
+    let tag = if *player == game.player1 { 1 } else { 2 };
+    chain_link(env, tag, game.draws + 1)
+}
+
+/// Helper: start a game and have both players reveal the links for the initial deal
+fn start_game(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: &i128,
+    player2_points: &i128,
+) {
+    client.start_game(
+        session_id,
+        player1,
+        player2,
+        &(*player1_points, *player2_points),
+        &(chain_link(env, 1, 0), chain_link(env, 2, 0)),
+        &CLASSIC,
+    );
+    client.reveal_link(session_id, player1, &next_link(env, client, session_id, player1));
+    client.reveal_link(session_id, player2, &next_link(env, client, session_id, player2));
+}
+
+/// Helper: start a game under `rules` (100 points each) and deal
+fn start_game_with_rules(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player1: &Address,
+    player2: &Address,
+    rules: &Rules,
+) {
+    client.start_game(
+        session_id,
+        player1,
+        player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(env, 1, 0), chain_link(env, 2, 0)),
+        rules,
+    );
+    client.reveal_link(session_id, player1, &next_link(env, client, session_id, player1));
+    client.reveal_link(session_id, player2, &next_link(env, client, session_id, player2));
+}
+
+/// Helper: start games under `rules` from `first_session` on until the initial hands
+/// tie, then have both players stick. Returns the tied session ID.
+fn start_tied_game(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    first_session: u32,
+    player1: &Address,
+    player2: &Address,
+    rules: &Rules,
+) -> u32 {
+    for session_id in first_session..first_session + 200 {
+        start_game_with_rules(env, client, &session_id, player1, player2, rules);
+        let game = client.get_game(&session_id);
+        let player1_value = calculate_hand_value(&game.player1_hand, rules.soft_aces).unwrap();
+        let player2_value = calculate_hand_value(&game.player2_hand, rules.soft_aces).unwrap();
+        if player1_value == player2_value {
+            client.stick(&session_id, player1);
+            client.stick(&session_id, player2);
+            return session_id;
+        }
+    }
+    panic!("no tied deal found");
+}
+
+/// Helper: how many times each card byte appears in `hands`
+fn card_counts(hands: &[&Bytes]) -> [u32; 64] {
+    let mut counts = [0u32; 64];
+    for hand in hands {
+        for card in hand.iter() {
+            counts[card as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Helper: start a hidden-hand game (hand chains use tags 11 and 12) and deal
+fn start_hidden_game(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    client.start_hidden_game(
+        session_id,
+        player1,
+        player2,
+        &(100_0000000, 100_0000000),
+        &(
+            Anchors {
+                draw: chain_link(env, 1, 0),
+                hand: chain_link(env, 11, 0),
+            },
+            Anchors {
+                draw: chain_link(env, 2, 0),
+                hand: chain_link(env, 12, 0),
+            },
+        ),
+        &CLASSIC,
+    );
+    client.reveal_link(session_id, player1, &next_link(env, client, session_id, player1));
+    client.reveal_link(session_id, player2, &next_link(env, client, session_id, player2));
+}
+
+/// Helper: `player` hits and the opponent reveals their link so the card is dealt
+fn hit(env: &Env, client: &TwentyOneContractClient, session_id: &u32, player: &Address) {
+    let game = client.get_game(session_id);
+    let opponent = if *player == game.player1 {
+        game.player2
+    } else {
+        game.player1
+    };
+    client.hit(session_id, player, &next_link(env, client, session_id, player));
+    client.reveal_link(session_id, &opponent, &next_link(env, client, session_id, &opponent));
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game_simple() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    start_game(&env, &client, &session_id, &player1, &player2, &points, &points);
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
@@ -174,15 +339,15 @@ fn test_complete_game_simple() {
     assert_eq!(game.player2_points, points);
     assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
     assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
 
     // Both players stick immediately (no hits)
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
     // Reveal winner
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Verify game is ended
@@ -193,10 +358,10 @@ fn test_complete_game_simple() {
 
 #[test]
 fn test_initial_cards_dealt() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
 
@@ -204,31 +369,27 @@ fn test_initial_cards_dealt() {
     assert_eq!(game.player1_hand.len(), 2);
     assert_eq!(game.player2_hand.len(), 2);
 
-    // Cards should be in valid range (1-13)
-    for i in 0..game.player1_hand.len() {
-        let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
-    }
-    for i in 0..game.player2_hand.len() {
-        let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+    // Ranks should be in valid range (1-13), suits in 0-3
+    for card in game.player1_hand.iter().chain(game.player2_hand.iter()) {
+        assert!((1..=13).contains(&(card & 0x0F)), "Rank should be between 1-13");
+        assert!(card >> 4 < 4, "Suit should be between 0-3");
     }
 }
 
 #[test]
 fn test_get_hand_value() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Get hand values
     let player1_value = client.get_hand_value(&session_id, &player1);
     let player2_value = client.get_hand_value(&session_id, &player2);
 
     // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((2..=20).contains(&player1_value));
+    assert!((2..=20).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
@@ -241,16 +402,16 @@ fn test_get_hand_value() {
 
 #[test]
 fn test_hit_adds_card() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
     // Player 1 hits
-    client.hit(&session_id, &player1);
+    hit(&env, &client, &session_id, &player1);
 
     let after_hit_game = client.get_game(&session_id);
     assert_eq!(after_hit_game.player1_hand.len(), initial_hand_size + 1);
@@ -258,44 +419,38 @@ fn test_hit_adds_card() {
 
 #[test]
 fn test_stick_prevents_further_hits() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
 
     // Try to hit after sticking - should fail
-    let result = client.try_hit(&session_id, &player1);
+    let result = client.try_hit(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
     assert_twenty_one_error(&result, Error::AlreadyStuck);
 }
 
 #[test]
 fn test_multiple_hits_allowed() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
-    // Player 1 hits multiple times (be careful not to bust in deterministic test)
-    // This test may fail if player1 busts, but we're testing the mechanics
-    let result1 = client.try_hit(&session_id, &player1);
-
-    // If first hit succeeds (didn't bust), try another
-    if result1.is_ok() {
-        let mid_game = client.get_game(&session_id);
-        assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
+    // Player 1 hits twice unless the first card busts them
+    hit(&env, &client, &session_id, &player1);
+    let mid_game = client.get_game(&session_id);
+    assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
 
-        let result2 = client.try_hit(&session_id, &player1);
-        // Could succeed or fail (bust), both are valid
-        if result2.is_ok() {
-            let final_game = client.get_game(&session_id);
-            assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
-        }
+    if mid_game.winner.is_none() {
+        hit(&env, &client, &session_id, &player1);
+        let final_game = client.get_game(&session_id);
+        assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
     }
 }
 
@@ -305,37 +460,38 @@ fn test_multiple_hits_allowed() {
 
 #[test]
 fn test_closer_to_21_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    let winner = client.reveal_winner(&session_id);
-
-    // Get final hand values
+    // Get hand values before a tie could clear them
     let game = client.get_game(&session_id);
     let player1_value = calculate_hand_value_helper(&game.player1_hand);
     let player2_value = calculate_hand_value_helper(&game.player2_hand);
 
-    // Winner should be closer to 21
+    let winner = client.reveal_winner(&session_id);
+
+    // Winner should be closer to 21; equal hands start a new round
     if player1_value > player2_value {
-        assert_eq!(winner, player1);
+        assert_eq!(winner, Some(player1));
     } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
+        assert_eq!(winner, Some(player2));
+    } else {
+        assert_eq!(winner, None);
     }
-    // If equal, test will continue (draw handling)
 }
 
 #[test]
 fn test_reveal_winner_requires_both_stuck() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
@@ -351,26 +507,26 @@ fn test_reveal_winner_requires_both_stuck() {
 
 #[test]
 fn test_bust_detection() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Keep hitting until player1 busts
     // Note: With enough hits, player will eventually bust (hand value > 21)
     let mut busted = false;
     for _ in 0..20 {
         // Hit succeeds even when busting (returns Ok), but game ends
-        client.hit(&session_id, &player1);
+        hit(&env, &client, &session_id, &player1);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
+            assert_eq!(winner, player2, "Player 2 should win when player 1 busts");
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
+            let result = client.try_hit(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
             assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
@@ -381,24 +537,24 @@ fn test_bust_detection() {
 
 #[test]
 fn test_cannot_hit_after_bust() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Hit until bust (game ends)
     let mut did_bust = false;
     for _ in 0..20 {
-        client.hit(&session_id, &player1);
+        hit(&env, &client, &session_id, &player1);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
+            assert_eq!(winner, player2);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
+            let result = client.try_hit(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
             assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
@@ -413,34 +569,28 @@ fn test_cannot_hit_after_bust() {
 
 #[test]
 fn test_draw_starts_new_round() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Both players stick immediately
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
-
-    let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Only test draw behavior if hands are equal
-    if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
-
-        // Verify new round was created
-        let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
-    }
-    // If not a draw, test passes (no assertion needed)
+    let session_id = start_tied_game(&env, &client, 100, &player1, &player2, &CLASSIC);
+
+    // A tie is not an error: the new round must persist
+    assert_eq!(client.reveal_winner(&session_id), None);
+
+    // Verify new round was created
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.round, 2); // Round should increment
+    assert!(game_after.winner.is_none());
+    assert!(!game_after.player1_stuck); // Flags reset
+    assert!(!game_after.player2_stuck);
+    assert_eq!(game_after.player1_hand.len(), 0);
+    assert_eq!(game_after.pending_draw, Draw::Deal);
+
+    // New cards are dealt once both players reveal their next links
+    client.reveal_link(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
+    client.reveal_link(&session_id, &player2, &next_link(&env, &client, &session_id, &player2));
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.player1_hand.len(), 2);
+    assert_eq!(game_after.player2_hand.len(), 2);
 }
 
 // ============================================================================
@@ -449,10 +599,10 @@ fn test_draw_starts_new_round() {
 
 #[test]
 fn test_cannot_stick_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -468,10 +618,10 @@ fn test_non_player_cannot_hit() {
     let non_player = Address::generate(&env);
 
     let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to hit
-    let result = client.try_hit(&session_id, &non_player);
+    let result = client.try_hit(&session_id, &non_player, &next_link(&env, &client, &session_id, &non_player));
     assert_twenty_one_error(&result, Error::NotPlayer);
 }
 
@@ -481,7 +631,7 @@ fn test_non_player_cannot_stick() {
     let non_player = Address::generate(&env);
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
@@ -494,7 +644,7 @@ fn test_non_player_cannot_get_hand_value() {
     let non_player = Address::generate(&env);
 
     let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -503,9 +653,9 @@ fn test_non_player_cannot_get_hand_value() {
 
 #[test]
 fn test_cannot_hit_nonexistent_game() {
-    let (_env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
-    let result = client.try_hit(&999, &player1);
+    let result = client.try_hit(&999, &player1, &chain_link(&env, 1, 1));
     assert_twenty_one_error(&result, Error::GameNotFound);
 }
 
@@ -535,31 +685,31 @@ fn test_cannot_get_nonexistent_game() {
 
 #[test]
 fn test_cannot_hit_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner (may panic if draw, but that's okay)
+    // Reveal winner (a tie starts a new round instead)
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Some(_))) = result {
         // Game ended successfully
         // Try to hit after game ended
-        let hit_result = client.try_hit(&session_id, &player1);
+        let hit_result = client.try_hit(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
         assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
     }
 }
 
 #[test]
 fn test_cannot_stick_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -569,7 +719,7 @@ fn test_cannot_stick_after_game_ended() {
 
     // Reveal winner
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Some(_))) = result {
         // Game ended, verify stuck flag is set
         let game = client.get_game(&session_id);
         assert!(game.player1_stuck);
@@ -582,22 +732,20 @@ fn test_cannot_stick_after_game_ended() {
 
 #[test]
 fn test_reveal_winner_idempotent() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
     // First reveal
     let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
-
+    if let Ok(Ok(Some(winner1))) = result1 {
         // Second reveal should return same winner (idempotent)
         let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
+        assert_eq!(winner2, Some(winner1));
     }
 }
 
@@ -615,12 +763,12 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    start_game(&env, &client, &session1, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session2, &player3, &player4, &50_0000000, &50_0000000);
 
-    // Play both games independently (use try_ methods to handle potential busts)
-    let _ = client.try_hit(&session1, &player1);
-    let _ = client.try_hit(&session2, &player3);
+    // Play both games independently (a hit may end a game with a bust)
+    hit(&env, &client, &session1, &player1);
+    hit(&env, &client, &session2, &player3);
 
     // Only continue if games haven't ended from busts
     let game1_check = client.get_game(&session1);
@@ -653,8 +801,8 @@ fn test_multiple_sessions() {
     let session1 = 22u32;
     let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    start_game(&env, &client, &session1, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session2, &player3, &player4, &50_0000000, &50_0000000);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -668,13 +816,13 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 24u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    start_game(&env, &client, &session_id, &player1, &player2, &points1, &points2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
@@ -695,29 +843,29 @@ fn test_asymmetric_points() {
 fn test_face_cards_worth_10() {
     // This is a deterministic test of card value logic
     // We can't control what cards are dealt, but we can verify the hand value calculation
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
 
     // Manually verify card values
     for i in 0..game.player1_hand.len() {
-        let card = game.player1_hand.get(i).unwrap() as u32;
+        let card = (game.player1_hand.get(i).unwrap() & 0x0F) as u32;
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
 #[test]
 fn test_hand_value_calculation() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -827,31 +975,61 @@ fn test_upgrade_function_exists() {
 
 #[test]
 fn test_deterministic_card_dealing() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 27u32;
 
     // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
     let game1 = client.get_game(&session_id);
 
-    // Start second game with same session_id in new environment (should be identical)
-    let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
+    // Same session ID and chains in a new environment: cards depend only on the
+    // revealed links, not on addresses or ledger state, so the hands are identical
+    let (env2, client2, _hub2, player1_2, player2_2) = setup_test();
+    env2.ledger().with_mut(|li| li.sequence_number += 1_000);
+    start_game(&env2, &client2, &session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
     let game2 = client2.get_game(&session_id);
 
-    // Note: Since we generate new addresses each time, the cards will be different
-    // But we can verify that within the same session, cards are consistent
     assert_eq!(game1.player1_hand.len(), 2);
-    assert_eq!(game2.player1_hand.len(), 2);
+    assert_eq!(game1.player1_hand, game2.player1_hand);
+    assert_eq!(game1.player2_hand, game2.player2_hand);
+}
+
+#[test]
+fn test_cards_depend_on_both_chains() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let game1 = client.get_game(&session_id);
+
+    // Same players and session, but player 2 uses a different chain
+    let (env2, client2, _hub2, _, _) = setup_test();
+    client2.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env2, 1, 0), chain_link(&env2, 7, 0)),
+        &CLASSIC,
+    );
+    client2.reveal_link(&session_id, &player1, &chain_link(&env2, 1, 1));
+    client2.reveal_link(&session_id, &player2, &chain_link(&env2, 7, 1));
+    let game2 = client2.get_game(&session_id);
+
+    let mut hands1 = game1.player1_hand.clone();
+    hands1.append(&game1.player2_hand);
+    let mut hands2 = game2.player1_hand.clone();
+    hands2.append(&game2.player2_hand);
+    assert_ne!(hands1, hands2);
 }
 
 #[test]
 fn test_round_counter() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.round, 1); // First round
@@ -863,10 +1041,711 @@ fn test_round_counter() {
 
 #[test]
 fn test_cannot_play_against_self() {
-    let (_env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
     let session_id = 29u32;
     // Try to start game where player1 plays against themselves
-    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
+    let anchor = chain_link(&env, 1, 0);
+    let result = client.try_start_game(
+        &session_id,
+        &player1,
+        &player1,
+        &(100_0000000, 100_0000000),
+        &(anchor.clone(), anchor.clone()),
+        &CLASSIC,
+    );
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
+
+#[test]
+fn test_hub_not_set() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CoreKey::GameHubAddress);
+    });
+
+    let anchor1 = chain_link(&env, 1, 0);
+    let anchor2 = chain_link(&env, 2, 0);
+    let result = client.try_start_game(
+        &31,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(anchor1.clone(), anchor2.clone()),
+        &CLASSIC,
+    );
+    assert_twenty_one_error(&result, Error::HubNotSet);
+
+    // The draw that busts player 1 cannot report the result
+    for _ in 0..20 {
+        client.hit(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
+        let link = next_link(&env, &client, &session_id, &player2);
+        let result = client.try_reveal_link(&session_id, &player2, &link);
+        if result.is_err() {
+            assert_twenty_one_error(&result, Error::HubNotSet);
+            assert_eq!(client.get_game(&session_id).winner, None);
+            return;
+        }
+    }
+    panic!("Player should have busted after 20 hits");
+}
+
+#[test]
+fn test_session_exists_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_game(&env, &client, &32, &player1, &player2, &100_0000000, &100_0000000);
+    let anchor1 = chain_link(&env, 1, 0);
+    let anchor2 = chain_link(&env, 2, 0);
+    let result = client.try_start_game(
+        &32,
+        &player2,
+        &player1,
+        &(100_0000000, 100_0000000),
+        &(anchor2.clone(), anchor1.clone()),
+        &CLASSIC,
+    );
+    assert_twenty_one_error(&result, Error::SessionExists);
+    assert_eq!(client.get_game(&32).player1, player1);
+}
+
+#[test]
+fn test_session_id_allocated_by_hub() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(
+        &0,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env, 1, 0), chain_link(&env, 2, 0)),
+        &CLASSIC,
+    );
+    assert_eq!(session_id, 42);
+    assert_eq!(client.get_game(&42).player1, player1);
+}
+
+// ============================================================================
+// Entropy Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_initial_deal_waits_for_both_links() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env, 1, 0), chain_link(&env, 2, 0)),
+        &CLASSIC,
+    );
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 0);
+    assert_eq!(game.pending_draw, Draw::Deal);
+
+    // Nobody can act on an empty hand
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DrawPending);
+
+    client.reveal_link(&session_id, &player1, &chain_link(&env, 1, 1));
+    assert_eq!(client.get_game(&session_id).player1_hand.len(), 0);
+
+    let result = client.try_reveal_link(&session_id, &player1, &chain_link(&env, 1, 2));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    client.reveal_link(&session_id, &player2, &chain_link(&env, 2, 1));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 2);
+    assert_eq!(game.player2_hand.len(), 2);
+    assert_eq!(game.pending_draw, Draw::None);
+    assert_eq!(game.draws, 1);
+    assert_eq!(game.player1_link, chain_link(&env, 1, 1));
+}
+
+#[test]
+fn test_reveal_rejects_wrong_link() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env, 1, 0), chain_link(&env, 2, 0)),
+        &CLASSIC,
+    );
+
+    // Skipping ahead in the chain or using the opponent's chain is rejected
+    let result = client.try_reveal_link(&session_id, &player1, &chain_link(&env, 1, 2));
+    assert_twenty_one_error(&result, Error::HashMismatch);
+    let result = client.try_reveal_link(&session_id, &player1, &chain_link(&env, 2, 1));
+    assert_twenty_one_error(&result, Error::HashMismatch);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_link(&session_id, &non_player, &chain_link(&env, 1, 1));
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_hit_waits_for_opponent_link() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    client.hit(&session_id, &player1, &chain_link(&env, 1, 2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 2);
+    assert_eq!(game.pending_draw, Draw::Player1Hit);
+
+    // The draw must complete before anyone else acts
+    let result = client.try_hit(&session_id, &player2, &chain_link(&env, 2, 2));
+    assert_twenty_one_error(&result, Error::DrawPending);
+    let result = client.try_stick(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::DrawPending);
+
+    client.reveal_link(&session_id, &player2, &chain_link(&env, 2, 2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 3);
+    assert_eq!(game.player2_hand.len(), 2);
+    assert_eq!(game.pending_draw, Draw::None);
+    assert_eq!(game.draws, 2);
+}
+
+#[test]
+fn test_reveal_without_pending_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_reveal_link(&session_id, &player1, &chain_link(&env, 1, 2));
+    assert_twenty_one_error(&result, Error::NoPendingDraw);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_withholds_link() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 44u32;
+    start_game(&env, &client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game(&session_id).draw_deadline, 0);
+
+    // Player 1 hits; player 2 could stall to keep the card from being dealt
+    client.hit(&session_id, &player1, &chain_link(&env, 1, 2));
+    let deadline = client.get_game(&session_id).draw_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::CannotClaimTimeout);
+    let result = client.try_claim_timeout(&session_id, &Address::generate(&env));
+    assert_twenty_one_error(&result, Error::NotPlayer);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    let result = client.try_reveal_link(&session_id, &player2, &chain_link(&env, 2, 2));
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_requires_stalled_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 45u32;
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env, 1, 0), chain_link(&env, 2, 0)),
+        &CLASSIC,
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+
+    // Nobody revealed for the initial deal: there is no one to award the win to
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::CannotClaimTimeout);
+
+    // Both revealed, even late: the draw completes and nothing is pending
+    client.reveal_link(&session_id, &player1, &chain_link(&env, 1, 1));
+    client.reveal_link(&session_id, &player2, &chain_link(&env, 2, 1));
+    assert_eq!(client.get_game(&session_id).draw_deadline, 0);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::NoPendingDraw);
+}
+
+// ============================================================================
+// Hidden-Hand Tests
+// ============================================================================
+
+#[test]
+fn test_hidden_game_shows_only_face_up_card() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+
+    let game = client.get_game(&session_id);
+    assert!(game.hidden);
+    assert_eq!(game.player1_hand.len(), 1);
+    assert_eq!(game.player2_hand.len(), 1);
+    assert_eq!(game.player1_hidden.len(), 1);
+    assert_eq!(game.player2_hidden.len(), 1);
+    assert_eq!(
+        client.get_hand_value(&session_id, &player1),
+        calculate_hand_value_helper(&game.player1_hand)
+    );
+
+    // Hits stay face down
+    hit(&env, &client, &session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 1);
+    assert_eq!(game.player1_hidden.len(), 2);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_hidden_showdown() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 51u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+    hit(&env, &client, &session_id, &player2);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::HandNotRevealed);
+
+    client.reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    let result = client.try_reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::HandNotRevealed);
+
+    client.reveal_hand(&session_id, &player2, &chain_link(&env, 12, 1));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 2);
+    assert_eq!(game.player2_hand.len(), 3);
+    assert!(game.player1_hidden.is_empty());
+    assert!(game.player2_hidden.is_empty());
+
+    let score = |hand: &Bytes| match calculate_hand_value_helper(hand) {
+        value if value > 21 => 0,
+        value => value,
+    };
+    let player1_value = score(&game.player1_hand);
+    let player2_value = score(&game.player2_hand);
+
+    let winner = client.reveal_winner(&session_id);
+    if player1_value == player2_value {
+        assert_eq!(winner, None);
+    } else {
+        let expected = if player1_value > player2_value { player1 } else { player2 };
+        assert_eq!(winner, Some(expected));
+    }
+}
+
+#[test]
+fn test_hidden_bust_loses_at_showdown() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 52u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+
+    // 23 cards of at least 1 point each: a certain bust, but nobody can tell yet
+    for _ in 0..21 {
+        hit(&env, &client, &session_id, &player1);
+    }
+    assert!(client.get_game(&session_id).winner.is_none());
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    client.reveal_hand(&session_id, &player2, &chain_link(&env, 12, 1));
+
+    assert!(client.get_hand_value(&session_id, &player1) > 21);
+    assert_eq!(client.reveal_winner(&session_id), Some(player2));
+}
+
+#[test]
+fn test_reveal_hand_validation() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 53u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+
+    // Showdown only
+    client.stick(&session_id, &player1);
+    let result = client.try_reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
+    client.stick(&session_id, &player2);
+
+    // Secret must match the committed hand chain
+    let result = client.try_reveal_hand(&session_id, &player1, &chain_link(&env, 12, 1));
+    assert_twenty_one_error(&result, Error::HashMismatch);
+    let result = client.try_reveal_hand(&session_id, &player1, &chain_link(&env, 11, 2));
+    assert_twenty_one_error(&result, Error::HashMismatch);
+
+    // Public games have nothing to reveal
+    let public_session = 54u32;
+    start_game(&env, &client, &public_session, &player1, &player2, &100_0000000, &100_0000000);
+    let result = client.try_reveal_hand(&public_session, &player1, &chain_link(&env, 11, 1));
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_withholds_hand() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 55u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    // The showdown clock starts once both players stick
+    let deadline = client.get_game(&session_id).showdown_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    // Player 1 reveals; player 2 sees the total and stalls
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    client.reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    let deadline = client.get_game(&session_id).showdown_deadline;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::CannotClaimTimeout);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    let result = client.try_reveal_hand(&session_id, &player2, &chain_link(&env, 12, 1));
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_neither_reveals_hand() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 56u32;
+    start_hidden_game(&env, &client, &session_id, &player1, &player2);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    let deadline = client.get_game(&session_id).showdown_deadline;
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+
+    // Neither hand can be judged: the session ends as a draw
+    assert_eq!(client.claim_timeout(&session_id, &player2), None);
+    let game = client.get_game(&session_id);
+    assert!(game.forfeited);
+    assert_eq!(game.winner, None);
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_hand(&session_id, &player1, &chain_link(&env, 11, 1));
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Rule Set Tests
+// ============================================================================
+
+#[test]
+fn test_calculate_hand_value_classic() {
+    let env = Env::default();
+    let cases: [(&[u8], u32); 7] = [
+        (&[], 0),
+        (&[1], 1),
+        (&[1, 13], 11),
+        (&[1, 1], 2),
+        (&[10, 11, 12], 30),
+        (&[2, 3, 4, 5, 6], 20),
+        (&[13, 9, 1, 1], 21),
+    ];
+    for (cards, expected) in cases {
+        let hand = Bytes::from_slice(&env, cards);
+        assert_eq!(calculate_hand_value(&hand, false), Ok(expected), "{:?}", cards);
+    }
+}
+
+#[test]
+fn test_calculate_hand_value_soft_aces() {
+    let env = Env::default();
+    let cases: [(&[u8], u32); 11] = [
+        (&[], 0),
+        (&[1], 11),
+        (&[1, 13], 21),      // natural
+        (&[1, 5], 16),       // soft 16
+        (&[1, 5, 10], 16),   // becomes hard 16
+        (&[1, 1], 12),       // only one Ace can count 11
+        (&[1, 1, 9], 21),
+        (&[1, 1, 10], 12),
+        (&[1, 1, 1, 1], 14),
+        (&[12, 11, 1], 21),  // drawn 21
+        (&[10, 10, 5], 25),  // bust
+    ];
+    for (cards, expected) in cases {
+        let hand = Bytes::from_slice(&env, cards);
+        assert_eq!(calculate_hand_value(&hand, true), Ok(expected), "{:?}", cards);
+    }
+}
+
+#[test]
+fn test_natural_beats_drawn_21() {
+    let env = Env::default();
+    let soft = Rules {
+        soft_aces: true,
+        ..CLASSIC
+    };
+
+    let natural = hand_rank(&Bytes::from_slice(&env, &[1, 13]), &soft).unwrap();
+    let drawn = hand_rank(&Bytes::from_slice(&env, &[7, 7, 7]), &soft).unwrap();
+    let twenty = hand_rank(&Bytes::from_slice(&env, &[10, 10]), &soft).unwrap();
+    let bust = hand_rank(&Bytes::from_slice(&env, &[10, 10, 5]), &soft).unwrap();
+
+    assert_eq!(natural, (21, true));
+    assert_eq!(drawn, (21, false));
+    assert!(natural > drawn);
+    assert!(drawn > twenty);
+    assert_eq!(bust, (0, false));
+
+    // No soft Aces, no naturals
+    let classic = hand_rank(&Bytes::from_slice(&env, &[1, 13]), &CLASSIC).unwrap();
+    assert_eq!(classic, (11, false));
+}
+
+#[test]
+fn test_against_dealer() {
+    assert_eq!(against_dealer((20, false), (19, false)), 2);
+    assert_eq!(against_dealer((19, false), (19, false)), 1);
+    assert_eq!(against_dealer((18, false), (19, false)), 0);
+    assert_eq!(against_dealer((21, true), (21, false)), 2);
+    assert_eq!(against_dealer((21, false), (21, true)), 0);
+    // Dealer bust pays any standing hand; a busted player always loses
+    assert_eq!(against_dealer((12, false), (0, false)), 2);
+    assert_eq!(against_dealer((0, false), (0, false)), 0);
+}
+
+#[test]
+fn test_soft_aces_game_hand_value() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let soft = Rules {
+        soft_aces: true,
+        ..CLASSIC
+    };
+
+    let session_id = 60u32;
+    start_game_with_rules(&env, &client, &session_id, &player1, &player2, &soft);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.rules, soft);
+    assert_eq!(
+        client.get_hand_value(&session_id, &player1),
+        calculate_hand_value(&game.player1_hand, true).unwrap()
+    );
+    assert!(game.dealer_hand.is_empty());
+}
+
+#[test]
+fn test_house_dealer_draws_to_17() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let house = Rules {
+        soft_aces: true,
+        house: true,
+        ..CLASSIC
+    };
+
+    let session_id = 61u32;
+    start_game_with_rules(&env, &client, &session_id, &player1, &player2, &house);
+    assert_eq!(client.get_game(&session_id).dealer_hand.len(), 1);
+
+    client.stick(&session_id, &player1);
+    assert_eq!(client.get_game(&session_id).pending_draw, Draw::None);
+    client.stick(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).pending_draw, Draw::Dealer);
+
+    // The dealer plays only after both players reveal fresh links
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::DrawPending);
+    client.reveal_link(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
+    client.reveal_link(&session_id, &player2, &next_link(&env, &client, &session_id, &player2));
+
+    let game = client.get_game(&session_id);
+    assert!(game.dealer_hand.len() >= 2);
+    assert!(calculate_hand_value(&game.dealer_hand, true).unwrap() >= 17);
+    let mut before_last = game.dealer_hand.clone();
+    before_last.pop_back();
+    assert!(before_last.len() == 1 || calculate_hand_value(&before_last, true).unwrap() < 17);
+
+    // Players are judged against the dealer first, then against each other
+    let dealer = hand_rank(&game.dealer_hand, &house).unwrap();
+    let rank1 = hand_rank(&game.player1_hand, &house).unwrap();
+    let rank2 = hand_rank(&game.player2_hand, &house).unwrap();
+    let score1 = (against_dealer(rank1, dealer), rank1);
+    let score2 = (against_dealer(rank2, dealer), rank2);
+
+    let winner = client.reveal_winner(&session_id);
+    if score1 == score2 {
+        assert_eq!(winner, None);
+    } else {
+        let expected = if score1 > score2 { player1 } else { player2 };
+        assert_eq!(winner, Some(expected));
+    }
+}
+
+// ============================================================================
+// Shoe Tests
+// ============================================================================
+
+#[test]
+fn test_shoe_deals_without_replacement() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let single_deck = Rules {
+        decks: 1,
+        ..CLASSIC
+    };
+
+    let session_id = 70u32;
+    start_game_with_rules(&env, &client, &session_id, &player1, &player2, &single_deck);
+    assert_eq!(client.get_game(&session_id).shoe.len(), 48);
+
+    for _ in 0..5 {
+        if client.get_game(&session_id).winner.is_some() {
+            break;
+        }
+        hit(&env, &client, &session_id, &player1);
+    }
+
+    // Every card of the deck is either in a hand or still in the shoe, exactly once
+    let game = client.get_game(&session_id);
+    let counts = card_counts(&[&game.player1_hand, &game.player2_hand, &game.shoe]);
+    for suit in 0..4u8 {
+        for rank in 1..=13u8 {
+            assert_eq!(counts[((suit << 4) | rank) as usize], 1);
+        }
+    }
+    assert_eq!(counts.iter().sum::<u32>(), 52);
+}
+
+#[test]
+fn test_multi_deck_shoe() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let two_decks = Rules {
+        decks: 2,
+        ..CLASSIC
+    };
+
+    let session_id = 71u32;
+    start_game_with_rules(&env, &client, &session_id, &player1, &player2, &two_decks);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.shoe.len(), 100);
+    let counts = card_counts(&[&game.player1_hand, &game.player2_hand, &game.shoe]);
+    assert!(counts.iter().all(|&count| count == 0 || count == 2));
+
+    let too_many = Rules {
+        decks: 9,
+        ..CLASSIC
+    };
+    let result = client.try_start_game(
+        &72,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(chain_link(&env, 1, 0), chain_link(&env, 2, 0)),
+        &too_many,
+    );
+    assert_twenty_one_error(&result, Error::InvalidRules);
+}
+
+#[test]
+fn test_shoe_carries_over_tie() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let single_deck = Rules {
+        decks: 1,
+        ..CLASSIC
+    };
+
+    let session_id = start_tied_game(&env, &client, 100, &player1, &player2, &single_deck);
+    assert_eq!(client.reveal_winner(&session_id), None);
+    assert_eq!(client.get_game(&session_id).shoe.len(), 48);
+
+    // The first round's cards are discarded, not returned
+    client.reveal_link(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
+    client.reveal_link(&session_id, &player2, &next_link(&env, &client, &session_id, &player2));
+    assert_eq!(client.get_game(&session_id).shoe.len(), 44);
+}
+
+#[test]
+fn test_shoe_reshuffles_when_low() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let reshuffle_every_round = Rules {
+        decks: 1,
+        reshuffle_below: 52,
+        ..CLASSIC
+    };
+
+    let session_id = start_tied_game(&env, &client, 100, &player1, &player2, &reshuffle_every_round);
+    assert_eq!(client.reveal_winner(&session_id), None);
+    assert_eq!(client.get_game(&session_id).shoe.len(), 52);
+
+    client.reveal_link(&session_id, &player1, &next_link(&env, &client, &session_id, &player1));
+    client.reveal_link(&session_id, &player2, &next_link(&env, &client, &session_id, &player2));
+    assert_eq!(client.get_game(&session_id).shoe.len(), 48);
+}
+
+#[test]
+fn test_hidden_cards_stay_out_of_shoe() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let single_deck = Rules {
+        decks: 1,
+        ..CLASSIC
+    };
+
+    let session_id = 73u32;
+    client.start_hidden_game(
+        &session_id,
+        &player1,
+        &player2,
+        &(100_0000000, 100_0000000),
+        &(
+            Anchors {
+                draw: chain_link(&env, 1, 0),
+                hand: chain_link(&env, 11, 0),
+            },
+            Anchors {
+                draw: chain_link(&env, 2, 0),
+                hand: chain_link(&env, 12, 0),
+            },
+        ),
+        &single_deck,
+    );
+    client.reveal_link(&session_id, &player1, &chain_link(&env, 1, 1));
+    client.reveal_link(&session_id, &player2, &chain_link(&env, 2, 1));
+
+    // Only the two face-up cards left the shoe
+    assert_eq!(client.get_game(&session_id).shoe.len(), 50);
+}
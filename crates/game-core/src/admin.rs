@@ -0,0 +1,83 @@
+//! Admin entry points shared by every game.
+
+use soroban_sdk::{contracttrait, Address, Env};
+
+use crate::CoreKey;
+
+/// Store the admin and Game Hub addresses.  Call from `__constructor`.
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    env.storage().instance().set(&CoreKey::Admin, admin);
+    env.storage()
+        .instance()
+        .set(&CoreKey::GameHubAddress, game_hub);
+}
+
+/// The configured admin address.
+pub fn address(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CoreKey::Admin)
+        .expect("Admin not set")
+}
+
+/// Require the admin's authorization and return the admin address.
+pub fn require_admin(env: &Env) -> Address {
+    let admin = address(env);
+    admin.require_auth();
+    admin
+}
+
+/// Admin contract functions.
+///
+/// Implement with an empty `#[contractimpl(contracttrait)]` block to export
+/// all of them; override a function to change its behavior.
+///
+/// Signatures use full paths because the macro copies them into the
+/// implementing contract's module.
+#[contracttrait]
+pub trait Admin {
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    fn get_admin(env: soroban_sdk::Env) -> soroban_sdk::Address {
+        address(&env)
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    fn set_admin(env: soroban_sdk::Env, new_admin: soroban_sdk::Address) {
+        require_admin(&env);
+        env.storage().instance().set(&CoreKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    fn get_hub(env: soroban_sdk::Env) -> Result<soroban_sdk::Address, game_core::CoreError> {
+        crate::hub::address(&env)
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    fn set_hub(env: soroban_sdk::Env, new_hub: soroban_sdk::Address) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&CoreKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    fn upgrade(env: soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
+        require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
@@ -0,0 +1,79 @@
+//! Game Hub client and session helpers.
+//!
+//! Every call is made as the current contract, which is the `game_id` the
+//! hub authorizes sessions against.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+use crate::{CoreError, CoreKey};
+
+// Import GameHub contract interface
+// This allows us to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn reserve_session_id(env: Env, game_id: Address) -> u32;
+
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn end_game_draw(env: Env, session_id: u32);
+}
+
+/// The configured Game Hub address.
+pub fn address(env: &Env) -> Result<Address, CoreError> {
+    env.storage()
+        .instance()
+        .get(&CoreKey::GameHubAddress)
+        .ok_or(CoreError::HubNotSet)
+}
+
+/// A client for the configured Game Hub.
+pub fn client(env: &Env) -> Result<GameHubClient<'_>, CoreError> {
+    Ok(GameHubClient::new(env, &address(env)?))
+}
+
+/// Reserve the next free session ID in the Game Hub for this game.
+pub fn reserve_session_id(env: &Env) -> Result<u32, CoreError> {
+    Ok(client(env)?.reserve_session_id(&env.current_contract_address()))
+}
+
+/// Open a session in the Game Hub and lock both players' points.
+pub fn start_game(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), CoreError> {
+    client(env)?.start_game(
+        &env.current_contract_address(),
+        &session_id,
+        player1,
+        player2,
+        &player1_points,
+        &player2_points,
+    );
+    Ok(())
+}
+
+/// Settle a session in the Game Hub; the winner receives both stakes.
+pub fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<(), CoreError> {
+    client(env)?.end_game(&session_id, &player1_won);
+    Ok(())
+}
+
+/// Settle a session in the Game Hub with no winner; both stakes are refunded.
+pub fn end_game_draw(env: &Env, session_id: u32) -> Result<(), CoreError> {
+    client(env)?.end_game_draw(&session_id);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![no_std]
+
+//! # Game Core
+//!
+//! Building blocks shared by the game contracts in this workspace, so a new
+//! game only has to write its own rules.
+//!
+//! - [`hub`] – the Game Hub client and helpers that open and settle a
+//!   session on behalf of the calling game.
+//! - [`admin`] – the [`Admin`](admin::Admin) contract trait, exporting
+//!   `get_admin` / `set_admin` / `get_hub` / `set_hub` / `upgrade` to any
+//!   contract that implements it with `#[contractimpl(contracttrait)]`.
+//! - [`session`] – temporary game storage with a 30-day TTL, the checks
+//!   every game runs when a session starts, and reveal deadlines.
+//! - [`lobby`] – the [`Lobby`](lobby::Lobby) contract trait, exporting
+//!   `get_lobby` / `set_lobby` to games that can be started from the
+//!   matchmaking lobby.
+//!
+//! Failures are reported as [`CoreError`]; each game converts it into its
+//! own `Error` with a `From` impl so `?` works and codes stay per contract.
+//!
+//! All of them read and write the keys in [`CoreKey`].  Games that need more
+//! configuration keep their own `DataKey` enum next to it; the variants of
+//! both are stored by name, so they only must not reuse these names.
+
+use soroban_sdk::{contracterror, contracttype, Address};
+
+// Lets the `Admin` trait name this crate's types by a path that also
+// resolves inside implementing contracts.
+extern crate self as game_core;
+
+pub mod admin;
+pub mod hub;
+pub mod lobby;
+pub mod session;
+
+pub use admin::Admin;
+pub use hub::{GameHub, GameHubClient};
+pub use lobby::Lobby;
+
+/// Errors raised by the shared helpers.
+///
+/// Codes start at 100 so they never clash with a game's own codes when
+/// returned directly, as the [`Admin`] functions do.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CoreError {
+    SelfPlay      = 100,
+    HubNotSet     = 101,
+    SessionExists = 102,
+}
+
+/// Storage keys owned by this crate.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub enum CoreKey {
+    /// A game's state, in temporary storage.
+    Game(u32),
+    /// The Game Hub contract, in instance storage.
+    GameHubAddress,
+    /// The contract admin, in instance storage.
+    Admin,
+    /// The lobby allowed to start sessions, in instance storage.
+    Lobby,
+}
+
+/// A game's outcome from `get_result`, for contracts such as the lobby and
+/// the tournament that only need to know who won.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResult {
+    /// The game has ended
+    pub complete: bool,
+    /// The winner once complete; `None` after a draw
+    pub winner: Option<Address>,
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,39 @@
+//! Sessions started by a registered matchmaking lobby.
+//!
+//! The lobby collects both players' stakes in separate transactions and then
+//! starts the session itself, so a game's `start_lobby_game` checks the
+//! lobby's auth instead of the players'.  The lobby holds the stakes, so
+//! lobby sessions lock no Game Hub points.
+
+use soroban_sdk::{contracttrait, Address, Env};
+
+use crate::{admin, CoreKey};
+
+/// The registered lobby, if any.
+pub fn address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CoreKey::Lobby)
+}
+
+/// Lobby contract functions.
+///
+/// Implement with an empty `#[contractimpl(contracttrait)]` block to export
+/// both of them.
+#[contracttrait]
+pub trait Lobby {
+    /// Get the lobby allowed to call `start_lobby_game`
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The lobby address, or `None` if none is registered
+    fn get_lobby(env: soroban_sdk::Env) -> Option<soroban_sdk::Address> {
+        address(&env)
+    }
+
+    /// Register the lobby allowed to call `start_lobby_game`
+    ///
+    /// # Arguments
+    /// * `lobby` - The lobby contract address
+    fn set_lobby(env: soroban_sdk::Env, lobby: soroban_sdk::Address) {
+        admin::require_admin(&env);
+        env.storage().instance().set(&CoreKey::Lobby, &lobby);
+    }
+}
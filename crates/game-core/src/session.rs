@@ -0,0 +1,73 @@
+//! Game state storage and session start checks.
+
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
+
+use crate::{hub, CoreError, CoreKey};
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) ensures game data doesn't expire unexpectedly
+// Games are stored in temporary storage with a minimum 30-day retention
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Ledgers a player may keep a game waiting on a commit or reveal before a
+/// player who has made that move can claim the win (1 day ≈ 17 280 ledgers).
+pub const REVEAL_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Deadline for a commit or reveal step that opens at the current ledger.
+pub fn reveal_deadline(env: &Env) -> u32 {
+    env.ledger().sequence().saturating_add(REVEAL_TIMEOUT_LEDGERS)
+}
+
+/// Load a game from temporary storage.
+pub fn load<T: TryFromVal<Env, Val>>(env: &Env, session_id: u32) -> Option<T> {
+    env.storage().temporary().get(&CoreKey::Game(session_id))
+}
+
+/// Store a game in temporary storage and extend its TTL to
+/// [`GAME_TTL_LEDGERS`].
+pub fn save<T: IntoVal<Env, Val>>(env: &Env, session_id: u32, game: &T) {
+    let key = CoreKey::Game(session_id);
+    env.storage().temporary().set(&key, game);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Fail if a game is already stored under `session_id`.
+pub fn require_new(env: &Env, session_id: u32) -> Result<(), CoreError> {
+    if env.storage().temporary().has(&CoreKey::Game(session_id)) {
+        return Err(CoreError::SessionExists);
+    }
+    Ok(())
+}
+
+/// Resolve the session ID for a new game.
+///
+/// `0` reserves the next free ID in the Game Hub, so games sharing the hub
+/// never allocate the same one, reserving again while the ID already holds
+/// a game here (one started under a hub that never saw it); any other ID is
+/// used as given and must not already hold a game.
+pub fn claim(env: &Env, session_id: u32) -> Result<u32, CoreError> {
+    if session_id == 0 {
+        let mut session_id = hub::reserve_session_id(env)?;
+        while require_new(env, session_id).is_err() {
+            session_id = hub::reserve_session_id(env)?;
+        }
+        return Ok(session_id);
+    }
+    require_new(env, session_id)?;
+    Ok(session_id)
+}
+
+/// Prevent self-play: Player 1 and Player 2 must be different.
+pub fn require_distinct_players(player1: &Address, player2: &Address) -> Result<(), CoreError> {
+    if player1 == player2 {
+        return Err(CoreError::SelfPlay);
+    }
+    Ok(())
+}
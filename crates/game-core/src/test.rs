@@ -0,0 +1,264 @@
+#![cfg(test)]
+
+//! Tests for the shared building blocks, through a minimal game contract
+//! run against the real Game Hub.
+
+use crate::{admin, hub, session, Admin, CoreError, CoreKey};
+use game_hub::{GameHub, GameHubClient as HubClient, SessionStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+// ============================================================================
+// Test Game
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub moves: u32,
+}
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        admin::init(&env, &admin, &game_hub);
+    }
+
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+    ) -> Result<u32, CoreError> {
+        session::require_distinct_players(&player1, &player2)?;
+        let session_id = session::claim(&env, session_id)?;
+        hub::start_game(&env, session_id, &player1, &player2, 10, 10)?;
+        session::save(
+            &env,
+            session_id,
+            &Game {
+                player1,
+                player2,
+                moves: 0,
+            },
+        );
+        Ok(session_id)
+    }
+
+    pub fn play(env: Env, session_id: u32) {
+        let mut game: Game = session::load(&env, session_id).expect("Game not found");
+        game.moves += 1;
+        session::save(&env, session_id, &game);
+    }
+
+    pub fn finish(env: Env, session_id: u32, player1_won: Option<bool>) -> Result<(), CoreError> {
+        match player1_won {
+            Some(player1_won) => hub::end_game(&env, session_id, player1_won),
+            None => hub::end_game_draw(&env, session_id),
+        }
+    }
+
+    pub fn get_game(env: Env, session_id: u32) -> Option<Game> {
+        session::load(&env, session_id)
+    }
+}
+
+#[contractimpl(contracttrait)]
+impl Admin for TestGame {}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, TestGameClient<'static>, HubClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 16,
+        min_persistent_entry_ttl: 4096,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(GameHub, (&admin,));
+    let hub = HubClient::new(&env, &hub_addr);
+    let game_addr = env.register(TestGame, (&admin, &hub_addr));
+    let game = TestGameClient::new(&env, &game_addr);
+    hub.add_game(&game_addr);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    hub.add_points(&player1, &100);
+    hub.add_points(&player2, &100);
+
+    (env, game, hub, player1, player2)
+}
+
+// ============================================================================
+// Session Tests
+// ============================================================================
+
+#[test]
+fn test_session_save_and_load() {
+    let (env, game, _hub, player1, player2) = setup_test();
+
+    assert_eq!(game.get_game(&1), None);
+    game.start_game(&1, &player1, &player2);
+    game.play(&1);
+
+    let stored = game.get_game(&1).unwrap();
+    assert_eq!(stored.player1, player1);
+    assert_eq!(stored.moves, 1);
+
+    // Every save keeps the game alive for the full TTL
+    env.ledger().with_mut(|li| li.sequence_number += 100_000);
+    game.play(&1);
+    env.ledger()
+        .with_mut(|li| li.sequence_number += session::GAME_TTL_LEDGERS - 1);
+    assert_eq!(game.get_game(&1).unwrap().moves, 2);
+}
+
+#[test]
+fn test_session_ids_allocated_by_hub() {
+    let (_env, game, hub, player1, player2) = setup_test();
+
+    assert_eq!(game.start_game(&0, &player1, &player2), 1);
+    assert_eq!(game.start_game(&3, &player1, &player2), 3);
+    assert_eq!(game.start_game(&0, &player1, &player2), 2);
+    assert_eq!(game.start_game(&0, &player1, &player2), 4);
+    assert_eq!(hub.get_session(&4).game_id, game.address);
+    assert_eq!(game.get_game(&4).unwrap().player1, player1);
+}
+
+#[test]
+fn test_allocated_session_skips_local_games() {
+    let (env, game, hub, player1, player2) = setup_test();
+
+    // A game started under a previous hub the current one has never seen
+    game.start_game(&1, &player1, &player2);
+    game.play(&1);
+    let new_hub = env.register(GameHub, (&game.get_admin(),));
+    let new_hub = HubClient::new(&env, &new_hub);
+    new_hub.add_game(&game.address);
+    new_hub.add_points(&player1, &100);
+    new_hub.add_points(&player2, &100);
+    game.set_hub(&new_hub.address);
+    assert_eq!(new_hub.next_session_id(), 1);
+
+    assert_eq!(game.start_game(&0, &player2, &player1), 2);
+    assert_eq!(game.get_game(&1).unwrap().moves, 1);
+    assert_eq!(game.get_game(&2).unwrap().player1, player2);
+    assert_eq!(hub.get_session(&1).game_id, game.address);
+}
+
+#[test]
+fn test_games_sharing_a_hub_get_distinct_sessions() {
+    let (env, game, hub, player1, player2) = setup_test();
+    let other = env.register(TestGame, (&game.get_admin(), &hub.address));
+    let other = TestGameClient::new(&env, &other);
+    hub.add_game(&other.address);
+
+    assert_eq!(game.start_game(&0, &player1, &player2), 1);
+    assert_eq!(other.start_game(&0, &player1, &player2), 2);
+    // An ID one game chose itself is skipped for both
+    other.start_game(&3, &player2, &player1);
+    assert_eq!(game.start_game(&0, &player1, &player2), 4);
+    assert_eq!(other.start_game(&0, &player1, &player2), 5);
+
+    assert_eq!(hub.get_session(&2).game_id, other.address);
+    assert_eq!(hub.get_session(&4).game_id, game.address);
+    assert_eq!(game.get_game(&2), None);
+    assert_eq!(other.get_game(&4), None);
+}
+
+#[test]
+fn test_session_exists_rejected() {
+    let (_env, game, _hub, player1, player2) = setup_test();
+
+    game.start_game(&5, &player1, &player2);
+    game.play(&5);
+    let result = game.try_start_game(&5, &player2, &player1);
+    assert_eq!(result, Err(Ok(CoreError::SessionExists)));
+    assert_eq!(game.get_game(&5).unwrap().moves, 1);
+}
+
+#[test]
+fn test_self_play_rejected() {
+    let (_env, game, _hub, player1, _player2) = setup_test();
+    let result = game.try_start_game(&1, &player1, &player1);
+    assert_eq!(result, Err(Ok(CoreError::SelfPlay)));
+}
+
+// ============================================================================
+// Game Hub Tests
+// ============================================================================
+
+#[test]
+fn test_hub_session_lifecycle() {
+    let (_env, game, hub, player1, player2) = setup_test();
+
+    game.start_game(&1, &player1, &player2);
+    let opened = hub.get_session(&1);
+    assert_eq!(opened.game_id, game.address);
+    assert_eq!(opened.status, SessionStatus::Open);
+    assert_eq!(hub.get_points(&player1), 90);
+
+    game.finish(&1, &Some(false));
+    assert_eq!(hub.get_session(&1).status, SessionStatus::Player2Won);
+    assert_eq!(hub.get_points(&player2), 110);
+
+    game.start_game(&2, &player1, &player2);
+    game.finish(&2, &None);
+    assert_eq!(hub.get_session(&2).status, SessionStatus::Draw);
+    assert_eq!(hub.get_points(&player1), 90);
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_admin_functions() {
+    let (env, game, hub, _player1, _player2) = setup_test();
+    let admin = game.get_admin();
+    assert_eq!(game.get_hub(), hub.address);
+
+    let new_hub = Address::generate(&env);
+    game.set_hub(&new_hub);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(game.get_hub(), new_hub);
+
+    let new_admin = Address::generate(&env);
+    game.set_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(game.get_admin(), new_admin);
+
+    game.set_hub(&hub.address);
+    assert_eq!(env.auths()[0].0, new_admin);
+}
+
+#[test]
+fn test_hub_not_set() {
+    let (env, game, _hub, player1, player2) = setup_test();
+    env.as_contract(&game.address, || {
+        env.storage().instance().remove(&CoreKey::GameHubAddress);
+    });
+
+    assert_eq!(game.try_get_hub(), Err(Ok(CoreError::HubNotSet)));
+    assert_eq!(
+        game.try_start_game(&1, &player1, &player2),
+        Err(Ok(CoreError::HubNotSet))
+    );
+    assert_eq!(game.try_finish(&1, &None), Err(Ok(CoreError::HubNotSet)));
+}
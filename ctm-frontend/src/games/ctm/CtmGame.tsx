@@ -16,6 +16,7 @@ import {
   Sparkles,
   RotateCcw,
   Send,
+  Clock,
 } from 'lucide-react';
 import {
   CtmService,
@@ -33,7 +34,7 @@ import { CTM_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
 import { Buffer } from 'buffer';
-import type { Game } from './bindings';
+import { Phase, type Game } from './bindings';
 
 // ============================================================================
 // Constants
@@ -94,11 +95,11 @@ function deriveUIPhase(game: Game | null, userAddress: string): UIPhase {
   if (!game) return 'create';
   const isP1 = game.player1 === userAddress;
   switch (game.phase) {
-    case 1: return (isP1 ? game.p1_commit : game.p2_commit) != null ? 'waiting_commits' : 'commit_hands';
-    case 2: return (isP1 ? game.p1_left : game.p2_left) != null ? 'waiting_reveals' : 'reveal_hands';
-    case 3: return (isP1 ? game.p1_choice_commit : game.p2_choice_commit) != null ? 'waiting_choices' : 'commit_choice';
-    case 4: return (isP1 ? game.p1_kept : game.p2_kept) != null ? 'waiting_final' : 'reveal_choice';
-    case 5: return 'complete';
+    case Phase.CommitHands: return (isP1 ? game.p1_commit : game.p2_commit) != null ? 'waiting_commits' : 'commit_hands';
+    case Phase.RevealHands: return (isP1 ? game.p1_left : game.p2_left) != null ? 'waiting_reveals' : 'reveal_hands';
+    case Phase.CommitChoice: return (isP1 ? game.p1_choice_commit : game.p2_choice_commit) != null ? 'waiting_choices' : 'commit_choice';
+    case Phase.RevealChoice: return (isP1 ? game.p1_kept : game.p2_kept) != null ? 'waiting_final' : 'reveal_choice';
+    case Phase.Complete: return 'complete';
     default: return 'create';
   }
 }
@@ -125,6 +126,7 @@ export function CtmGame({
   const [sessionId, setSessionId] = useState(() => createRandomSessionId());
   const [player1Address, setPlayer1Address] = useState(userAddress);
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
+  const [bestOf, setBestOf] = useState(1);
   const [gameState, setGameState] = useState<Game | null>(null);
   const [loading, setLoading] = useState(false);
   const [quickstartLoading, setQuickstartLoading] = useState(false);
@@ -137,8 +139,11 @@ export function CtmGame({
   const [importSessionId, setImportSessionId] = useState('');
   const [importPlayer1, setImportPlayer1] = useState('');
   const [importPlayer1Points, setImportPlayer1Points] = useState('');
+  const [importBestOf, setImportBestOf] = useState('');
+  const [escrow, setEscrow] = useState<{ token: string; rakeBps: number } | null>(null);
   const [importPlayer2Points, setImportPlayer2Points] = useState(DEFAULT_POINTS);
   const [loadSessionId, setLoadSessionId] = useState('');
+  const [recentGames, setRecentGames] = useState<Array<readonly [number, Game]>>([]);
   const [authEntryCopied, setAuthEntryCopied] = useState(false);
   const [shareUrlCopied, setShareUrlCopied] = useState(false);
   const [xdrParsing, setXdrParsing] = useState(false);
@@ -165,6 +170,11 @@ export function CtmGame({
   // --- helpers ---------------------------------------------------------------
 
   useEffect(() => { setPlayer1Address(userAddress); }, [userAddress]);
+  useEffect(() => { ctmService.getEscrow().then(setEscrow); }, []);
+  useEffect(() => {
+    if (createMode !== 'load' || !userAddress) return;
+    ctmService.getPlayerGames(userAddress).then((page) => setRecentGames(page?.games ?? []));
+  }, [createMode, userAddress]);
 
   // Reset selections when switching players
   useEffect(() => {
@@ -205,7 +215,7 @@ export function CtmGame({
 
   // Refresh standings when game completes
   useEffect(() => {
-    if (uiPhase === 'complete' && gameState?.winner) onStandingsRefresh();
+    if (uiPhase === 'complete') onStandingsRefresh();
   }, [uiPhase, gameState?.winner]);
 
   // --- deep-linking ----------------------------------------------------------
@@ -256,9 +266,9 @@ export function CtmGame({
         const p = ctmService.parseAuthEntry(importAuthEntryXDR.trim());
         if (p.player1 === userAddress) throw new Error('You cannot play against yourself.');
         setImportSessionId(p.sessionId.toString()); setImportPlayer1(p.player1);
-        setImportPlayer1Points((Number(p.player1Points) / 1e7).toString()); setXdrParseSuccess(true);
+        setImportPlayer1Points((Number(p.player1Points) / 1e7).toString()); setImportBestOf(p.bestOf.toString()); setXdrParseSuccess(true);
       } catch (e) {
-        setXdrParseError(e instanceof Error ? e.message : 'Invalid auth entry'); setImportSessionId(''); setImportPlayer1(''); setImportPlayer1Points('');
+        setXdrParseError(e instanceof Error ? e.message : 'Invalid auth entry'); setImportSessionId(''); setImportPlayer1(''); setImportPlayer1Points(''); setImportBestOf('');
       } finally { setXdrParsing(false); }
     }, 500);
     return () => clearTimeout(t);
@@ -267,7 +277,7 @@ export function CtmGame({
   // --- actions ---------------------------------------------------------------
 
   const resetToCreate = () => {
-    if (gameState?.winner) onGameComplete();
+    if (gameState?.phase === Phase.Complete) onGameComplete();
     actionLock.current = false;
     setGamePhase('create'); setSessionId(createRandomSessionId()); setGameState(null);
     setLoading(false); setQuickstartLoading(false); setError(null); setSuccess(null);
@@ -276,7 +286,7 @@ export function CtmGame({
     setImportPlayer1Points(''); setImportPlayer2Points(DEFAULT_POINTS);
     setLoadSessionId(''); setAuthEntryCopied(false); setShareUrlCopied(false);
     setXdrParsing(false); setXdrParseError(null); setXdrParseSuccess(false);
-    setPlayer1Address(userAddress); setPlayer1Points(DEFAULT_POINTS);
+    setPlayer1Address(userAddress); setPlayer1Points(DEFAULT_POINTS); setBestOf(1);
     setSelectedLeft(null); setSelectedRight(null); setSelectedKeep(null);
   };
 
@@ -288,7 +298,7 @@ export function CtmGame({
         if (!pts || pts <= 0n) throw new Error('Enter valid points');
         const signer = getContractSigner();
         const placeholder = await getFundedSimulationSourceAddress([player1Address, userAddress]);
-        const xdr = await ctmService.prepareStartGame(sessionId, player1Address, placeholder, pts, pts, signer);
+        const xdr = await ctmService.prepareStartGame(sessionId, player1Address, placeholder, pts, pts, signer, undefined, bestOf);
         setExportedAuthEntryXDR(xdr);
         setSuccess('Auth entry signed! Send to Player 2.');
         // Poll for game creation
@@ -312,8 +322,8 @@ export function CtmGame({
         if (params.player1 === userAddress) throw new Error('Cannot play against yourself');
         const signer = getContractSigner();
         const fullXdr = await ctmService.importAndSignAuthEntry(importAuthEntryXDR.trim(), userAddress, p2pts, signer);
-        await ctmService.finalizeStartGame(fullXdr, userAddress, signer);
-        setSessionId(params.sessionId); setGamePhase('playing');
+        const startedSessionId = await ctmService.finalizeStartGame(fullXdr, userAddress, signer);
+        setSessionId(startedSessionId); setGamePhase('playing');
         await loadGameState(); onStandingsRefresh();
         setSuccess('Game created!'); setTimeout(() => setSuccess(null), 2000);
       } catch (e) { setError(e instanceof Error ? e.message : 'Import failed'); } finally { setLoading(false); }
@@ -448,6 +458,62 @@ export function CtmGame({
     });
   };
 
+  const handleClaimTimeout = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true); setError(null);
+        const signer = getContractSigner();
+        await ctmService.claimTimeout(sessionId, userAddress, signer);
+        setSuccess('Timeout claimed – you win by forfeit.');
+        await loadGameState();
+        clearGameData(sessionId, userAddress);
+      } catch (e) { setError(e instanceof Error ? e.message : 'Claim timeout failed'); } finally { setLoading(false); }
+    });
+  };
+
+  const handleRequestRematch = async (swap: boolean) => {
+    await runAction(async () => {
+      try {
+        setLoading(true); setError(null);
+        const signer = getContractSigner();
+        await ctmService.requestRematch(sessionId, userAddress, swap, signer);
+        setSuccess('Rematch offered! Waiting for opponent to accept…');
+        await loadGameState();
+      } catch (e) { setError(e instanceof Error ? e.message : 'Rematch request failed'); } finally { setLoading(false); }
+    });
+  };
+
+  const handleAcceptRematch = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true); setError(null);
+        const signer = getContractSigner();
+        const newSessionId = await ctmService.acceptRematch(sessionId, userAddress, signer);
+        setSelectedLeft(null); setSelectedRight(null); setSelectedKeep(null);
+        setSessionId(newSessionId); setGameState(null);
+        setSuccess('Rematch started!'); setTimeout(() => setSuccess(null), 2000);
+        onStandingsRefresh();
+      } catch (e) { setError(e instanceof Error ? e.message : 'Accept rematch failed'); } finally { setLoading(false); }
+    });
+  };
+
+  const handleCancelRematch = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true); setError(null);
+        const signer = getContractSigner();
+        await ctmService.cancelRematch(sessionId, userAddress, signer);
+        setSuccess('Rematch offer withdrawn.');
+        await loadGameState();
+      } catch (e) { setError(e instanceof Error ? e.message : 'Cancel rematch failed'); } finally { setLoading(false); }
+    });
+  };
+
+  const openRematch = (rematchSessionId: number) => {
+    setSelectedLeft(null); setSelectedRight(null); setSelectedKeep(null);
+    setSessionId(rematchSessionId); setGameState(null); setSuccess(null);
+  };
+
   // --- clipboard helpers -----------------------------------------------------
   const copyAuth = async () => { if (exportedAuthEntryXDR) { await navigator.clipboard.writeText(exportedAuthEntryXDR); setAuthEntryCopied(true); setTimeout(() => setAuthEntryCopied(false), 2000); } };
   const copyShareUrl = async () => {
@@ -526,14 +592,21 @@ export function CtmGame({
         <div className={`w-10 h-10 rounded-full flex items-center justify-center flex-shrink-0 ${isP1 ? 'bg-amber-100' : 'bg-violet-100'}`}>
           <Loader2 className={`w-5 h-5 animate-spin ${isP1 ? 'text-amber-500' : 'text-violet-500'}`} />
         </div>
-        <p className="text-sm font-medium text-stone-600 leading-relaxed">{msg}</p>
+        <div className="flex-1">
+          <p className="text-sm font-medium text-stone-600 leading-relaxed">{msg}</p>
+          {gameState && <p className="text-[10px] font-semibold text-stone-400 uppercase tracking-wider mt-1">Opponent deadline: ledger {gameState.deadline}</p>}
+        </div>
+        <button onClick={handleClaimTimeout} disabled={isBusy}
+          className="px-4 py-2 rounded-full text-[10px] font-bold uppercase tracking-widest text-stone-600 bg-white ring-1 ring-stone-200 hover:bg-stone-50 disabled:text-stone-300 transition-all duration-300 flex items-center gap-1.5 flex-shrink-0">
+          <Clock className="w-3.5 h-3.5" /> Claim Timeout
+        </button>
       </div>
     </div>
   );
 
   // Phase stepper
   const PHASE_NAMES = ['Commit', 'Reveal', 'Choose', 'Showdown', 'Done'];
-  const PhaseStep = ({ phase }: { phase: number }) => (
+  const PhaseStep = ({ phase }: { phase: Phase }) => (
     <div className="flex items-center justify-center gap-1 sm:gap-2">
       {[1, 2, 3, 4, 5].map((p) => (
         <div key={p} className="flex items-center gap-1 sm:gap-2">
@@ -551,17 +624,17 @@ export function CtmGame({
   // --- phase status for player cards -----------------------------------------
 
   const p1Status = (g: Game) => {
-    if (g.phase >= 5) return <StatusBadge done text={g.winner === g.player1 ? '🏆 Winner!' : 'Lost'} />;
-    if (g.phase >= 4) return <StatusBadge done={g.p1_kept != null} />;
-    if (g.phase >= 3) return <StatusBadge done={g.p1_choice_commit != null} />;
-    if (g.phase >= 2) return <StatusBadge done={g.p1_left != null} />;
+    if (g.phase >= Phase.Complete) return <StatusBadge done text={!g.winner ? 'Draw' : g.winner === g.player1 ? '🏆 Winner!' : 'Lost'} />;
+    if (g.phase >= Phase.RevealChoice) return <StatusBadge done={g.p1_kept != null} />;
+    if (g.phase >= Phase.CommitChoice) return <StatusBadge done={g.p1_choice_commit != null} />;
+    if (g.phase >= Phase.RevealHands) return <StatusBadge done={g.p1_left != null} />;
     return <StatusBadge done={g.p1_commit != null} />;
   };
   const p2Status = (g: Game) => {
-    if (g.phase >= 5) return <StatusBadge done text={g.winner === g.player2 ? '🏆 Winner!' : 'Lost'} />;
-    if (g.phase >= 4) return <StatusBadge done={g.p2_kept != null} />;
-    if (g.phase >= 3) return <StatusBadge done={g.p2_choice_commit != null} />;
-    if (g.phase >= 2) return <StatusBadge done={g.p2_left != null} />;
+    if (g.phase >= Phase.Complete) return <StatusBadge done text={!g.winner ? 'Draw' : g.winner === g.player2 ? '🏆 Winner!' : 'Lost'} />;
+    if (g.phase >= Phase.RevealChoice) return <StatusBadge done={g.p2_kept != null} />;
+    if (g.phase >= Phase.CommitChoice) return <StatusBadge done={g.p2_choice_commit != null} />;
+    if (g.phase >= Phase.RevealHands) return <StatusBadge done={g.p2_left != null} />;
     return <StatusBadge done={g.p2_commit != null} />;
   };
 
@@ -594,6 +667,16 @@ export function CtmGame({
             <Lock className="w-3 h-3" /> {sessionId}
           </span>
           {gameState && <PhaseStep phase={gameState.phase} />}
+          {gameState?.escrow_token && (
+            <span className="px-3 py-1 rounded-full bg-teal-50 ring-1 ring-teal-200 text-[10px] font-bold text-teal-600 uppercase tracking-wider">
+              Token stake{gameState.rake_bps > 0 ? ` · ${gameState.rake_bps / 100}% rake` : ''}
+            </span>
+          )}
+          {gameState && gameState.best_of > 1 && (
+            <span className="px-3 py-1 rounded-full bg-stone-100 text-[10px] font-bold text-stone-500 uppercase tracking-wider">
+              Round {gameState.round} · Bo{gameState.best_of} · {gameState.p1_wins}–{gameState.p2_wins}
+            </span>
+          )}
         </div>
       </div>
 
@@ -658,10 +741,30 @@ export function CtmGame({
                   className="w-full px-4 py-3 rounded-xl bg-white ring-1 ring-stone-200 focus:outline-none focus:ring-2 focus:ring-amber-300 text-sm font-mono text-stone-700 placeholder-stone-300 transition-all" />
                 <p className="text-[10px] font-medium text-stone-400 mt-1 ml-0.5">Available: {(Number(availablePoints) / 1e7).toFixed(2)} Points</p>
               </div>
+              <div className="space-y-1.5">
+                <label className="block text-[10px] font-bold uppercase tracking-widest text-stone-500 ml-0.5">Match Length</label>
+                <div className="flex items-center gap-1 p-1 bg-white ring-1 ring-stone-200 rounded-xl w-fit">
+                  {[1, 3, 5, 7].map((n) => (
+                    <button key={n} onClick={() => setBestOf(n)} disabled={!!exportedAuthEntryXDR}
+                      className={`py-2 px-4 rounded-lg text-xs font-bold uppercase tracking-widest transition-all duration-300
+                        ${bestOf === n ? 'bg-stone-800 text-white shadow-sm' : 'text-stone-400 hover:text-stone-600'}`}>
+                      {n === 1 ? 'Single' : `Bo${n}`}
+                    </button>
+                  ))}
+                </div>
+              </div>
               <div className="p-3 bg-sky-50 ring-1 ring-sky-200 rounded-xl flex items-start gap-2.5">
                 <AlertCircle className="w-4 h-4 text-sky-500 flex-shrink-0 mt-0.5" />
                 <p className="text-xs text-sky-700 leading-relaxed">Player 2 will specify their own address and points when importing.</p>
               </div>
+              {escrow && (
+                <div className="p-3 bg-teal-50 ring-1 ring-teal-200 rounded-xl flex items-start gap-2.5">
+                  <Lock className="w-4 h-4 text-teal-500 flex-shrink-0 mt-0.5" />
+                  <p className="text-xs text-teal-700 leading-relaxed">
+                    Stakes are escrowed in token {shortAddr(escrow.token)}; the winner takes the pot{escrow.rakeBps > 0 ? ` minus a ${escrow.rakeBps / 100}% rake` : ''}.
+                  </p>
+                </div>
+              )}
               {!exportedAuthEntryXDR ? (
                 <button onClick={handlePrepare} disabled={isBusy}
                   className="w-full py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-white bg-stone-800 hover:bg-stone-700 disabled:bg-stone-200 disabled:text-stone-400 transition-all duration-300 shadow-sm hover:shadow-md mt-2 flex items-center justify-center gap-2">
@@ -716,6 +819,7 @@ export function CtmGame({
                   <div><label className="block text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-1 ml-0.5">Session ID</label><input readOnly value={importSessionId} className="w-full px-3 py-2.5 rounded-xl bg-stone-100 ring-1 ring-stone-200 text-xs font-mono text-stone-400 cursor-not-allowed" /></div>
                   <div><label className="block text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-1 ml-0.5">P1 Points</label><input readOnly value={importPlayer1Points} className="w-full px-3 py-2.5 rounded-xl bg-stone-100 ring-1 ring-stone-200 text-xs text-stone-400 cursor-not-allowed" /></div>
                 </div>
+                <div><label className="block text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-1 ml-0.5">Best Of</label><input readOnly value={importBestOf} className="w-full px-3 py-2.5 rounded-xl bg-stone-100 ring-1 ring-stone-200 text-xs text-stone-400 cursor-not-allowed" /></div>
                 <div><label className="block text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-1 ml-0.5">Player 1</label><input readOnly value={importPlayer1} className="w-full px-3 py-2.5 rounded-xl bg-stone-100 ring-1 ring-stone-200 text-xs font-mono text-stone-400 cursor-not-allowed" /></div>
                 <div className="grid grid-cols-2 gap-3">
                   <div><label className="block text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-1 ml-0.5">You (P2)</label><input readOnly value={userAddress} className="w-full px-3 py-2.5 rounded-xl bg-stone-100 ring-1 ring-stone-200 text-xs font-mono text-stone-400 cursor-not-allowed" /></div>
@@ -739,6 +843,20 @@ export function CtmGame({
                 </p>
                 <input type="text" value={loadSessionId} onChange={(e) => setLoadSessionId(e.target.value)} placeholder="Session ID"
                   className="w-full px-4 py-3 rounded-xl bg-white ring-1 ring-stone-200 focus:outline-none focus:ring-2 focus:ring-teal-300 text-sm font-mono text-stone-700 placeholder-stone-300 transition-all" />
+                {recentGames.length > 0 && (
+                  <div className="space-y-1.5">
+                    <p className="text-[10px] font-bold uppercase tracking-widest text-stone-400 ml-0.5">Your recent games</p>
+                    <div className="flex flex-wrap gap-2">
+                      {recentGames.map(([sid, g]) => (
+                        <button key={sid} onClick={() => setLoadSessionId(sid.toString())}
+                          className={`px-3 py-1.5 rounded-full text-[11px] font-mono ring-1 transition-all duration-300
+                            ${loadSessionId === sid.toString() ? 'bg-teal-600 text-white ring-teal-600' : 'bg-white text-stone-500 ring-stone-200 hover:ring-teal-300'}`}>
+                          #{sid} · {g.phase === Phase.Complete ? (g.winner === userAddress ? 'won' : g.winner ? 'lost' : 'draw') : 'live'}
+                        </button>
+                      ))}
+                    </div>
+                  </div>
+                )}
               </div>
               <button onClick={handleLoad} disabled={isBusy || !loadSessionId.trim()}
                 className="w-full py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-white bg-teal-600 hover:bg-teal-700 disabled:bg-stone-200 disabled:text-stone-400 transition-all duration-300 shadow-sm hover:shadow-md flex items-center justify-center gap-2">
@@ -953,7 +1071,7 @@ export function CtmGame({
                         <span className="mx-2 text-stone-300">→</span>
                         <span className="text-sm font-bold text-teal-600">
                           {rpsResult(gameState.p1_kept, gameState.p2_kept) === 'draw'
-                            ? 'Draw (P1 tiebreak)'
+                            ? (gameState.draw_policy.tag === 'Player1Wins' ? 'Draw (P1 tiebreak)' : 'Draw')
                             : rpsResult(gameState.p1_kept, gameState.p2_kept) === 'p1'
                             ? `${HAND_NAME[gameState.p1_kept]} beats ${HAND_NAME[gameState.p2_kept]}`
                             : `${HAND_NAME[gameState.p2_kept]} beats ${HAND_NAME[gameState.p1_kept]}`}
@@ -961,6 +1079,12 @@ export function CtmGame({
                       </div>
                     </div>
                   )}
+                  {!gameState.winner && (
+                    <div className="p-5 bg-stone-50 ring-1 ring-stone-200 rounded-xl max-w-sm mx-auto">
+                      <p className="text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-2">Result</p>
+                      <p className="text-stone-600 font-bold text-lg">🤝 Draw – nobody wins</p>
+                    </div>
+                  )}
                   {gameState.winner && (
                     <div className="p-5 bg-stone-50 ring-1 ring-stone-200 rounded-xl max-w-sm mx-auto">
                       <p className="text-[10px] font-bold uppercase tracking-widest text-stone-400 mb-2">Winner</p>
@@ -971,6 +1095,45 @@ export function CtmGame({
                   )}
                 </div>
               </div>
+              {/* Rematch */}
+              {gameState.rematch != null ? (
+                <button onClick={() => openRematch(gameState.rematch!)}
+                  className={`w-full py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-white transition-all duration-300 shadow-sm hover:shadow-md flex items-center justify-center gap-2
+                    ${isP1 ? 'bg-amber-500 hover:bg-amber-600' : 'bg-violet-500 hover:bg-violet-600'}`}>
+                  <Swords className="w-4 h-4" /> Go to Rematch #{gameState.rematch}
+                </button>
+              ) : gameState.rematch_offer === userAddress ? (
+                <div className="space-y-3">
+                  <div className="p-4 bg-stone-50 ring-1 ring-stone-200 rounded-xl flex items-center gap-3">
+                    <Loader2 className="w-4 h-4 animate-spin text-stone-400 flex-shrink-0" />
+                    <p className="text-sm font-medium text-stone-600">Rematch offered{gameState.rematch_swap ? ' (seats swapped)' : ''}. Waiting for opponent to accept…</p>
+                  </div>
+                  <button onClick={handleCancelRematch} disabled={isBusy}
+                    className="w-full py-3 rounded-xl font-bold text-xs uppercase tracking-widest text-stone-500 bg-white ring-1 ring-stone-200 hover:bg-stone-50 disabled:text-stone-300 transition-all duration-300">
+                    Withdraw Offer
+                  </button>
+                </div>
+              ) : gameState.rematch_offer ? (
+                <button onClick={handleAcceptRematch} disabled={isBusy}
+                  className={`w-full py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-white disabled:bg-stone-200 disabled:text-stone-400 transition-all duration-300 shadow-sm hover:shadow-md flex items-center justify-center gap-2
+                    ${isP1 ? 'bg-amber-500 hover:bg-amber-600' : 'bg-violet-500 hover:bg-violet-600'}`}>
+                  {loading ? <Loader2 className="w-4 h-4 animate-spin" /> : <Swords className="w-4 h-4" />}
+                  Accept Rematch{gameState.rematch_swap ? ' (seats swapped)' : ''}
+                </button>
+              ) : gameState.lobby ? (
+                <p className="text-sm font-medium text-stone-500 text-center">Lobby stakes are settled by the lobby. Post a new challenge for a rematch.</p>
+              ) : (
+                <div className="grid grid-cols-1 sm:grid-cols-2 gap-3">
+                  <button onClick={() => handleRequestRematch(false)} disabled={isBusy}
+                    className="py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-white bg-stone-800 hover:bg-stone-700 disabled:bg-stone-200 disabled:text-stone-400 transition-all duration-300 shadow-sm hover:shadow-md flex items-center justify-center gap-2">
+                    <Swords className="w-4 h-4" /> Offer Rematch
+                  </button>
+                  <button onClick={() => handleRequestRematch(true)} disabled={isBusy}
+                    className="py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-stone-700 bg-white ring-1 ring-stone-200 hover:bg-stone-50 disabled:text-stone-300 transition-all duration-300 flex items-center justify-center gap-2">
+                    <RotateCcw className="w-4 h-4" /> Rematch, Swap Seats
+                  </button>
+                </div>
+              )}
               <button onClick={resetToCreate}
                 className="w-full py-4 rounded-xl font-bold text-xs uppercase tracking-widest text-stone-600 bg-stone-100 hover:bg-stone-200 ring-1 ring-stone-200 transition-all duration-300 hover:shadow-sm flex items-center justify-center gap-2">
                 <RotateCcw className="w-4 h-4" /> Start New Game
@@ -47,40 +47,181 @@ export const Errors = {
   6: {message:"HandsMustDiffer"},
   7: {message:"HashMismatch"},
   8: {message:"InvalidChoice"},
-  9: {message:"GameAlreadyEnded"}
+  9: {message:"GameAlreadyEnded"},
+  10: {message:"DeadlineNotReached"},
+  11: {message:"CannotClaimTimeout"},
+  12: {message:"InvalidRounds"},
+  13: {message:"ProofNotConfigured"},
+  14: {message:"InvalidProof"},
+  15: {message:"InvalidRake"},
+  16: {message:"InvalidStake"},
+  17: {message:"SelfPlay"},
+  18: {message:"HubNotSet"},
+  19: {message:"LobbyNotSet"},
+  20: {message:"SessionExists"},
+  21: {message:"RematchExists"},
+  22: {message:"NoRematchOffer"},
+  23: {message:"CannotAcceptOwn"},
+  24: {message:"TournamentNotSet"},
+  25: {message:"InvalidPageSize"},
+  26: {message:"LobbyRematch"},
+  100: {message:"SelfPlay"},
+  101: {message:"HubNotSet"},
+  102: {message:"SessionExists"}
 }
 
 
+/**
+ * Game phases.  Encoded as `u32` (1..5) on the wire.
+ */
+export enum Phase {
+  /**
+   * Waiting for both commit hashes
+   */
+  CommitHands = 1,
+  /**
+   * Waiting for both to reveal hands
+   */
+  RevealHands = 2,
+  /**
+   * Waiting for both to commit which hand to keep
+   */
+  CommitChoice = 3,
+  /**
+   * Waiting for both to reveal their choice
+   */
+  RevealChoice = 4,
+  /**
+   * Winner determined (none after a true draw)
+   */
+  Complete = 5,
+}
+
 /**
  * Hand constants: 0 = Rock 🪨,  1 = Paper ✋,  2 = Scissors ✌️
- * 
- * Game phases:
- * 1 = CommitHands      – waiting for both commit hashes
- * 2 = RevealHands      – waiting for both to reveal hands
- * 3 = CommitChoice     – waiting for both to commit which hand to keep
- * 4 = RevealChoice     – waiting for both to reveal their choice
- * 5 = Complete         – winner determined
  */
 export interface Game {
+  best_of: u32;
+  deadline: u32;
+  draw_policy: DrawPolicy;
+  escrow_token: Option<string>;
+  history: Array<RoundResult>;
+  lobby: boolean;
   p1_choice_commit: Option<Buffer>;
   p1_commit: Option<Buffer>;
   p1_kept: Option<u32>;
   p1_left: Option<u32>;
   p1_right: Option<u32>;
+  p1_wins: u32;
   p2_choice_commit: Option<Buffer>;
   p2_commit: Option<Buffer>;
   p2_kept: Option<u32>;
   p2_left: Option<u32>;
   p2_right: Option<u32>;
-  phase: u32;
+  p2_wins: u32;
+  phase: Phase;
   player1: string;
   player1_points: i128;
   player2: string;
   player2_points: i128;
+  rake_bps: u32;
+  rematch: Option<u32>;
+  rematch_of: Option<u32>;
+  rematch_offer: Option<string>;
+  rematch_swap: boolean;
+  round: u32;
   winner: Option<string>;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * One player's side of a `GameView`.
+ */
+export interface SeatView {
+  choice_committed: boolean;
+  choice_revealed: boolean;
+  hands_committed: boolean;
+  hands_revealed: boolean;
+  /**
+   * Hidden from the opponent until the game is complete
+   */
+  kept_hand: Option<u32>;
+  /**
+   * Hidden from the opponent until both hands are revealed
+   */
+  left_hand: Option<u32>;
+  player: string;
+  points: i128;
+  right_hand: Option<u32>;
+  wins: u32;
+}
+
+/**
+ * A game as one viewer may see it, from `get_game_view`.  Commitment
+ * hashes are never included.
+ */
+export interface GameView {
+  best_of: u32;
+  deadline: u32;
+  draw_policy: DrawPolicy;
+  escrow_token: Option<string>;
+  history: Array<RoundResult>;
+  lobby: boolean;
+  phase: Phase;
+  player1: SeatView;
+  player2: SeatView;
+  rake_bps: u32;
+  rematch: Option<u32>;
+  rematch_of: Option<u32>;
+  rematch_offer: Option<string>;
+  rematch_swap: boolean;
+  round: u32;
+  session_id: u32;
+  winner: Option<string>;
+}
+
+/**
+ * One page of a player's sessions from `get_player_games`.
+ */
+export interface GamePage {
+  /**
+   * `(session_id, game)` pairs on this page, newest first.  Sessions
+   * whose game has expired from storage are left out, so a page can
+   * hold fewer than `limit`.
+   */
+  games: Array<readonly [u32, Game]>;
+  /**
+   * Cursor for the next page, `None` once the index is exhausted.
+   * Counted from the player's oldest session, so games started while
+   * paging do not shift it.
+   */
+  next_cursor: Option<u32>;
+}
+
+/**
+ * Outcome of one completed round in a match.
+ */
+export interface RoundResult {
+  outcome: RoundOutcome;
+  p1_kept: u32;
+  p1_left: u32;
+  p1_right: u32;
+  p2_kept: u32;
+  p2_left: u32;
+  p2_right: u32;
+}
+
+export type RoundOutcome = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void};
+
+/**
+ * How a round resolves when both kept hands are equal.
+ */
+export type DrawPolicy = {tag: "Player1Wins", values: void} | {tag: "ReplayChoice", values: void} | {tag: "ReplayRound", values: void} | {tag: "TrueDraw", values: void};
+
+/**
+ * CTM configuration.  Games, the admin and the Game Hub address are stored
+ * under `game_core::CoreKey`.
+ */
+export type DataKey = {tag: "DrawPolicy", values: void} | {tag: "HandsVerifier", values: void} | {tag: "HandsVk", values: void} | {tag: "EscrowToken", values: void} | {tag: "RakeBps", values: void} | {tag: "Tournament", values: void} | {tag: "PlayerGames", values: readonly [string]};
 
 export interface Client {
   /**
@@ -88,9 +229,41 @@ export interface Client {
    * Start a new Gawi Bawi Bo session.
    * 
    * Creates a session in the Game Hub and locks both players' points.
-   * Requires multi-sig auth from both players.
+   * Requires multi-sig auth from both players. Pass `session_id` 0 to let
+   * the Game Hub allocate an unused ID; the session ID is returned.
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_lobby_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-1 session on behalf of the registered lobby.
+   * 
+   * Only the lobby's auth is required: the players already authorized
+   * their stakes when posting and accepting the challenge there.  The
+   * lobby holds those stakes, so no points are locked.
+   */
+  start_lobby_game: ({session_id, player1, player2}: {session_id: u32, player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_tournament_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-1 session for a bracket pairing of the registered
+   * tournament contract.
+   * 
+   * Only the tournament's auth is required: the players signed up when
+   * they registered there.  The Game Hub allocates the session ID, which
+   * is returned, and no points are locked.  Drawn rounds are never
+   * replayed: under either replay policy they are scored as `TrueDraw`.
+   */
+  start_tournament_game: ({player1, player2}: {player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-N match (`best_of` = 1, 3, 5 or 7).
+   * 
+   * Same as `start_game`, but each player's auth also covers `best_of`
+   * so neither side can be signed into a longer match than agreed.
+   */
+  start_match: ({session_id, player1, player2, player1_points, player2_points, best_of}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, best_of: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a commit_hands transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -100,6 +273,15 @@ export interface Client {
    */
   commit_hands: ({session_id, player, hands_hash}: {session_id: u32, player: string, hands_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a commit_hands_with_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * **Phase 1** – Commit two hands with a `hands_commit` UltraHonk proof.
+   * 
+   * The proof shows the hash opens to two valid, different hands, so the
+   * commitment cannot later fail `reveal_hands` on hand validity.
+   */
+  commit_hands_with_proof: ({session_id, player, hands_hash, proof}: {session_id: u32, player: string, hands_hash: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a reveal_hands transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * **Phase 2** – Reveal hands and verify against the commitment hash.
@@ -124,39 +306,195 @@ export interface Client {
    * **Phase 4** – Reveal which hand you kept.
    * 
    * The contract verifies the hash, looks up the actual hand value, and —
-   * once both players have revealed — resolves the RPS duel and reports to
-   * the Game Hub.
+   * once both players have revealed — resolves the RPS duel.  The round is
+   * appended to `history`; if neither player has a majority yet the next
+   * round opens in phase 1, otherwise the match result goes to the Game Hub.
    */
   reveal_choice: ({session_id, player, choice_index, salt}: {session_id: u32, player: string, choice_index: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the win after the opponent stalls past the phase deadline.
+   * 
+   * Only the player who already acted in the current phase may claim, and
+   * only once the ledger sequence is past `game.deadline`.  The claimant is
+   * recorded as winner and the outcome is reported to the Game Hub.
+   */
+  claim_timeout: ({session_id, claimant}: {session_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a request_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer a rematch of a completed game to the opponent.
+   * 
+   * The rematch keeps the players, stakes, match length, draw policy and
+   * rake; with `swap` player 1 and player 2 trade seats.  In an escrowed
+   * game the requester's stake is deposited now and refunded by
+   * `cancel_rematch`.  Lobby games cannot be rematched: their stakes are
+   * held and paid out by the lobby, so a rematch would be unstaked.
+   */
+  request_rematch: ({session_id, player, swap}: {session_id: u32, player: string, swap: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's rematch offer and open the new session.
+   * 
+   * The session ID is allocated by the Game Hub and returned.  Both
+   * players' points are locked again, and in an escrowed game the
+   * accepting player's stake is deposited.
+   */
+  accept_rematch: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a cancel_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a pending rematch offer, refunding an escrowed stake.
+   */
+  cancel_rematch: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Read the current game state.
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read whether a game is complete and who won, without the full state.
+   */
+  get_result: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameResult>>>
+
+  /**
+   * Construct and simulate a get_game_view transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read the game as `viewer` may see it, without commitment hashes or
+   * the opponent's undisclosed hands.  Any address can view; only the
+   * players see their own seat unredacted.
+   */
+  get_game_view: ({session_id, viewer}: {session_id: u32, viewer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameView>>>
+
+  /**
+   * Construct and simulate a get_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read up to `MAX_PAGE_SIZE` games at once, keyed by session ID.
+   * Unknown or expired sessions are left out of the map.
+   */
+  get_games: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Map<u32, Game>>>>
+
+  /**
+   * Construct and simulate a get_player_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Page through the sessions `player` has taken part in, newest first.
+   * 
+   * `cursor` is 0 for the first page and `next_cursor` of the previous
+   * page after that; `limit` is 1 to `MAX_PAGE_SIZE`.  A page picks up
+   * where the previous one stopped even if new games were indexed in
+   * between; sessions dropped from the index meanwhile are skipped.
+   */
+  get_player_games: ({player, cursor, limit}: {player: string, cursor: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GamePage>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   * 
+   * # Returns
+   * * `Address` - The admin address
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
    * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new admin address
+   * 
+   * # Arguments
+   * * `new_admin` - The new admin address
    */
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   * 
+   * # Returns
+   * * `Address` - The GameHub contract address
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
+   * 
+   * # Arguments
+   * * `new_hub` - The new GameHub contract address
    */
   set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_draw_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_draw_policy: (options?: MethodOptions) => Promise<AssembledTransaction<DrawPolicy>>
+
+  /**
+   * Construct and simulate a set_draw_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the draw policy for games started from now on.  Tournament games
+   * score drawn rounds as `TrueDraw` in place of either replay policy.
+   */
+  set_draw_policy: ({policy}: {policy: DrawPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_hands_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configure the UltraHonk verifier contract and `hands_commit` VK used
+   * by `commit_hands_with_proof`.
+   */
+  set_hands_verifier: ({verifier, vk}: {verifier: string, vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_escrow: (options?: MethodOptions) => Promise<AssembledTransaction<Option<readonly [string, u32]>>>
+
+  /**
+   * Construct and simulate a set_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake real tokens in new games: `token` is a SEP-41 contract and
+   * `rake_bps` the admin's cut of each pot in basis points.
+   */
+  set_escrow: ({token, rake_bps}: {token: string, rake_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Return to points-only games.  Games already in escrow still settle.
+   */
+  clear_escrow: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the lobby allowed to call `start_lobby_game`
+   * 
+   * # Returns
+   * * `Option<Address>` - The lobby address, or `None` if none is registered
+   */
+  get_lobby: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the lobby allowed to call `start_lobby_game`
+   * 
+   * # Arguments
+   * * `lobby` - The lobby contract address
+   */
+  set_lobby: ({lobby}: {lobby: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_tournament: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_tournament: ({tournament}: {tournament: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
+   * 
+   * # Arguments
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
   upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
@@ -180,35 +518,87 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAQAAAAAAAAAC0ludmFsaWRIYW5kAAAAAAUAAAAAAAAAD0hhbmRzTXVzdERpZmZlcgAAAAAGAAAAAAAAAAxIYXNoTWlzbWF0Y2gAAAAHAAAAAAAAAA1JbnZhbGlkQ2hvaWNlAAAAAAAACAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAk=",
-        "AAAAAQAAAXZIYW5kIGNvbnN0YW50czogMCA9IFJvY2sg8J+qqCwgIDEgPSBQYXBlciDinIssICAyID0gU2Npc3NvcnMg4pyM77iPCgpHYW1lIHBoYXNlczoKMSA9IENvbW1pdEhhbmRzICAgICAg4oCTIHdhaXRpbmcgZm9yIGJvdGggY29tbWl0IGhhc2hlcwoyID0gUmV2ZWFsSGFuZHMgICAgICDigJMgd2FpdGluZyBmb3IgYm90aCB0byByZXZlYWwgaGFuZHMKMyA9IENvbW1pdENob2ljZSAgICAg4oCTIHdhaXRpbmcgZm9yIGJvdGggdG8gY29tbWl0IHdoaWNoIGhhbmQgdG8ga2VlcAo0ID0gUmV2ZWFsQ2hvaWNlICAgICDigJMgd2FpdGluZyBmb3IgYm90aCB0byByZXZlYWwgdGhlaXIgY2hvaWNlCjUgPSBDb21wbGV0ZSAgICAgICAgIOKAkyB3aW5uZXIgZGV0ZXJtaW5lZAAAAAAAAAAAAARHYW1lAAAAEAAAAAAAAAAQcDFfY2hvaWNlX2NvbW1pdAAAA+gAAAPuAAAAIAAAAAAAAAAJcDFfY29tbWl0AAAAAAAD6AAAA+4AAAAgAAAAAAAAAAdwMV9rZXB0AAAAA+gAAAAEAAAAAAAAAAdwMV9sZWZ0AAAAA+gAAAAEAAAAAAAAAAhwMV9yaWdodAAAA+gAAAAEAAAAAAAAABBwMl9jaG9pY2VfY29tbWl0AAAD6AAAA+4AAAAgAAAAAAAAAAlwMl9jb21taXQAAAAAAAPoAAAD7gAAACAAAAAAAAAAB3AyX2tlcHQAAAAD6AAAAAQAAAAAAAAAB3AyX2xlZnQAAAAD6AAAAAQAAAAAAAAACHAyX3JpZ2h0AAAD6AAAAAQAAAAAAAAABXBoYXNlAAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAQAAAAAAAAAC0ludmFsaWRIYW5kAAAAAAUAAAAAAAAAD0hhbmRzTXVzdERpZmZlcgAAAAAGAAAAAAAAAAxIYXNoTWlzbWF0Y2gAAAAHAAAAAAAAAA1JbnZhbGlkQ2hvaWNlAAAAAAAACAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAkAAAAAAAAAEkRlYWRsaW5lTm90UmVhY2hlZAAAAAAACgAAAAAAAAASQ2Fubm90Q2xhaW1UaW1lb3V0AAAAAAALAAAAAAAAAA1JbnZhbGlkUm91bmRzAAAAAAAADAAAAAAAAAASUHJvb2ZOb3RDb25maWd1cmVkAAAAAAANAAAAAAAAAAxJbnZhbGlkUHJvb2YAAAAOAAAAAAAAAAtJbnZhbGlkUmFrZQAAAAAPAAAAAAAAAAxJbnZhbGlkU3Rha2UAAAAQAAAAAAAAAAhTZWxmUGxheQAAABEAAAAAAAAACUh1Yk5vdFNldAAAAAAAABIAAAAAAAAAC0xvYmJ5Tm90U2V0AAAAABMAAAAAAAAADVNlc3Npb25FeGlzdHMAAAAAAAAUAAAAAAAAAA1SZW1hdGNoRXhpc3RzAAAAAAAAFQAAAAAAAAAOTm9SZW1hdGNoT2ZmZXIAAAAAABYAAAAAAAAAD0Nhbm5vdEFjY2VwdE93bgAAAAAXAAAAAAAAABBUb3VybmFtZW50Tm90U2V0AAAAGAAAAAAAAAAPSW52YWxpZFBhZ2VTaXplAAAAABkAAAAAAAAADExvYmJ5UmVtYXRjaAAAABo=",
+        "AAAAAwAAADJHYW1lIHBoYXNlcy4gIEVuY29kZWQgYXMgYHUzMmAgKDEuLjUpIG9uIHRoZSB3aXJlLgAAAAAAAAAAAAVQaGFzZQAAAAAAAAUAAAAeV2FpdGluZyBmb3IgYm90aCBjb21taXQgaGFzaGVzAAAAAAALQ29tbWl0SGFuZHMAAAAAAQAAACBXYWl0aW5nIGZvciBib3RoIHRvIHJldmVhbCBoYW5kcwAAAAtSZXZlYWxIYW5kcwAAAAACAAAALVdhaXRpbmcgZm9yIGJvdGggdG8gY29tbWl0IHdoaWNoIGhhbmQgdG8ga2VlcAAAAAAAAAxDb21taXRDaG9pY2UAAAADAAAAJ1dhaXRpbmcgZm9yIGJvdGggdG8gcmV2ZWFsIHRoZWlyIGNob2ljZQAAAAAMUmV2ZWFsQ2hvaWNlAAAABAAAACpXaW5uZXIgZGV0ZXJtaW5lZCAobm9uZSBhZnRlciBhIHRydWUgZHJhdykAAAAAAAhDb21wbGV0ZQAAAAU=",
+        "AAAAAQAAAENIYW5kIGNvbnN0YW50czogMCA9IFJvY2sg8J+qqCwgIDEgPSBQYXBlciDinIssICAyID0gU2Npc3NvcnMg4pyM77iPAAAAAAAAAAAER2FtZQAAAB4AAAAAAAAAB2Jlc3Rfb2YAAAAABAAAAAAAAAAIZGVhZGxpbmUAAAAEAAAAAAAAAAtkcmF3X3BvbGljeQAAAAfQAAAACkRyYXdQb2xpY3kAAAAAAAAAAAAMZXNjcm93X3Rva2VuAAAD6AAAABMAAAAAAAAAB2hpc3RvcnkAAAAD6gAAB9AAAAALUm91bmRSZXN1bHQAAAAAAAAAAAVsb2JieQAAAAAAAAEAAAAAAAAAEHAxX2Nob2ljZV9jb21taXQAAAPoAAAD7gAAACAAAAAAAAAACXAxX2NvbW1pdAAAAAAAA+gAAAPuAAAAIAAAAAAAAAAHcDFfa2VwdAAAAAPoAAAABAAAAAAAAAAHcDFfbGVmdAAAAAPoAAAABAAAAAAAAAAIcDFfcmlnaHQAAAPoAAAABAAAAAAAAAAHcDFfd2lucwAAAAAEAAAAAAAAABBwMl9jaG9pY2VfY29tbWl0AAAD6AAAA+4AAAAgAAAAAAAAAAlwMl9jb21taXQAAAAAAAPoAAAD7gAAACAAAAAAAAAAB3AyX2tlcHQAAAAD6AAAAAQAAAAAAAAAB3AyX2xlZnQAAAAD6AAAAAQAAAAAAAAACHAyX3JpZ2h0AAAD6AAAAAQAAAAAAAAAB3AyX3dpbnMAAAAABAAAAAAAAAAFcGhhc2UAAAAAAAfQAAAABVBoYXNlAAAAAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAAAAAAAB3JlbWF0Y2gAAAAD6AAAAAQAAAAAAAAACnJlbWF0Y2hfb2YAAAAAA+gAAAAEAAAAAAAAAA1yZW1hdGNoX29mZmVyAAAAAAAD6AAAABMAAAAAAAAADHJlbWF0Y2hfc3dhcAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAQAAACJPbmUgcGxheWVyJ3Mgc2lkZSBvZiBhIGBHYW1lVmlld2AuAAAAAAAAAAAACFNlYXRWaWV3AAAACgAAAAAAAAAQY2hvaWNlX2NvbW1pdHRlZAAAAAEAAAAAAAAAD2Nob2ljZV9yZXZlYWxlZAAAAAABAAAAAAAAAA9oYW5kc19jb21taXR0ZWQAAAAAAQAAAAAAAAAOaGFuZHNfcmV2ZWFsZWQAAAAAAAEAAAAzSGlkZGVuIGZyb20gdGhlIG9wcG9uZW50IHVudGlsIHRoZSBnYW1lIGlzIGNvbXBsZXRlAAAAAAlrZXB0X2hhbmQAAAAAAAPoAAAABAAAADZIaWRkZW4gZnJvbSB0aGUgb3Bwb25lbnQgdW50aWwgYm90aCBoYW5kcyBhcmUgcmV2ZWFsZWQAAAAAAAlsZWZ0X2hhbmQAAAAAAAPoAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAACnJpZ2h0X2hhbmQAAAAAA+gAAAAEAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAF1BIGdhbWUgYXMgb25lIHZpZXdlciBtYXkgc2VlIGl0LCBmcm9tIGBnZXRfZ2FtZV92aWV3YC4gIENvbW1pdG1lbnQKaGFzaGVzIGFyZSBuZXZlciBpbmNsdWRlZC4AAAAAAAAAAAAACEdhbWVWaWV3AAAAEQAAAAAAAAAHYmVzdF9vZgAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAQAAAAAAAAAC2RyYXdfcG9saWN5AAAAB9AAAAAKRHJhd1BvbGljeQAAAAAAAAAAAAxlc2Nyb3dfdG9rZW4AAAPoAAAAEwAAAAAAAAAHaGlzdG9yeQAAAAPqAAAH0AAAAAtSb3VuZFJlc3VsdAAAAAAAAAAABWxvYmJ5AAAAAAAAAQAAAAAAAAAFcGhhc2UAAAAAAAfQAAAABVBoYXNlAAAAAAAAAAAAAAdwbGF5ZXIxAAAAB9AAAAAIU2VhdFZpZXcAAAAAAAAAB3BsYXllcjIAAAAH0AAAAAhTZWF0VmlldwAAAAAAAAAIcmFrZV9icHMAAAAEAAAAAAAAAAdyZW1hdGNoAAAAA+gAAAAEAAAAAAAAAApyZW1hdGNoX29mAAAAAAPoAAAABAAAAAAAAAANcmVtYXRjaF9vZmZlcgAAAAAAA+gAAAATAAAAAAAAAAxyZW1hdGNoX3N3YXAAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAADhPbmUgcGFnZSBvZiBhIHBsYXllcidzIHNlc3Npb25zIGZyb20gYGdldF9wbGF5ZXJfZ2FtZXNgLgAAAAAAAAAIR2FtZVBhZ2UAAAACAAAAmWAoc2Vzc2lvbl9pZCwgZ2FtZSlgIHBhaXJzIG9uIHRoaXMgcGFnZSwgbmV3ZXN0IGZpcnN0LiAgU2Vzc2lvbnMKd2hvc2UgZ2FtZSBoYXMgZXhwaXJlZCBmcm9tIHN0b3JhZ2UgYXJlIGxlZnQgb3V0LCBzbyBhIHBhZ2UgY2FuCmhvbGQgZmV3ZXIgdGhhbiBgbGltaXRgLgAAAAAAAAVnYW1lcwAAAAAAA+oAAAPtAAAAAgAAAAQAAAfQAAAABEdhbWUAAACWQ3Vyc29yIGZvciB0aGUgbmV4dCBwYWdlLCBgTm9uZWAgb25jZSB0aGUgaW5kZXggaXMgZXhoYXVzdGVkLgpDb3VudGVkIGZyb20gdGhlIHBsYXllcidzIG9sZGVzdCBzZXNzaW9uLCBzbyBnYW1lcyBzdGFydGVkIHdoaWxlCnBhZ2luZyBkbyBub3Qgc2hpZnQgaXQuAAAAAAALbmV4dF9jdXJzb3IAAAAD6AAAAAQ=",
+        "AAAAAQAAACpPdXRjb21lIG9mIG9uZSBjb21wbGV0ZWQgcm91bmQgaW4gYSBtYXRjaC4AAAAAAAAAAAALUm91bmRSZXN1bHQAAAAABwAAAAAAAAAHb3V0Y29tZQAAAAfQAAAADFJvdW5kT3V0Y29tZQAAAAAAAAAHcDFfa2VwdAAAAAAEAAAAAAAAAAdwMV9sZWZ0AAAAAAQAAAAAAAAACHAxX3JpZ2h0AAAABAAAAAAAAAAHcDJfa2VwdAAAAAAEAAAAAAAAAAdwMl9sZWZ0AAAAAAQAAAAAAAAACHAyX3JpZ2h0AAAABA==",
+        "AAAAAgAAAAAAAAAAAAAADFJvdW5kT3V0Y29tZQAAAAMAAAAAAAAAAAAAAApQbGF5ZXIxV29uAAAAAAAAAAAAAAAAAApQbGF5ZXIyV29uAAAAAAAAAAAAAAAAAAREcmF3",
+        "AAAAAgAAADRIb3cgYSByb3VuZCByZXNvbHZlcyB3aGVuIGJvdGgga2VwdCBoYW5kcyBhcmUgZXF1YWwuAAAAAAAAAApEcmF3UG9saWN5AAAAAAAEAAAAAAAAADNQbGF5ZXIgMSB0YWtlcyB0aGUgcm91bmQgKG9yaWdpbmFsIHN0dWRpbyB0aWVicmVhaykAAAAAC1BsYXllcjFXaW5zAAAAAAAAAAAqS2VlcCB0aGUgcmV2ZWFsZWQgaGFuZHMgYW5kIHJlb3BlbiBwaGFzZSAzAAAAAAAMUmVwbGF5Q2hvaWNlAAAAAAAAACREaXNjYXJkIHRoZSByb3VuZCBhbmQgcmVvcGVuIHBoYXNlIDEAAAALUmVwbGF5Um91bmQAAAAAAAAAAEdTY29yZSB0aGUgcm91bmQgYXMgYSBkcmF3OyBhIHRpZWQgbWF0Y2ggaXMgcmVwb3J0ZWQgdmlhIGBlbmRfZ2FtZV9kcmF3YAAAAAAIVHJ1ZURyYXc=",
+        "AAAAAgAAAGRDVE0gY29uZmlndXJhdGlvbi4gIEdhbWVzLCB0aGUgYWRtaW4gYW5kIHRoZSBHYW1lIEh1YiBhZGRyZXNzIGFyZSBzdG9yZWQKdW5kZXIgYGdhbWVfY29yZTo6Q29yZUtleWAuAAAAAAAAAAdEYXRhS2V5AAAAAAcAAAAAAAAAAAAAAApEcmF3UG9saWN5AAAAAAAAAAAAAAAAAA1IYW5kc1ZlcmlmaWVyAAAAAAAAAAAAAAAAAAAHSGFuZHNWawAAAAAAAAAAAAAAAAtFc2Nyb3dUb2tlbgAAAAAAAAAAAAAAAAdSYWtlQnBzAAAAAAAAAAAAAAAAClRvdXJuYW1lbnQAAAAAAAEAAABFYFBsYXllckluZGV4YCBvZiB0aGUgc2Vzc2lvbnMgYSBwbGF5ZXIgaGFzIHRha2VuIHBhcnQgaW4gKHBlcnNpc3RlbnQpAAAAAAAAC1BsYXllckdhbWVzAAAAAAEAAAAT",
+        "AAAABAAAAJtFcnJvcnMgcmFpc2VkIGJ5IHRoZSBzaGFyZWQgaGVscGVycy4KCkNvZGVzIHN0YXJ0IGF0IDEwMCBzbyB0aGV5IG5ldmVyIGNsYXNoIHdpdGggYSBnYW1lJ3Mgb3duIGNvZGVzIHdoZW4KcmV0dXJuZWQgZGlyZWN0bHksIGFzIHRoZSBbYEFkbWluYF0gZnVuY3Rpb25zIGRvLgAAAAAAAAAACUNvcmVFcnJvcgAAAAAAAAMAAAAAAAAACFNlbGZQbGF5AAAAZAAAAAAAAAAJSHViTm90U2V0AAAAAAAAZQAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAGY=",
         "AAAAAAAAADZJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIGFkbWluICsgR2FtZSBIdWIgYWRkcmVzcy4AAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAI9TdGFydCBhIG5ldyBHYXdpIEJhd2kgQm8gc2Vzc2lvbi4KCkNyZWF0ZXMgYSBzZXNzaW9uIGluIHRoZSBHYW1lIEh1YiBhbmQgbG9ja3MgYm90aCBwbGF5ZXJzJyBwb2ludHMuClJlcXVpcmVzIG11bHRpLXNpZyBhdXRoIGZyb20gYm90aCBwbGF5ZXJzLgAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAOpTdGFydCBhIG5ldyBHYXdpIEJhd2kgQm8gc2Vzc2lvbi4KCkNyZWF0ZXMgYSBzZXNzaW9uIGluIHRoZSBHYW1lIEh1YiBhbmQgbG9ja3MgYm90aCBwbGF5ZXJzJyBwb2ludHMuClJlcXVpcmVzIG11bHRpLXNpZyBhdXRoIGZyb20gYm90aCBwbGF5ZXJzLiBQYXNzIGBzZXNzaW9uX2lkYCAwIHRvIGxldAp0aGUgR2FtZSBIdWIgYWxsb2NhdGUgYW4gdW51c2VkIElEOyB0aGUgc2Vzc2lvbiBJRCBpcyByZXR1cm5lZC4AAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAPRTdGFydCBhIGJlc3Qtb2YtMSBzZXNzaW9uIG9uIGJlaGFsZiBvZiB0aGUgcmVnaXN0ZXJlZCBsb2JieS4KCk9ubHkgdGhlIGxvYmJ5J3MgYXV0aCBpcyByZXF1aXJlZDogdGhlIHBsYXllcnMgYWxyZWFkeSBhdXRob3JpemVkCnRoZWlyIHN0YWtlcyB3aGVuIHBvc3RpbmcgYW5kIGFjY2VwdGluZyB0aGUgY2hhbGxlbmdlIHRoZXJlLiAgVGhlCmxvYmJ5IGhvbGRzIHRob3NlIHN0YWtlcywgc28gbm8gcG9pbnRzIGFyZSBsb2NrZWQuAAAAEHN0YXJ0X2xvYmJ5X2dhbWUAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAWJTdGFydCBhIGJlc3Qtb2YtMSBzZXNzaW9uIGZvciBhIGJyYWNrZXQgcGFpcmluZyBvZiB0aGUgcmVnaXN0ZXJlZAp0b3VybmFtZW50IGNvbnRyYWN0LgoKT25seSB0aGUgdG91cm5hbWVudCdzIGF1dGggaXMgcmVxdWlyZWQ6IHRoZSBwbGF5ZXJzIHNpZ25lZCB1cCB3aGVuCnRoZXkgcmVnaXN0ZXJlZCB0aGVyZS4gIFRoZSBHYW1lIEh1YiBhbGxvY2F0ZXMgdGhlIHNlc3Npb24gSUQsIHdoaWNoCmlzIHJldHVybmVkLCBhbmQgbm8gcG9pbnRzIGFyZSBsb2NrZWQuICBEcmF3biByb3VuZHMgYXJlIG5ldmVyCnJlcGxheWVkOiB1bmRlciBlaXRoZXIgcmVwbGF5IHBvbGljeSB0aGV5IGFyZSBzY29yZWQgYXMgYFRydWVEcmF3YC4AAAAAABVzdGFydF90b3VybmFtZW50X2dhbWUAAAAAAAACAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAALZTdGFydCBhIGJlc3Qtb2YtTiBtYXRjaCAoYGJlc3Rfb2ZgID0gMSwgMywgNSBvciA3KS4KClNhbWUgYXMgYHN0YXJ0X2dhbWVgLCBidXQgZWFjaCBwbGF5ZXIncyBhdXRoIGFsc28gY292ZXJzIGBiZXN0X29mYApzbyBuZWl0aGVyIHNpZGUgY2FuIGJlIHNpZ25lZCBpbnRvIGEgbG9uZ2VyIG1hdGNoIHRoYW4gYWdyZWVkLgAAAAAAC3N0YXJ0X21hdGNoAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAB2Jlc3Rfb2YAAAAABAAAAAEAAAPpAAAABAAAAAM=",
         "AAAAAAAAAHMqKlBoYXNlIDEqKiDigJMgQ29tbWl0IHR3byBoYW5kcyAoaGlkZGVuKS4KCmBoYW5kc19oYXNoID0ga2VjY2FrMjU2KGxlZnRfaGFuZF91OCB8fCByaWdodF9oYW5kX3U4IHx8IHNhbHRfMzJieXRlcylgAAAAAAxjb21taXRfaGFuZHMAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmhhbmRzX2hhc2gAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAMsqKlBoYXNlIDEqKiDigJMgQ29tbWl0IHR3byBoYW5kcyB3aXRoIGEgYGhhbmRzX2NvbW1pdGAgVWx0cmFIb25rIHByb29mLgoKVGhlIHByb29mIHNob3dzIHRoZSBoYXNoIG9wZW5zIHRvIHR3byB2YWxpZCwgZGlmZmVyZW50IGhhbmRzLCBzbyB0aGUKY29tbWl0bWVudCBjYW5ub3QgbGF0ZXIgZmFpbCBgcmV2ZWFsX2hhbmRzYCBvbiBoYW5kIHZhbGlkaXR5LgAAAAAXY29tbWl0X2hhbmRzX3dpdGhfcHJvb2YAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApoYW5kc19oYXNoAAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAOwqKlBoYXNlIDIqKiDigJMgUmV2ZWFsIGhhbmRzIGFuZCB2ZXJpZnkgYWdhaW5zdCB0aGUgY29tbWl0bWVudCBoYXNoLgoKVGhlIGNvbnRyYWN0IHJlY29tcHV0ZXMgYGtlY2NhazI1NihsZWZ0IHx8IHJpZ2h0IHx8IHNhbHQpYCBhbmQgY2hlY2tzCml0IG1hdGNoZXMgdGhlIHN0b3JlZCBjb21taXRtZW50LiAgQm90aCBoYW5kcyBtdXN0IGJlIHZhbGlkICgwLTIpIGFuZApkaWZmZXJlbnQgZnJvbSBlYWNoIG90aGVyLgAAAAxyZXZlYWxfaGFuZHMAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWxlZnRfaGFuZAAAAAAAAAQAAAAAAAAACnJpZ2h0X2hhbmQAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAK0qKlBoYXNlIDMqKiDigJMgQ29tbWl0IHdoaWNoIGhhbmQgdG8ga2VlcCAoaGlkZGVuKS4KCmBjaG9pY2VfaGFzaCA9IGtlY2NhazI1NihjaG9pY2VfaW5kZXhfdTggfHwgc2FsdF8zMmJ5dGVzKWAKd2hlcmUgYGNob2ljZV9pbmRleGAgaXMgMCBmb3IgdGhlIGxlZnQgaGFuZCwgMSBmb3IgdGhlIHJpZ2h0LgAAAAAAAA1jb21taXRfY2hvaWNlAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtjaG9pY2VfaGFzaAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAMsqKlBoYXNlIDQqKiDigJMgUmV2ZWFsIHdoaWNoIGhhbmQgeW91IGtlcHQuCgpUaGUgY29udHJhY3QgdmVyaWZpZXMgdGhlIGhhc2gsIGxvb2tzIHVwIHRoZSBhY3R1YWwgaGFuZCB2YWx1ZSwgYW5kIOKAlApvbmNlIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIOKAlCByZXNvbHZlcyB0aGUgUlBTIGR1ZWwgYW5kIHJlcG9ydHMgdG8KdGhlIEdhbWUgSHViLgAAAAANcmV2ZWFsX2Nob2ljZQAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAMY2hvaWNlX2luZGV4AAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAUsqKlBoYXNlIDQqKiDigJMgUmV2ZWFsIHdoaWNoIGhhbmQgeW91IGtlcHQuCgpUaGUgY29udHJhY3QgdmVyaWZpZXMgdGhlIGhhc2gsIGxvb2tzIHVwIHRoZSBhY3R1YWwgaGFuZCB2YWx1ZSwgYW5kIOKAlApvbmNlIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIOKAlCByZXNvbHZlcyB0aGUgUlBTIGR1ZWwuICBUaGUgcm91bmQgaXMKYXBwZW5kZWQgdG8gYGhpc3RvcnlgOyBpZiBuZWl0aGVyIHBsYXllciBoYXMgYSBtYWpvcml0eSB5ZXQgdGhlIG5leHQKcm91bmQgb3BlbnMgaW4gcGhhc2UgMSwgb3RoZXJ3aXNlIHRoZSBtYXRjaCByZXN1bHQgZ29lcyB0byB0aGUgR2FtZSBIdWIuAAAAAA1yZXZlYWxfY2hvaWNlAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAxjaG9pY2VfaW5kZXgAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAQ9DbGFpbSB0aGUgd2luIGFmdGVyIHRoZSBvcHBvbmVudCBzdGFsbHMgcGFzdCB0aGUgcGhhc2UgZGVhZGxpbmUuCgpPbmx5IHRoZSBwbGF5ZXIgd2hvIGFscmVhZHkgYWN0ZWQgaW4gdGhlIGN1cnJlbnQgcGhhc2UgbWF5IGNsYWltLCBhbmQKb25seSBvbmNlIHRoZSBsZWRnZXIgc2VxdWVuY2UgaXMgcGFzdCBgZ2FtZS5kZWFkbGluZWAuICBUaGUgY2xhaW1hbnQgaXMKcmVjb3JkZWQgYXMgd2lubmVyIGFuZCB0aGUgb3V0Y29tZSBpcyByZXBvcnRlZCB0byB0aGUgR2FtZSBIdWIuAAAAAA1jbGFpbV90aW1lb3V0AAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIY2xhaW1hbnQAAAATAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAYBPZmZlciBhIHJlbWF0Y2ggb2YgYSBjb21wbGV0ZWQgZ2FtZSB0byB0aGUgb3Bwb25lbnQuCgpUaGUgcmVtYXRjaCBrZWVwcyB0aGUgcGxheWVycywgc3Rha2VzLCBtYXRjaCBsZW5ndGgsIGRyYXcgcG9saWN5IGFuZApyYWtlOyB3aXRoIGBzd2FwYCBwbGF5ZXIgMSBhbmQgcGxheWVyIDIgdHJhZGUgc2VhdHMuICBJbiBhbiBlc2Nyb3dlZApnYW1lIHRoZSByZXF1ZXN0ZXIncyBzdGFrZSBpcyBkZXBvc2l0ZWQgbm93IGFuZCByZWZ1bmRlZCBieQpgY2FuY2VsX3JlbWF0Y2hgLiAgTG9iYnkgZ2FtZXMgY2Fubm90IGJlIHJlbWF0Y2hlZDogdGhlaXIgc3Rha2VzIGFyZQpoZWxkIGFuZCBwYWlkIG91dCBieSB0aGUgbG9iYnksIHNvIGEgcmVtYXRjaCB3b3VsZCBiZSB1bnN0YWtlZC4AAAAPcmVxdWVzdF9yZW1hdGNoAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEc3dhcAAAAAEAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAONBY2NlcHQgdGhlIG9wcG9uZW50J3MgcmVtYXRjaCBvZmZlciBhbmQgb3BlbiB0aGUgbmV3IHNlc3Npb24uCgpUaGUgc2Vzc2lvbiBJRCBpcyBhbGxvY2F0ZWQgYnkgdGhlIEdhbWUgSHViIGFuZCByZXR1cm5lZC4gIEJvdGgKcGxheWVycycgcG9pbnRzIGFyZSBsb2NrZWQgYWdhaW4sIGFuZCBpbiBhbiBlc2Nyb3dlZCBnYW1lIHRoZQphY2NlcHRpbmcgcGxheWVyJ3Mgc3Rha2UgaXMgZGVwb3NpdGVkLgAAAAAOYWNjZXB0X3JlbWF0Y2gAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAD5XaXRoZHJhdyBhIHBlbmRpbmcgcmVtYXRjaCBvZmZlciwgcmVmdW5kaW5nIGFuIGVzY3Jvd2VkIHN0YWtlLgAAAAAADmNhbmNlbF9yZW1hdGNoAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAABxSZWFkIHRoZSBjdXJyZW50IGdhbWUgc3RhdGUuAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA" ]),
+        "AAAAAAAAAERSZWFkIHdoZXRoZXIgYSBnYW1lIGlzIGNvbXBsZXRlIGFuZCB3aG8gd29uLCB3aXRob3V0IHRoZSBmdWxsIHN0YXRlLgAAAApnZXRfcmVzdWx0AAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAM=",
+        "AAAAAAAAAKtSZWFkIHRoZSBnYW1lIGFzIGB2aWV3ZXJgIG1heSBzZWUgaXQsIHdpdGhvdXQgY29tbWl0bWVudCBoYXNoZXMgb3IKdGhlIG9wcG9uZW50J3MgdW5kaXNjbG9zZWQgaGFuZHMuICBBbnkgYWRkcmVzcyBjYW4gdmlldzsgb25seSB0aGUKcGxheWVycyBzZWUgdGhlaXIgb3duIHNlYXQgdW5yZWRhY3RlZC4AAAAADWdldF9nYW1lX3ZpZXcAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZ2aWV3ZXIAAAAAABMAAAABAAAD6QAAB9AAAAAIR2FtZVZpZXcAAAAD",
+        "AAAAAAAAAHNSZWFkIHVwIHRvIGBNQVhfUEFHRV9TSVpFYCBnYW1lcyBhdCBvbmNlLCBrZXllZCBieSBzZXNzaW9uIElELgpVbmtub3duIG9yIGV4cGlyZWQgc2Vzc2lvbnMgYXJlIGxlZnQgb3V0IG9mIHRoZSBtYXAuAAAAAAlnZXRfZ2FtZXMAAAAAAAABAAAAAAAAAAtzZXNzaW9uX2lkcwAAAAPqAAAABAAAAAEAAAPpAAAD7AAAAAQAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAAUtQYWdlIHRocm91Z2ggdGhlIHNlc3Npb25zIGBwbGF5ZXJgIGhhcyB0YWtlbiBwYXJ0IGluLCBuZXdlc3QgZmlyc3QuCgpgY3Vyc29yYCBpcyAwIGZvciB0aGUgZmlyc3QgcGFnZSBhbmQgYG5leHRfY3Vyc29yYCBvZiB0aGUgcHJldmlvdXMKcGFnZSBhZnRlciB0aGF0OyBgbGltaXRgIGlzIDEgdG8gYE1BWF9QQUdFX1NJWkVgLiAgQSBwYWdlIHBpY2tzIHVwCndoZXJlIHRoZSBwcmV2aW91cyBvbmUgc3RvcHBlZCBldmVuIGlmIG5ldyBnYW1lcyB3ZXJlIGluZGV4ZWQgaW4KYmV0d2Vlbjsgc2Vzc2lvbnMgZHJvcHBlZCBmcm9tIHRoZSBpbmRleCBtZWFud2hpbGUgYXJlIHNraXBwZWQuAAAAABBnZXRfcGxheWVyX2dhbWVzAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAH0AAAAAhHYW1lUGFnZQAAAAM=",
+        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAAlDb3JlRXJyb3IAAAA=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAPZ2V0X2RyYXdfcG9saWN5AAAAAAAAAAABAAAH0AAAAApEcmF3UG9saWN5AAA=",
+        "AAAAAAAAAIdTZXQgdGhlIGRyYXcgcG9saWN5IGZvciBnYW1lcyBzdGFydGVkIGZyb20gbm93IG9uLiAgVG91cm5hbWVudCBnYW1lcwpzY29yZSBkcmF3biByb3VuZHMgYXMgYFRydWVEcmF3YCBpbiBwbGFjZSBvZiBlaXRoZXIgcmVwbGF5IHBvbGljeS4AAAAAD3NldF9kcmF3X3BvbGljeQAAAAABAAAAAAAAAAZwb2xpY3kAAAAAB9AAAAAKRHJhd1BvbGljeQAAAAAAAA==",
+        "AAAAAAAAAGJDb25maWd1cmUgdGhlIFVsdHJhSG9uayB2ZXJpZmllciBjb250cmFjdCBhbmQgYGhhbmRzX2NvbW1pdGAgVksgdXNlZApieSBgY29tbWl0X2hhbmRzX3dpdGhfcHJvb2ZgLgAAAAAAEnNldF9oYW5kc192ZXJpZmllcgAAAAAAAgAAAAAAAAAIdmVyaWZpZXIAAAATAAAAAAAAAAJ2awAAAAAADgAAAAA=",
+        "AAAAAAAAAAAAAAAKZ2V0X2VzY3JvdwAAAAAAAAAAAAEAAAPoAAAD7QAAAAIAAAATAAAABA==",
+        "AAAAAAAAAHhTdGFrZSByZWFsIHRva2VucyBpbiBuZXcgZ2FtZXM6IGB0b2tlbmAgaXMgYSBTRVAtNDEgY29udHJhY3QgYW5kCmByYWtlX2Jwc2AgdGhlIGFkbWluJ3MgY3V0IG9mIGVhY2ggcG90IGluIGJhc2lzIHBvaW50cy4AAAAKc2V0X2VzY3JvdwAAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAENSZXR1cm4gdG8gcG9pbnRzLW9ubHkgZ2FtZXMuICBHYW1lcyBhbHJlYWR5IGluIGVzY3JvdyBzdGlsbCBzZXR0bGUuAAAAAAxjbGVhcl9lc2Nyb3cAAAAAAAAAAA==",
+        "AAAAAAAAAIRHZXQgdGhlIGxvYmJ5IGFsbG93ZWQgdG8gY2FsbCBgc3RhcnRfbG9iYnlfZ2FtZWAKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gVGhlIGxvYmJ5IGFkZHJlc3MsIG9yIGBOb25lYCBpZiBub25lIGlzIHJlZ2lzdGVyZWQAAAAJZ2V0X2xvYmJ5AAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAGlSZWdpc3RlciB0aGUgbG9iYnkgYWxsb3dlZCB0byBjYWxsIGBzdGFydF9sb2JieV9nYW1lYAoKIyBBcmd1bWVudHMKKiBgbG9iYnlgIC0gVGhlIGxvYmJ5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAJc2V0X2xvYmJ5AAAAAAAAAQAAAAAAAAAFbG9iYnkAAAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAOZ2V0X3RvdXJuYW1lbnQAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAOc2V0X3RvdXJuYW1lbnQAAAAAAAEAAAAAAAAACnRvdXJuYW1lbnQAAAAAABMAAAAA",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    start_game: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<u32>>,
+        start_lobby_game: this.txFromJSON<Result<void>>,
+        start_tournament_game: this.txFromJSON<Result<u32>>,
+        start_match: this.txFromJSON<Result<u32>>,
         commit_hands: this.txFromJSON<Result<void>>,
+        commit_hands_with_proof: this.txFromJSON<Result<void>>,
         reveal_hands: this.txFromJSON<Result<void>>,
         commit_choice: this.txFromJSON<Result<void>>,
         reveal_choice: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<string>>,
+        request_rematch: this.txFromJSON<Result<void>>,
+        accept_rematch: this.txFromJSON<Result<u32>>,
+        cancel_rematch: this.txFromJSON<Result<void>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_result: this.txFromJSON<Result<GameResult>>,
+        get_game_view: this.txFromJSON<Result<GameView>>,
+        get_games: this.txFromJSON<Result<Map<u32, Game>>>,
+        get_player_games: this.txFromJSON<Result<GamePage>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        get_hub: this.txFromJSON<string>,
+        get_hub: this.txFromJSON<Result<string>>,
         set_hub: this.txFromJSON<null>,
+        get_draw_policy: this.txFromJSON<DrawPolicy>,
+        set_draw_policy: this.txFromJSON<null>,
+        set_hands_verifier: this.txFromJSON<null>,
+        get_escrow: this.txFromJSON<Option<readonly [string, u32]>>,
+        set_escrow: this.txFromJSON<Result<void>>,
+        clear_escrow: this.txFromJSON<null>,
+        get_lobby: this.txFromJSON<Option<string>>,
+        set_lobby: this.txFromJSON<null>,
+        get_tournament: this.txFromJSON<Option<string>>,
+        set_tournament: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
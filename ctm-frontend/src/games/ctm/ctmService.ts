@@ -1,4 +1,4 @@
-import { Client as CtmClient, type Game } from './bindings';
+import { Client as CtmClient, type Game, type GameView } from './bindings';
 import {
   NETWORK_PASSPHRASE,
   RPC_URL,
@@ -168,6 +168,60 @@ export class CtmService {
     }
   }
 
+  /**
+   * The game as `viewer` may see it: no commitment hashes, and the
+   * opponent's hands only once the contract discloses them.
+   */
+  async getGameView(sessionId: number, viewer: string): Promise<GameView | null> {
+    try {
+      const tx = await this.baseClient.get_game_view({ session_id: sessionId, viewer });
+      const result = await tx.simulate();
+      if (result.result.isOk()) return result.result.unwrap();
+      return null;
+    } catch (err) {
+      console.log('[getGameView] Error querying game view:', err);
+      return null;
+    }
+  }
+
+  /**
+   * Token and rake (basis points) new games escrow, or `null` when games
+   * only stake Game Hub points.
+   */
+  async getEscrow(): Promise<{ token: string; rakeBps: number } | null> {
+    try {
+      const tx = await this.baseClient.get_escrow();
+      const result = await tx.simulate();
+      if (!result.result) return null;
+      const [token, rakeBps] = result.result;
+      return { token, rakeBps };
+    } catch (err) {
+      console.log('[getEscrow] Error querying escrow:', err);
+      return null;
+    }
+  }
+
+  /**
+   * One page of `player`'s sessions, newest first.  Pass the returned
+   * `nextCursor` to fetch the following page; it is `null` at the end.
+   */
+  async getPlayerGames(
+    player: string,
+    cursor = 0,
+    limit = 10,
+  ): Promise<{ games: Array<readonly [number, Game]>; nextCursor: number | null } | null> {
+    try {
+      const tx = await this.baseClient.get_player_games({ player, cursor, limit });
+      const result = await tx.simulate();
+      if (!result.result.isOk()) return null;
+      const page = result.result.unwrap();
+      return { games: page.games, nextCursor: page.next_cursor ?? null };
+    } catch (err) {
+      console.log('[getPlayerGames] Error querying player games:', err);
+      return null;
+    }
+  }
+
   // ------------------------------------------------------------------
   // Start game (multi-sig)
   // ------------------------------------------------------------------
@@ -197,6 +251,20 @@ export class CtmService {
   // Multi-sig helpers (prepare → import → finalize)
   // ------------------------------------------------------------------
 
+  /**
+   * Single games go through `start_game`; best-of-N matches through
+   * `start_match`, whose auth entries also cover `best_of`.
+   */
+  private buildStartTx(
+    client: CtmClient,
+    args: { session_id: number; player1: string; player2: string; player1_points: bigint; player2_points: bigint },
+    bestOf: number,
+  ) {
+    return bestOf === 1
+      ? client.start_game(args, DEFAULT_METHOD_OPTIONS)
+      : client.start_match({ ...args, best_of: bestOf }, DEFAULT_METHOD_OPTIONS);
+  }
+
   async prepareStartGame(
     sessionId: number,
     player1: string,
@@ -205,6 +273,7 @@ export class CtmService {
     player2Points: bigint,
     player1Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number,
+    bestOf = 1,
   ): Promise<string> {
     const buildClient = new CtmClient({
       contractId: this.contractId,
@@ -212,9 +281,10 @@ export class CtmService {
       rpcUrl: RPC_URL,
       publicKey: player2,
     });
-    const tx = await buildClient.start_game(
+    const tx = await this.buildStartTx(
+      buildClient,
       { session_id: sessionId, player1, player2, player1_points: player1Points, player2_points: player2Points },
-      DEFAULT_METHOD_OPTIONS,
+      bestOf,
     );
 
     if (!tx.simulationData?.result?.auth) throw new Error('No auth entries found in simulation');
@@ -256,13 +326,15 @@ export class CtmService {
     const player1 = Address.fromScAddress(creds.address()).toString();
     const rootFn = authEntry.rootInvocation().function().contractFn();
     const functionName = rootFn.functionName().toString();
-    if (functionName !== 'start_game') throw new Error(`Unexpected function: ${functionName}`);
+    if (functionName !== 'start_game' && functionName !== 'start_match') throw new Error(`Unexpected function: ${functionName}`);
     const args = rootFn.args();
-    if (args.length !== 2) throw new Error(`Expected 2 args, got ${args.length}`);
+    const expected = functionName === 'start_match' ? 3 : 2;
+    if (args.length !== expected) throw new Error(`Expected ${expected} args, got ${args.length}`);
     return {
       sessionId: args[0].u32(),
       player1,
       player1Points: args[1].i128().lo().toBigInt(),
+      bestOf: functionName === 'start_match' ? args[2].u32() : 1,
       functionName,
     };
   }
@@ -283,7 +355,8 @@ export class CtmService {
       rpcUrl: RPC_URL,
       publicKey: player2Address,
     });
-    const tx = await buildClient.start_game(
+    const tx = await this.buildStartTx(
+      buildClient,
       {
         session_id: params.sessionId,
         player1: params.player1,
@@ -291,7 +364,7 @@ export class CtmService {
         player1_points: params.player1Points,
         player2_points: player2Points,
       },
-      DEFAULT_METHOD_OPTIONS,
+      params.bestOf,
     );
 
     const validUntil = authTtlMinutes
@@ -320,7 +393,7 @@ export class CtmService {
     signerAddress: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number,
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(signerAddress, signer);
     const tx = client.txFromXDR(txXdr);
     await tx.simulate();
@@ -328,7 +401,8 @@ export class CtmService {
       ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
       : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
     const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntil);
-    return sentTx.result;
+    // start_game and start_match return the session ID the game was stored under
+    return (sentTx.result as { unwrap(): number }).unwrap();
   }
 
   parseTransactionXDR(txXdr: string) {
@@ -338,15 +412,17 @@ export class CtmService {
     if (!op || op.type !== 'invokeHostFunction') throw new Error('Not a contract invocation');
     const invokeArgs = op.func.invokeContract();
     const functionName = invokeArgs.functionName().toString();
-    if (functionName !== 'start_game') throw new Error(`Unexpected function: ${functionName}`);
+    if (functionName !== 'start_game' && functionName !== 'start_match') throw new Error(`Unexpected function: ${functionName}`);
     const args = invokeArgs.args();
-    if (args.length !== 5) throw new Error(`Expected 5 args, got ${args.length}`);
+    const expected = functionName === 'start_match' ? 6 : 5;
+    if (args.length !== expected) throw new Error(`Expected ${expected} args, got ${args.length}`);
     return {
       sessionId: args[0].u32(),
       player1: StrKey.encodeEd25519PublicKey(args[1].address().accountId().ed25519()),
       player2: StrKey.encodeEd25519PublicKey(args[2].address().accountId().ed25519()),
       player1Points: args[3].i128().lo().toBigInt(),
       player2Points: args[4].i128().lo().toBigInt(),
+      bestOf: functionName === 'start_match' ? args[5].u32() : 1,
       transactionSource,
       functionName,
     };
@@ -396,6 +472,30 @@ export class CtmService {
     );
   }
 
+  /**
+   * Commit hands together with a `hands_commit` UltraHonk proof produced
+   * off-chain (see contracts/ctm/noir/hands_commit).
+   */
+  async commitHandsWithProof(
+    sessionId: number,
+    playerAddress: string,
+    handsHash: Buffer,
+    proof: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number,
+  ) {
+    return this.sendGameAction(
+      (c) =>
+        c.commit_hands_with_proof(
+          { session_id: sessionId, player: playerAddress, hands_hash: handsHash, proof },
+          DEFAULT_METHOD_OPTIONS,
+        ),
+      playerAddress,
+      signer,
+      authTtlMinutes,
+    );
+  }
+
   async revealHands(
     sessionId: number,
     playerAddress: string,
@@ -452,6 +552,73 @@ export class CtmService {
     );
   }
 
+  /**
+   * Claim the win once the opponent has stalled past `game.deadline`.
+   * The contract rejects the call while the deadline is still open.
+   */
+  async claimTimeout(
+    sessionId: number,
+    claimant: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number,
+  ) {
+    return this.sendGameAction(
+      (c) => c.claim_timeout({ session_id: sessionId, claimant }, DEFAULT_METHOD_OPTIONS),
+      claimant,
+      signer,
+      authTtlMinutes,
+    );
+  }
+
+  // ------------------------------------------------------------------
+  // Rematch
+  // ------------------------------------------------------------------
+
+  async requestRematch(
+    sessionId: number,
+    playerAddress: string,
+    swap: boolean,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number,
+  ) {
+    return this.sendGameAction(
+      (c) => c.request_rematch({ session_id: sessionId, player: playerAddress, swap }, DEFAULT_METHOD_OPTIONS),
+      playerAddress,
+      signer,
+      authTtlMinutes,
+    );
+  }
+
+  /** Accept the opponent's offer; resolves to the new session ID. */
+  async acceptRematch(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number,
+  ): Promise<number> {
+    const result = await this.sendGameAction(
+      (c) => c.accept_rematch({ session_id: sessionId, player: playerAddress }, DEFAULT_METHOD_OPTIONS),
+      playerAddress,
+      signer,
+      authTtlMinutes,
+    );
+    return result.unwrap();
+  }
+
+  async cancelRematch(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number,
+  ) {
+    return this.sendGameAction(
+      (c) => c.cancel_rematch({ session_id: sessionId }, DEFAULT_METHOD_OPTIONS),
+      playerAddress,
+      signer,
+      authTtlMinutes,
+    );
+  }
+
   // ------------------------------------------------------------------
   // Error extraction
   // ------------------------------------------------------------------
@@ -301,8 +301,10 @@ for (const contract of contracts) {
   console.log(`Deploying ${contract.packageName}...`);
   try {
     console.log("  Deploying and initializing...");
-    const deployResult =
-      await $`stellar contract deploy --wasm ${contract.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
+    // The Game Hub only takes an admin; games are approved with `add_game` after deploy.
+    const deployResult = contract.isGameHub
+      ? await $`stellar contract deploy --wasm ${contract.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text()
+      : await $`stellar contract deploy --wasm ${contract.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
@@ -9,6 +9,7 @@ export type ContractInfo = {
   envKey: string;
   bindingsOutDir: string;
   isMockHub: boolean;
+  isGameHub: boolean;
 };
 
 export type ContractSelection = {
@@ -65,6 +66,7 @@ export async function getWorkspaceContracts(): Promise<ContractInfo[]> {
       envKey,
       bindingsOutDir: `bindings/${wasmName}`,
       isMockHub: packageName === "mock-game-hub" || wasmName === "mock_game_hub",
+      isGameHub: packageName === "game-hub" || wasmName === "game_hub",
     });
   }
 
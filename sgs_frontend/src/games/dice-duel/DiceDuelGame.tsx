@@ -675,7 +675,7 @@ export function DiceDuelGame({
 
         // Step 2: Player 2 finalizes and submits (they are the transaction source)
         console.log('Simulating and submitting transaction...');
-        await diceDuelService.finalizeStartGame(
+        const startedSessionId = await diceDuelService.finalizeStartGame(
           fullySignedTxXDR,
           userAddress,
           signer
@@ -683,7 +683,7 @@ export function DiceDuelGame({
 
         // If we get here, transaction succeeded! Now update state.
         console.log('Transaction submitted successfully! Updating state...');
-        setSessionId(gameParams.sessionId);
+        setSessionId(startedSessionId);
         setSuccess('Game created successfully! Both players signed.');
         setGamePhase('roll');
 
@@ -768,7 +768,7 @@ export function DiceDuelGame({
         } else if (game.player1_rolled && game.player2_rolled) {
           // Both players rolled, waiting for reveal
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have rolled. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have rolled. Reveal your secret to settle the dice.');
         } else {
           // Still in rolling phase
           setGamePhase('roll');
@@ -861,6 +861,49 @@ export function DiceDuelGame({
     });
   };
 
+  const handleRevealRoll = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await diceDuelService.revealRoll(sessionId, userAddress, signer);
+
+        setSuccess('Secret revealed! Waiting for the other player...');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal roll error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal roll');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleClaimTimeout = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await diceDuelService.claimTimeout(sessionId, userAddress, signer);
+
+        setSuccess('🎉 Opponent timed out - you won!');
+        await loadGameState();
+        onStandingsRefresh();
+      } catch (err) {
+        console.error('Claim timeout error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to claim timeout');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await diceDuelService.getGame(sessionId);
     let attempts = 0;
@@ -909,6 +952,8 @@ export function DiceDuelGame({
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
   const hasRolled = isPlayer1 ? !!gameState?.player1_rolled : isPlayer2 ? !!gameState?.player2_rolled : false;
+  const hasRevealed = isPlayer1 ? !!gameState?.player1_secret : isPlayer2 ? !!gameState?.player2_secret : false;
+  const bothRevealed = !!gameState?.player1_secret && !!gameState?.player2_secret;
 
   const player1Dice = [gameState?.player1_die1 ?? null, gameState?.player1_die2 ?? null];
   const player2Dice = [gameState?.player2_die1 ?? null, gameState?.player2_die2 ?? null];
@@ -1404,10 +1449,17 @@ export function DiceDuelGame({
           )}
 
           {hasRolled && (
-            <div className="p-4 bg-gradient-to-r from-amber-50 to-rose-50 border-2 border-amber-200 rounded-xl">
+            <div className="p-4 bg-gradient-to-r from-amber-50 to-rose-50 border-2 border-amber-200 rounded-xl space-y-3">
               <p className="text-sm font-semibold text-amber-800">
-                ✓ You've rolled. Waiting for the other player...
+                ✓ You've rolled. Waiting for the other player (deadline: ledger {gameState.commit_deadline})...
               </p>
+              <button
+                onClick={handleClaimTimeout}
+                disabled={isBusy}
+                className="px-6 py-2 rounded-lg font-bold text-white bg-rose-600 hover:bg-rose-700 disabled:bg-gray-400 transition-all"
+              >
+                Claim win after deadline
+              </button>
             </div>
           )}
         </div>
@@ -1422,7 +1474,9 @@ export function DiceDuelGame({
               Both Players Rolled!
             </h3>
             <p className="text-sm font-semibold text-gray-700 mb-6">
-              The house is ready. Reveal the dice.
+              {bothRevealed
+                ? 'Both secrets are in. Reveal the dice.'
+                : 'Reveal your roll secret - the dice are seeded from both players\' secrets.'}
             </p>
             <div className="flex items-center justify-center gap-4 mb-6 dice-tray">
               <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled />
@@ -1430,13 +1484,38 @@ export function DiceDuelGame({
               <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled />
               <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled />
             </div>
-            <button
-              onClick={handleRevealWinner}
-              disabled={isBusy}
-              className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-amber-500 via-orange-500 to-rose-500 hover:from-amber-600 hover:via-orange-600 hover:to-rose-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
-            >
-              {loading ? 'Revealing...' : 'Reveal Winner'}
-            </button>
+            {(isPlayer1 || isPlayer2) && !hasRevealed && (
+              <button
+                onClick={handleRevealRoll}
+                disabled={isBusy}
+                className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-amber-500 via-orange-500 to-rose-500 hover:from-amber-600 hover:via-orange-600 hover:to-rose-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal My Roll'}
+              </button>
+            )}
+            {hasRevealed && !bothRevealed && (
+              <div className="space-y-3">
+                <p className="text-sm font-semibold text-amber-800">
+                  ✓ Secret revealed. Waiting for the other player (deadline: ledger {gameState.reveal_deadline})...
+                </p>
+                <button
+                  onClick={handleClaimTimeout}
+                  disabled={isBusy}
+                  className="px-6 py-2 rounded-lg font-bold text-white bg-rose-600 hover:bg-rose-700 disabled:bg-gray-400 transition-all"
+                >
+                  Claim win after deadline
+                </button>
+              </div>
+            )}
+            {bothRevealed && (
+              <button
+                onClick={handleRevealWinner}
+                disabled={isBusy}
+                className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-amber-500 via-orange-500 to-rose-500 hover:from-amber-600 hover:via-orange-600 hover:to-rose-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal Winner'}
+              </button>
+            )}
           </div>
         </div>
       )}
@@ -59,16 +59,30 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Ledger sequence after which a player who rolled can claim the win
+   * against one who did not; set when the game starts
+   */
+  commit_deadline: u32;
   player1: string;
+  player1_commit: Option<Buffer>;
   player1_die1: Option<u32>;
   player1_die2: Option<u32>;
   player1_points: i128;
   player1_rolled: boolean;
+  player1_secret: Option<Buffer>;
   player2: string;
+  player2_commit: Option<Buffer>;
   player2_die1: Option<u32>;
   player2_die2: Option<u32>;
   player2_points: i128;
   player2_rolled: boolean;
+  player2_secret: Option<Buffer>;
+  /**
+   * Ledger sequence after which a player who revealed can claim the
+   * win; 0 until both players have rolled
+   */
+  reveal_deadline: u32;
   winner: Option<string>;
 }
 
@@ -77,22 +91,64 @@ export const Errors = {
   2: {message:"NotPlayer"},
   3: {message:"AlreadyRolled"},
   4: {message:"BothPlayersNotRolled"},
-  5: {message:"GameAlreadyEnded"}
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"HashMismatch"},
+  7: {message:"AlreadyRevealed"},
+  8: {message:"BothPlayersNotRevealed"},
+  9: {message:"SelfPlay"},
+  10: {message:"HubNotSet"},
+  11: {message:"SessionExists"},
+  12: {message:"DeadlineNotReached"},
+  13: {message:"CannotClaimTimeout"},
+  14: {message:"LobbyNotSet"},
+  100: {message:"SelfPlay"},
+  101: {message:"HubNotSet"},
+  102: {message:"SessionExists"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
-
 export interface Client {
   /**
    * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Commit a roll for the current game.
-   * Both players must roll before the winner can be revealed.
+   * Both players must roll before secrets can be revealed.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * * `player` - Address of the player rolling the dice
+   * * `secret_hash` - keccak256 of the player's 32-byte secret
+   */
+  roll: ({session_id, player, secret_hash}: {session_id: u32, player: string, secret_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the secret committed in `roll`.
+   * Only allowed once both players have rolled, so the first secret
+   * disclosed cannot influence the other player's commitment.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `secret` - The secret whose keccak256 was committed
    */
-  roll: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  reveal_roll: ({session_id, player, secret}: {session_id: u32, player: string, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the win after the opponent fails to roll or reveal in time.
+   * 
+   * Until both players have rolled, only allowed once the ledger sequence
+   * is past `commit_deadline` and only by a player who has rolled against
+   * one who has not.  After that, the same applies to revealing with
+   * `reveal_deadline`.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `claimant` - Address of the player who rolled or revealed
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player (the claimant)
+   */
+  claim_timeout: ({session_id, claimant}: {session_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -101,7 +157,7 @@ export interface Client {
    * # Returns
    * * `Address` - The GameHub contract address
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -121,6 +177,24 @@ export interface Client {
    */
   upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the lobby allowed to call `start_lobby_game`
+   * 
+   * # Returns
+   * * `Option<Address>` - The lobby address, or `None` if none is registered
+   */
+  get_lobby: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the lobby allowed to call `start_lobby_game`
+   * 
+   * # Arguments
+   * * `lobby` - The lobby contract address
+   */
+  set_lobby: ({lobby}: {lobby: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get game information.
@@ -133,6 +207,18 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get whether a game is complete and who won, without the full state.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `GameResult` - Complete once the dice have been rolled or a timeout claimed
+   */
+  get_result: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameResult>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -160,18 +246,36 @@ export interface Client {
    * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
+   * * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
    * * `player1` - Address of first player
    * * `player2` - Address of second player
    * * `player1_points` - Points amount committed by player 1
    * * `player2_points` - Points amount committed by player 2
+   * 
+   * # Returns
+   * * `u32` - The session ID of the new game
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_lobby_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game on behalf of the registered lobby.
+   * 
+   * Only the lobby's auth is required: the players already authorized
+   * their stakes when posting and accepting the challenge there.  The
+   * lobby holds those stakes, so no points are locked.
+   * 
+   * # Arguments
+   * * `session_id` - Session ID the lobby keyed the challenge by
+   * * `player1` - Address of the challenge's host
+   * * `player2` - Address of the player who accepted
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_lobby_game: ({session_id, player1, player2}: {session_id: u32, player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have rolled.
+   * Can only be called after both players have revealed their secrets.
    * This generates dice rolls for both players, determines the winner, and ends the session.
    * 
    * # Arguments
@@ -202,31 +306,43 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAU=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAMpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlAAAAAAAEcm9sbAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAARAAAAc0xlZGdlciBzZXF1ZW5jZSBhZnRlciB3aGljaCBhIHBsYXllciB3aG8gcm9sbGVkIGNhbiBjbGFpbSB0aGUgd2luCmFnYWluc3Qgb25lIHdobyBkaWQgbm90OyBzZXQgd2hlbiB0aGUgZ2FtZSBzdGFydHMAAAAAD2NvbW1pdF9kZWFkbGluZQAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfY29tbWl0AAAAAAPoAAAD7gAAACAAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAA5wbGF5ZXIxX3NlY3JldAAAAAAD6AAAA+4AAAAgAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfY29tbWl0AAAAAAPoAAAD7gAAACAAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAA5wbGF5ZXIyX3NlY3JldAAAAAAD6AAAA+4AAAAgAAAAZUxlZGdlciBzZXF1ZW5jZSBhZnRlciB3aGljaCBhIHBsYXllciB3aG8gcmV2ZWFsZWQgY2FuIGNsYWltIHRoZQp3aW47IDAgdW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkAAAAAAAAD3JldmVhbF9kZWFkbGluZQAAAAAEAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAABAAAAJtFcnJvcnMgcmFpc2VkIGJ5IHRoZSBzaGFyZWQgaGVscGVycy4KCkNvZGVzIHN0YXJ0IGF0IDEwMCBzbyB0aGV5IG5ldmVyIGNsYXNoIHdpdGggYSBnYW1lJ3Mgb3duIGNvZGVzIHdoZW4KcmV0dXJuZWQgZGlyZWN0bHksIGFzIHRoZSBbYEFkbWluYF0gZnVuY3Rpb25zIGRvLgAAAAAAAAAACUNvcmVFcnJvcgAAAAAAAAMAAAAAAAAACFNlbGZQbGF5AAAAZAAAAAAAAAAJSHViTm90U2V0AAAAAAAAZQAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAGY=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAADEhhc2hNaXNtYXRjaAAAAAYAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAHAAAAAAAAABZCb3RoUGxheWVyc05vdFJldmVhbGVkAAAAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAACUh1Yk5vdFNldAAAAAAAAAoAAAAAAAAADVNlc3Npb25FeGlzdHMAAAAAAAALAAAAAAAAABJEZWFkbGluZU5vdFJlYWNoZWQAAAAAAAwAAAAAAAAAEkNhbm5vdENsYWltVGltZW91dAAAAAAADQAAAAAAAAALTG9iYnlOb3RTZXQAAAAADg==",
+        "AAAAAAAAAQJDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSBzZWNyZXRzIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlCiogYHNlY3JldF9oYXNoYCAtIGtlY2NhazI1NiBvZiB0aGUgcGxheWVyJ3MgMzItYnl0ZSBzZWNyZXQAAAAAAARyb2xsAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtzZWNyZXRfaGFzaAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAATxSZXZlYWwgdGhlIHNlY3JldCBjb21taXR0ZWQgaW4gYHJvbGxgLgpPbmx5IGFsbG93ZWQgb25jZSBib3RoIHBsYXllcnMgaGF2ZSByb2xsZWQsIHNvIHRoZSBmaXJzdCBzZWNyZXQKZGlzY2xvc2VkIGNhbm5vdCBpbmZsdWVuY2UgdGhlIG90aGVyIHBsYXllcidzIGNvbW1pdG1lbnQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSByZXZlYWxpbmcgcGxheWVyCiogYHNlY3JldGAgLSBUaGUgc2VjcmV0IHdob3NlIGtlY2NhazI1NiB3YXMgY29tbWl0dGVkAAAAC3JldmVhbF9yb2xsAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGc2VjcmV0AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAd1DbGFpbSB0aGUgd2luIGFmdGVyIHRoZSBvcHBvbmVudCBmYWlscyB0byByb2xsIG9yIHJldmVhbCBpbiB0aW1lLgoKVW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkLCBvbmx5IGFsbG93ZWQgb25jZSB0aGUgbGVkZ2VyIHNlcXVlbmNlCmlzIHBhc3QgYGNvbW1pdF9kZWFkbGluZWAgYW5kIG9ubHkgYnkgYSBwbGF5ZXIgd2hvIGhhcyByb2xsZWQgYWdhaW5zdApvbmUgd2hvIGhhcyBub3QuICBBZnRlciB0aGF0LCB0aGUgc2FtZSBhcHBsaWVzIHRvIHJldmVhbGluZyB3aXRoCmByZXZlYWxfZGVhZGxpbmVgLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBjbGFpbWFudGAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgd2hvIHJvbGxlZCBvciByZXZlYWxlZAoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIgKHRoZSBjbGFpbWFudCkAAAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAAlDb3JlRXJyb3IAAAA=",
         "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAIRHZXQgdGhlIGxvYmJ5IGFsbG93ZWQgdG8gY2FsbCBgc3RhcnRfbG9iYnlfZ2FtZWAKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gVGhlIGxvYmJ5IGFkZHJlc3MsIG9yIGBOb25lYCBpZiBub25lIGlzIHJlZ2lzdGVyZWQAAAAJZ2V0X2xvYmJ5AAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAGlSZWdpc3RlciB0aGUgbG9iYnkgYWxsb3dlZCB0byBjYWxsIGBzdGFydF9sb2JieV9nYW1lYAoKIyBBcmd1bWVudHMKKiBgbG9iYnlgIC0gVGhlIGxvYmJ5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAJc2V0X2xvYmJ5AAAAAAAAAQAAAAAAAAAFbG9iYnkAAAAAAAATAAAAAA==",
         "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAANVHZXQgd2hldGhlciBhIGdhbWUgaXMgY29tcGxldGUgYW5kIHdobyB3b24sIHdpdGhvdXQgdGhlIGZ1bGwgc3RhdGUuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgR2FtZVJlc3VsdGAgLSBDb21wbGV0ZSBvbmNlIHRoZSBkaWNlIGhhdmUgYmVlbiByb2xsZWQgb3IgYSB0aW1lb3V0IGNsYWltZWQAAAAAAAAKZ2V0X3Jlc3VsdAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAApHYW1lUmVzdWx0AAAAAAAD",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAoVTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMiksIG9yIDAgdG8gbGV0IHRoZSBHYW1lIEh1YiBhbGxvY2F0ZSBvbmUKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgbmV3IGdhbWUAAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAZRTdGFydCBhIG5ldyBnYW1lIG9uIGJlaGFsZiBvZiB0aGUgcmVnaXN0ZXJlZCBsb2JieS4KCk9ubHkgdGhlIGxvYmJ5J3MgYXV0aCBpcyByZXF1aXJlZDogdGhlIHBsYXllcnMgYWxyZWFkeSBhdXRob3JpemVkCnRoZWlyIHN0YWtlcyB3aGVuIHBvc3RpbmcgYW5kIGFjY2VwdGluZyB0aGUgY2hhbGxlbmdlIHRoZXJlLiAgVGhlCmxvYmJ5IGhvbGRzIHRob3NlIHN0YWtlcywgc28gbm8gcG9pbnRzIGFyZSBsb2NrZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFNlc3Npb24gSUQgdGhlIGxvYmJ5IGtleWVkIHRoZSBjaGFsbGVuZ2UgYnkKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIHRoZSBjaGFsbGVuZ2UncyBob3N0CiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHdobyBhY2NlcHRlZAAAABBzdGFydF9sb2JieV9nYW1lAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAThSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZC4KVGhpcyBnZW5lcmF0ZXMgZGljZSByb2xscyBmb3IgYm90aCBwbGF5ZXJzLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD" ]),
+        "AAAAAAAAAUhSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIHNlY3JldHMuClRoaXMgZ2VuZXJhdGVzIGRpY2Ugcm9sbHMgZm9yIGJvdGggcGxheWVycywgZGV0ZXJtaW5lcyB0aGUgd2lubmVyLCBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
       options
     )
   }
   public readonly fromJSON = {
     roll: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
+        reveal_roll: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<string>>,
+        get_hub: this.txFromJSON<Result<string>>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
+        get_lobby: this.txFromJSON<Option<string>>,
+        set_lobby: this.txFromJSON<null>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_result: this.txFromJSON<Result<GameResult>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
+        start_game: this.txFromJSON<Result<u32>>,
+        start_lobby_game: this.txFromJSON<Result<void>>,
         reveal_winner: this.txFromJSON<Result<string>>
   }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ import { Client as DiceDuelClient, type Game } from './bindings';
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, TransactionBuilder, StrKey, xdr, Address, authorizeEntry } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
+import { keccak_256 } from '@noble/hashes/sha3';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
@@ -449,7 +450,7 @@ export class DiceDuelService {
     signerAddress: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(signerAddress, signer);
 
     // Import the transaction with all auth entries signed
@@ -469,7 +470,8 @@ export class DiceDuelService {
       DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
       validUntilLedgerSeq
     );
-    return sentTx.result;
+    // start_game returns the session ID the game was stored under
+    return (sentTx.result as { unwrap(): number }).unwrap();
   }
 
   /**
@@ -588,6 +590,7 @@ export class DiceDuelService {
     const tx = await client.roll({
       session_id: sessionId,
       player: playerAddress,
+      secret_hash: Buffer.from(keccak_256(this.rollSecret(sessionId, playerAddress))),
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
@@ -613,7 +616,105 @@ export class DiceDuelService {
   }
 
   /**
-   * Reveal the winner after both players have rolled
+   * Reveal the secret committed in `roll`, once both players have rolled
+   */
+  async revealRoll(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const key = this.secretKey(sessionId, playerAddress);
+    const stored = localStorage.getItem(key);
+    if (!stored) {
+      throw new Error('No saved roll secret for this game - was it rolled from another browser?');
+    }
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_roll({
+      session_id: sessionId,
+      player: playerAddress,
+      secret: Buffer.from(stored, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check that both players have rolled and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
+  /**
+   * Claim the win after the opponent missed the roll or reveal deadline
+   */
+  async claimTimeout(
+    sessionId: number,
+    claimant: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const client = this.createSigningClient(claimant, signer);
+    const tx = await client.claim_timeout({
+      session_id: sessionId,
+      claimant,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - the roll or reveal deadline may not have passed yet');
+      }
+      throw err;
+    }
+  }
+
+  private secretKey(sessionId: number, player: string): string {
+    return `dice-duel-secret:${sessionId}:${player}`;
+  }
+
+  /**
+   * The player's 32-byte roll secret for a session, generated and kept in
+   * localStorage on first use so it survives a reload before `reveal_roll`
+   */
+  private rollSecret(sessionId: number, player: string): Uint8Array {
+    const key = this.secretKey(sessionId, player);
+    const stored = localStorage.getItem(key);
+    if (stored) {
+      return Buffer.from(stored, 'hex');
+    }
+    const secret = crypto.getRandomValues(new Uint8Array(32));
+    localStorage.setItem(key, Buffer.from(secret).toString('hex'));
+    return secret;
+  }
+
+  /**
+   * Reveal the winner after both players have revealed their roll secrets
    */
   async revealWinner(
     sessionId: number,
@@ -649,7 +750,7 @@ export class DiceDuelService {
 
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
         // This is the SDK error when trying to access .result on a failed transaction
-        throw new Error('Transaction failed - check if both players have rolled and the game is still active');
+        throw new Error('Transaction failed - check if both players have revealed and the game is still active');
       }
 
       throw err;
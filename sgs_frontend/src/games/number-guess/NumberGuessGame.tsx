@@ -122,10 +122,10 @@ export function NumberGuessGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && ((game.winner !== null && game.winner !== undefined) || game.forfeited)) {
         setGamePhase('complete');
-      } else if (game && game.player1_guess !== null && game.player1_guess !== undefined &&
-                 game.player2_guess !== null && game.player2_guess !== undefined) {
+      } else if (game && game.player1_commit !== null && game.player1_commit !== undefined &&
+                 game.player2_commit !== null && game.player2_commit !== undefined) {
         setGamePhase('reveal');
       } else {
         setGamePhase('guess');
@@ -605,7 +605,7 @@ export function NumberGuessGame({
 
         // Step 2: Player 2 finalizes and submits (they are the transaction source)
         console.log('Simulating and submitting transaction...');
-        await numberGuessService.finalizeStartGame(
+        const startedSessionId = await numberGuessService.finalizeStartGame(
           fullySignedTxXDR,
           userAddress,
           signer
@@ -613,7 +613,7 @@ export function NumberGuessGame({
 
         // If we get here, transaction succeeded! Now update state.
         console.log('Transaction submitted successfully! Updating state...');
-        setSessionId(gameParams.sessionId);
+        setSessionId(startedSessionId);
         setSuccess('Game created successfully! Both players signed.');
         setGamePhase('guess');
 
@@ -690,16 +690,19 @@ export function NumberGuessGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
+        if (game.forfeited) {
+          setGamePhase('complete');
+          setSuccess('Game complete. Neither player revealed in time - stakes refunded.');
+        } else if (game.winner !== null && game.winner !== undefined) {
           // Game is complete - show reveal phase with winner
           setGamePhase('reveal');
           const isWinner = game.winner === userAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_guess !== null && game.player1_guess !== undefined &&
-            game.player2_guess !== null && game.player2_guess !== undefined) {
+        } else if (game.player1_commit !== null && game.player1_commit !== undefined &&
+            game.player2_commit !== null && game.player2_commit !== undefined) {
           // Both players guessed, waiting for reveal
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have guessed. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have guessed. Reveal your guess to settle the round.');
         } else {
           // Still in guessing phase
           setGamePhase('guess');
@@ -780,7 +783,7 @@ export function NumberGuessGame({
         const signer = getContractSigner();
         await numberGuessService.makeGuess(sessionId, userAddress, guess, signer);
 
-        setSuccess(`Guess submitted: ${guess}`);
+        setSuccess(`Guess committed: ${guess} (hidden until both players reveal)`);
         await loadGameState();
       } catch (err) {
         console.error('Make guess error:', err);
@@ -791,6 +794,49 @@ export function NumberGuessGame({
     });
   };
 
+  const handleRevealGuess = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await numberGuessService.revealGuess(sessionId, userAddress, signer);
+
+        setSuccess('Guess revealed! Waiting for the other player...');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal guess error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal guess');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleClaimTimeout = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await numberGuessService.claimTimeout(sessionId, userAddress, signer);
+
+        setSuccess('Timed-out game settled.');
+        await loadGameState();
+        onStandingsRefresh();
+      } catch (err) {
+        console.error('Claim timeout error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to claim timeout');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await numberGuessService.getGame(sessionId);
     let attempts = 0;
@@ -836,8 +882,12 @@ export function NumberGuessGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasGuessed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
-                     isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  const hasGuessed = isPlayer1 ? gameState?.player1_commit !== null && gameState?.player1_commit !== undefined :
+                     isPlayer2 ? gameState?.player2_commit !== null && gameState?.player2_commit !== undefined : false;
+  const hasRevealed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
+                      isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  const bothRevealed = gameState?.player1_guess !== null && gameState?.player1_guess !== undefined &&
+                       gameState?.player2_guess !== null && gameState?.player2_guess !== undefined;
 
   const winningNumber = gameState?.winning_number;
   const player1Guess = gameState?.player1_guess;
@@ -1231,7 +1281,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player1_guess !== null && gameState.player1_guess !== undefined ? (
+                {gameState.player1_commit !== null && gameState.player1_commit !== undefined ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1252,7 +1302,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player2_guess !== null && gameState.player2_guess !== undefined ? (
+                {gameState.player2_commit !== null && gameState.player2_commit !== undefined ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1302,6 +1352,21 @@ export function NumberGuessGame({
               </p>
             </div>
           )}
+
+          {(isPlayer1 || isPlayer2) && (
+            <div className="space-y-2">
+              <p className="text-xs font-semibold text-gray-600">
+                Guess deadline: ledger {gameState.commit_deadline}. After it, either player can settle the game.
+              </p>
+              <button
+                onClick={handleClaimTimeout}
+                disabled={isBusy}
+                className="px-6 py-2 rounded-lg font-bold text-white bg-red-500 hover:bg-red-600 disabled:bg-gray-400 transition-all"
+              >
+                Settle after deadline
+              </button>
+            </div>
+          )}
         </div>
       )}
 
@@ -1314,15 +1379,45 @@ export function NumberGuessGame({
               Both Players Have Guessed!
             </h3>
             <p className="text-sm font-semibold text-gray-700 mb-6">
-              Click below to reveal the winner
+              {bothRevealed ? 'Click below to reveal the winner' : 'Reveal your committed guess'}
             </p>
-            <button
-              onClick={handleRevealWinner}
-              disabled={isBusy}
-              className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
-            >
-              {loading ? 'Revealing...' : 'Reveal Winner'}
-            </button>
+            {(isPlayer1 || isPlayer2) && !hasRevealed && (
+              <button
+                onClick={handleRevealGuess}
+                disabled={isBusy}
+                className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal My Guess'}
+              </button>
+            )}
+            {hasRevealed && !bothRevealed && (
+              <p className="text-sm font-semibold text-blue-700">
+                ✓ Guess revealed. Waiting for the other player...
+              </p>
+            )}
+            {(isPlayer1 || isPlayer2) && !bothRevealed && (
+              <div className="mt-4 space-y-2">
+                <p className="text-xs font-semibold text-gray-600">
+                  Reveal deadline: ledger {gameState.reveal_deadline}. After it, either player can settle the game.
+                </p>
+                <button
+                  onClick={handleClaimTimeout}
+                  disabled={isBusy}
+                  className="px-6 py-2 rounded-lg font-bold text-white bg-red-500 hover:bg-red-600 disabled:bg-gray-400 transition-all"
+                >
+                  Settle after deadline
+                </button>
+              </div>
+            )}
+            {bothRevealed && (
+              <button
+                onClick={handleRevealWinner}
+                disabled={isBusy}
+                className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal Winner'}
+              </button>
+            )}
           </div>
         </div>
       )}
@@ -1335,9 +1430,11 @@ export function NumberGuessGame({
             <h3 className="text-3xl font-black text-gray-900 mb-4">
               Game Complete!
             </h3>
-            <div className="text-2xl font-black text-green-700 mb-6">
-              Winning Number: {gameState.winning_number}
-            </div>
+            {!gameState.forfeited && (
+              <div className="text-2xl font-black text-green-700 mb-6">
+                Winning Number: {gameState.winning_number}
+              </div>
+            )}
             <div className="space-y-3 mb-6">
               <div className="p-4 bg-white/70 border border-green-200 rounded-xl">
                 <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-1">Player 1</p>
@@ -1361,6 +1458,13 @@ export function NumberGuessGame({
                 </p>
               </div>
             </div>
+            {gameState.forfeited && (
+              <div className="mt-6 p-5 bg-white border-2 border-green-200 rounded-xl shadow-lg">
+                <p className="text-sm font-bold text-gray-800">
+                  Neither player revealed in time - the game was drawn and both stakes refunded.
+                </p>
+              </div>
+            )}
             {gameState.winner && (
               <div className="mt-6 p-5 bg-white border-2 border-green-200 rounded-xl shadow-lg">
                 <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-2">Winner</p>
@@ -59,12 +59,33 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Ledger sequence after which `claim_timeout` can settle a game still
+   * missing a commitment; set when the game starts
+   */
+  commit_deadline: u32;
+  /**
+   * Neither player committed or revealed in time and the session was drawn
+   */
+  forfeited: boolean;
   player1: string;
+  player1_commit: Option<Buffer>;
+  /**
+   * Revealed guesses (None until `reveal_guess`)
+   */
   player1_guess: Option<u32>;
   player1_points: i128;
+  player1_salt: Option<Buffer>;
   player2: string;
+  player2_commit: Option<Buffer>;
   player2_guess: Option<u32>;
   player2_points: i128;
+  player2_salt: Option<Buffer>;
+  /**
+   * Ledger sequence after which `claim_timeout` can settle a game still
+   * missing a reveal; 0 until both players have committed
+   */
+  reveal_deadline: u32;
   winner: Option<string>;
   winning_number: Option<u32>;
 }
@@ -74,11 +95,22 @@ export const Errors = {
   2: {message:"NotPlayer"},
   3: {message:"AlreadyGuessed"},
   4: {message:"BothPlayersNotGuessed"},
-  5: {message:"GameAlreadyEnded"}
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"HashMismatch"},
+  7: {message:"AlreadyRevealed"},
+  8: {message:"BothPlayersNotRevealed"},
+  9: {message:"InvalidGuess"},
+  10: {message:"SelfPlay"},
+  11: {message:"HubNotSet"},
+  12: {message:"SessionExists"},
+  13: {message:"DeadlineNotReached"},
+  14: {message:"CannotClaimTimeout"},
+  15: {message:"LobbyNotSet"},
+  100: {message:"SelfPlay"},
+  101: {message:"HubNotSet"},
+  102: {message:"SessionExists"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
-
 export interface Client {
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -87,7 +119,7 @@ export interface Client {
    * # Returns
    * * `Address` - The GameHub contract address
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -107,6 +139,24 @@ export interface Client {
    */
   upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the lobby allowed to call `start_lobby_game`
+   * 
+   * # Returns
+   * * `Option<Address>` - The lobby address, or `None` if none is registered
+   */
+  get_lobby: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the lobby allowed to call `start_lobby_game`
+   * 
+   * # Arguments
+   * * `lobby` - The lobby contract address
+   */
+  set_lobby: ({lobby}: {lobby: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get game information.
@@ -119,6 +169,18 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get whether a game is complete and who won, without the full state.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `GameResult` - Complete once a winner is known or both players forfeited
+   */
+  get_result: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameResult>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -139,15 +201,47 @@ export interface Client {
 
   /**
    * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Make a guess for the current game.
-   * Players can guess a number between 1 and 10.
+   * Commit a guess for the current game.
+   * Players guess a number between 1 and 10; only the hash is stored.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * * `player` - Address of the player making the guess
-   * * `guess` - The guessed number (1-10)
+   * * `guess_hash` - keccak256(guess(1 byte) || salt(32 bytes))
    */
-  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  make_guess: ({session_id, player, guess_hash}: {session_id: u32, player: string, guess_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a committed guess.
+   * Only allowed once both players have committed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `guess` - The committed guess (1-10)
+   * * `salt` - The salt used in the commitment
+   */
+  reveal_guess: ({session_id, player, guess, salt}: {session_id: u32, player: string, guess: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a game whose deadline has passed without both commitments
+   * or, once both are in, without both guesses revealed.
+   * 
+   * A player who committed (or revealed) wins against one who did not; if
+   * neither did, the session is ended as a draw and both stakes refunded.
+   * Only allowed once the ledger sequence is past `commit_deadline`, or
+   * `reveal_deadline` once both players have committed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `claimant` - Address of either player
+   * 
+   * # Returns
+   * * `Option<Address>` - The winner, or None after a double forfeit
+   */
+  claim_timeout: ({session_id, claimant}: {session_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -158,18 +252,36 @@ export interface Client {
    * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
+   * * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
    * * `player1` - Address of first player
    * * `player2` - Address of second player
    * * `player1_points` - Points amount committed by player 1
    * * `player2_points` - Points amount committed by player 2
+   * 
+   * # Returns
+   * * `u32` - The session ID of the new game
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_lobby_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game on behalf of the registered lobby.
+   * 
+   * Only the lobby's auth is required: the players already authorized
+   * their stakes when posting and accepting the challenge there.  The
+   * lobby holds those stakes, so no points are locked.
+   * 
+   * # Arguments
+   * * `session_id` - Session ID the lobby keyed the challenge by
+   * * `player1` - Address of the challenge's host
+   * * `player2` - Address of the player who accepted
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_lobby_game: ({session_id, player1, player2}: {session_id: u32, player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have made their guesses.
+   * Can only be called after both players have revealed their guesses.
    * This generates the winning number, determines the winner, and ends the session.
    * 
    * # Arguments
@@ -200,31 +312,43 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAIAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAF",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAPAAAAckxlZGdlciBzZXF1ZW5jZSBhZnRlciB3aGljaCBgY2xhaW1fdGltZW91dGAgY2FuIHNldHRsZSBhIGdhbWUgc3RpbGwKbWlzc2luZyBhIGNvbW1pdG1lbnQ7IHNldCB3aGVuIHRoZSBnYW1lIHN0YXJ0cwAAAAAAD2NvbW1pdF9kZWFkbGluZQAAAAAEAAAARk5laXRoZXIgcGxheWVyIGNvbW1pdHRlZCBvciByZXZlYWxlZCBpbiB0aW1lIGFuZCB0aGUgc2Vzc2lvbiB3YXMgZHJhd24AAAAAAAlmb3JmZWl0ZWQAAAAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfY29tbWl0AAAAAAPoAAAD7gAAACAAAAAsUmV2ZWFsZWQgZ3Vlc3NlcyAoTm9uZSB1bnRpbCBgcmV2ZWFsX2d1ZXNzYCkAAAANcGxheWVyMV9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAMcGxheWVyMV9zYWx0AAAD6AAAA+4AAAAgAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfY29tbWl0AAAAAAPoAAAD7gAAACAAAAAAAAAADXBsYXllcjJfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjJfc2FsdAAAA+gAAAPuAAAAIAAAAHlMZWRnZXIgc2VxdWVuY2UgYWZ0ZXIgd2hpY2ggYGNsYWltX3RpbWVvdXRgIGNhbiBzZXR0bGUgYSBnYW1lIHN0aWxsCm1pc3NpbmcgYSByZXZlYWw7IDAgdW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgY29tbWl0dGVkAAAAAAAAD3JldmVhbF9kZWFkbGluZQAAAAAEAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAATAAAAAAAAAA53aW5uaW5nX251bWJlcgAAAAAD6AAAAAQ=",
+        "AAAABAAAAJtFcnJvcnMgcmFpc2VkIGJ5IHRoZSBzaGFyZWQgaGVscGVycy4KCkNvZGVzIHN0YXJ0IGF0IDEwMCBzbyB0aGV5IG5ldmVyIGNsYXNoIHdpdGggYSBnYW1lJ3Mgb3duIGNvZGVzIHdoZW4KcmV0dXJuZWQgZGlyZWN0bHksIGFzIHRoZSBbYEFkbWluYF0gZnVuY3Rpb25zIGRvLgAAAAAAAAAACUNvcmVFcnJvcgAAAAAAAAMAAAAAAAAACFNlbGZQbGF5AAAAZAAAAAAAAAAJSHViTm90U2V0AAAAAAAAZQAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAGY=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAAxIYXNoTWlzbWF0Y2gAAAAGAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAABwAAAAAAAAAWQm90aFBsYXllcnNOb3RSZXZlYWxlZAAAAAAACAAAAAAAAAAMSW52YWxpZEd1ZXNzAAAACQAAAAAAAAAIU2VsZlBsYXkAAAAKAAAAAAAAAAlIdWJOb3RTZXQAAAAAAAALAAAAAAAAAA1TZXNzaW9uRXhpc3RzAAAAAAAADAAAAAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAANAAAAAAAAABJDYW5ub3RDbGFpbVRpbWVvdXQAAAAAAA4AAAAAAAAAC0xvYmJ5Tm90U2V0AAAAAA8=",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAAlDb3JlRXJyb3IAAAA=",
         "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAIRHZXQgdGhlIGxvYmJ5IGFsbG93ZWQgdG8gY2FsbCBgc3RhcnRfbG9iYnlfZ2FtZWAKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gVGhlIGxvYmJ5IGFkZHJlc3MsIG9yIGBOb25lYCBpZiBub25lIGlzIHJlZ2lzdGVyZWQAAAAJZ2V0X2xvYmJ5AAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAGlSZWdpc3RlciB0aGUgbG9iYnkgYWxsb3dlZCB0byBjYWxsIGBzdGFydF9sb2JieV9nYW1lYAoKIyBBcmd1bWVudHMKKiBgbG9iYnlgIC0gVGhlIGxvYmJ5IGNvbnRyYWN0IGFkZHJlc3MAAAAAAAAJc2V0X2xvYmJ5AAAAAAAAAQAAAAAAAAAFbG9iYnkAAAAAAAATAAAAAA==",
         "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAANJHZXQgd2hldGhlciBhIGdhbWUgaXMgY29tcGxldGUgYW5kIHdobyB3b24sIHdpdGhvdXQgdGhlIGZ1bGwgc3RhdGUuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgR2FtZVJlc3VsdGAgLSBDb21wbGV0ZSBvbmNlIGEgd2lubmVyIGlzIGtub3duIG9yIGJvdGggcGxheWVycyBmb3JmZWl0ZWQAAAAAAApnZXRfcmVzdWx0AAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAM=",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAOJNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApAAAAAAAKbWFrZV9ndWVzcwAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAQ9Db21taXQgYSBndWVzcyBmb3IgdGhlIGN1cnJlbnQgZ2FtZS4KUGxheWVycyBndWVzcyBhIG51bWJlciBiZXR3ZWVuIDEgYW5kIDEwOyBvbmx5IHRoZSBoYXNoIGlzIHN0b3JlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBtYWtpbmcgdGhlIGd1ZXNzCiogYGd1ZXNzX2hhc2hgIC0ga2VjY2FrMjU2KGd1ZXNzKDEgYnl0ZSkgfHwgc2FsdCgzMiBieXRlcykpAAAAAAptYWtlX2d1ZXNzAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmd1ZXNzX2hhc2gAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAQBSZXZlYWwgYSBjb21taXR0ZWQgZ3Vlc3MuCk9ubHkgYWxsb3dlZCBvbmNlIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHJldmVhbGluZyBwbGF5ZXIKKiBgZ3Vlc3NgIC0gVGhlIGNvbW1pdHRlZCBndWVzcyAoMS0xMCkKKiBgc2FsdGAgLSBUaGUgc2FsdCB1c2VkIGluIHRoZSBjb21taXRtZW50AAAADHJldmVhbF9ndWVzcwAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFZ3Vlc3MAAAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAidTZXR0bGUgYSBnYW1lIHdob3NlIGRlYWRsaW5lIGhhcyBwYXNzZWQgd2l0aG91dCBib3RoIGNvbW1pdG1lbnRzCm9yLCBvbmNlIGJvdGggYXJlIGluLCB3aXRob3V0IGJvdGggZ3Vlc3NlcyByZXZlYWxlZC4KCkEgcGxheWVyIHdobyBjb21taXR0ZWQgKG9yIHJldmVhbGVkKSB3aW5zIGFnYWluc3Qgb25lIHdobyBkaWQgbm90OyBpZgpuZWl0aGVyIGRpZCwgdGhlIHNlc3Npb24gaXMgZW5kZWQgYXMgYSBkcmF3IGFuZCBib3RoIHN0YWtlcyByZWZ1bmRlZC4KT25seSBhbGxvd2VkIG9uY2UgdGhlIGxlZGdlciBzZXF1ZW5jZSBpcyBwYXN0IGBjb21taXRfZGVhZGxpbmVgLCBvcgpgcmV2ZWFsX2RlYWRsaW5lYCBvbmNlIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgY2xhaW1hbnRgIC0gQWRkcmVzcyBvZiBlaXRoZXIgcGxheWVyCgojIFJldHVybnMKKiBgT3B0aW9uPEFkZHJlc3M+YCAtIFRoZSB3aW5uZXIsIG9yIE5vbmUgYWZ0ZXIgYSBkb3VibGUgZm9yZmVpdAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAoVTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMiksIG9yIDAgdG8gbGV0IHRoZSBHYW1lIEh1YiBhbGxvY2F0ZSBvbmUKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgbmV3IGdhbWUAAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAZRTdGFydCBhIG5ldyBnYW1lIG9uIGJlaGFsZiBvZiB0aGUgcmVnaXN0ZXJlZCBsb2JieS4KCk9ubHkgdGhlIGxvYmJ5J3MgYXV0aCBpcyByZXF1aXJlZDogdGhlIHBsYXllcnMgYWxyZWFkeSBhdXRob3JpemVkCnRoZWlyIHN0YWtlcyB3aGVuIHBvc3RpbmcgYW5kIGFjY2VwdGluZyB0aGUgY2hhbGxlbmdlIHRoZXJlLiAgVGhlCmxvYmJ5IGhvbGRzIHRob3NlIHN0YWtlcywgc28gbm8gcG9pbnRzIGFyZSBsb2NrZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFNlc3Npb24gSUQgdGhlIGxvYmJ5IGtleWVkIHRoZSBjaGFsbGVuZ2UgYnkKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIHRoZSBjaGFsbGVuZ2UncyBob3N0CiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHdobyBhY2NlcHRlZAAAABBzdGFydF9sb2JieV9nYW1lAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAATtSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
+        "AAAAAAAAAT9SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIGd1ZXNzZXMuClRoaXMgZ2VuZXJhdGVzIHRoZSB3aW5uaW5nIG51bWJlciwgZGV0ZXJtaW5lcyB0aGUgd2lubmVyLCBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
+    get_hub: this.txFromJSON<Result<string>>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
+        get_lobby: this.txFromJSON<Option<string>>,
+        set_lobby: this.txFromJSON<null>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_result: this.txFromJSON<Result<GameResult>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
         make_guess: this.txFromJSON<Result<void>>,
-        start_game: this.txFromJSON<Result<void>>,
+        reveal_guess: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<Option<string>>>,
+        start_game: this.txFromJSON<Result<u32>>,
+        start_lobby_game: this.txFromJSON<Result<void>>,
         reveal_winner: this.txFromJSON<Result<string>>
   }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ import { Client as NumberGuessClient, type Game } from './bindings';
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, TransactionBuilder, StrKey, xdr, Address, authorizeEntry } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
+import { keccak_256 } from '@noble/hashes/sha3';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
@@ -449,7 +450,7 @@ export class NumberGuessService {
     signerAddress: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(signerAddress, signer);
 
     // Import the transaction with all auth entries signed
@@ -469,7 +470,8 @@ export class NumberGuessService {
       DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
       validUntilLedgerSeq
     );
-    return sentTx.result;
+    // start_game returns the session ID the game was stored under
+    return (sentTx.result as { unwrap(): number }).unwrap();
   }
 
   /**
@@ -589,14 +591,98 @@ export class NumberGuessService {
       throw new Error('Guess must be between 1 and 10');
     }
 
+    // Only keccak256(guess || salt) goes on-chain; the salt stays in this browser until reveal_guess
+    const salt = crypto.getRandomValues(new Uint8Array(32));
+    const preimage = new Uint8Array(33);
+    preimage[0] = guess;
+    preimage.set(salt, 1);
+
     const client = this.createSigningClient(playerAddress, signer);
     const tx = await client.make_guess({
       session_id: sessionId,
       player: playerAddress,
-      guess,
+      guess_hash: Buffer.from(keccak_256(preimage)),
     }, DEFAULT_METHOD_OPTIONS);
+    localStorage.setItem(
+      this.guessKey(sessionId, playerAddress),
+      JSON.stringify({ guess, salt: Buffer.from(salt).toString('hex') })
+    );
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {  /**
+   * Reveal a committed guess once both players have committed
+   */
+  async revealGuess(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const stored = localStorage.getItem(this.guessKey(sessionId, playerAddress));
+    if (!stored) {
+      throw new Error('No saved guess for this game - was it made from another browser?');
+    }
+    const { guess, salt } = JSON.parse(stored) as { guess: number; salt: string };
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_guess({
+      session_id: sessionId,
+      player: playerAddress,
+      guess,
+      salt: Buffer.from(salt, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check that both players have guessed and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
+  /**
+   * Settle a game whose guess or reveal deadline has passed. Resolves to the
+   * winner, or None when neither player moved and both stakes were refunded.
+   */
+  async claimTimeout(
+    sessionId: number,
+    claimant: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const client = this.createSigningClient(claimant, signer);
+    const tx = await client.claim_timeout({
+      session_id: sessionId,
+      claimant,
+    }, DEFAULT_METHOD_OPTIONS);
+
     const validUntilLedgerSeq = authTtlMinutes
       ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
       : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
@@ -612,6 +698,17 @@ export class NumberGuessService {
       return sentTx.result;
     } catch (err) {
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - the guess or reveal deadline may not have passed yet');
+      }
+      throw err;
+    }
+  }
+
+  private guessKey(sessionId: number, player: string): string {
+    return `number-guess-guess:${sessionId}:${player}`;
+  }
+
+
         throw new Error('Transaction failed - check if the game is still active and you haven\'t already guessed');
       }
       throw err;
@@ -619,7 +716,7 @@ export class NumberGuessService {
   }
 
   /**
-   * Reveal the winner after both players have guessed
+   * Reveal the winner after both players have revealed their guesses
    */
   async revealWinner(
     sessionId: number,
@@ -655,7 +752,7 @@ export class NumberGuessService {
 
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
         // This is the SDK error when trying to access .result on a failed transaction
-        throw new Error('Transaction failed - check if both players have guessed and the game is still active');
+        throw new Error('Transaction failed - check if both players have revealed and the game is still active');
       }
 
       throw err;
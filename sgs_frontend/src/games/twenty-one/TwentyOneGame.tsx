@@ -1,5 +1,6 @@
 import { useState, useEffect, useRef } from 'react';
-import { twentyOneService } from './twentyOneService';
+import { twentyOneService, cardRank, cardSuit } from './twentyOneService';
+import { Draw } from './bindings';
 import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { getLocationSearch } from '@/utils/location';
 import { useWallet } from '@/hooks/useWallet';
@@ -42,31 +43,34 @@ interface GameState {
   player2_stuck: boolean;
   winner: string | null;
   round: number;
+  pending_draw: Draw;
+  player1_revealed: boolean;
+  player2_revealed: boolean;
+  draws: number;
+  draw_deadline: number;
 }
 
-// Card component with suit and value
-const PlayingCard = ({ value, isHidden = false }: { value: number; isHidden?: boolean }) => {
-  const getCardDisplay = (val: number) => {
-    if (val === 1) return { display: 'A', name: 'Ace' };
-    if (val <= 10) return { display: val.toString(), name: val.toString() };
-    if (val === 11) return { display: 'J', name: 'Jack' };
-    if (val === 12) return { display: 'Q', name: 'Queen' };
-    if (val === 13) return { display: 'K', name: 'King' };
-    return { display: '?', name: 'Unknown' };
-  };
+const SUITS = [
+  { symbol: '♣', color: 'text-[#111827]', name: 'Clubs' },
+  { symbol: '♦', color: 'text-[#dc2626]', name: 'Diamonds' },
+  { symbol: '♥', color: 'text-[#dc2626]', name: 'Hearts' },
+  { symbol: '♠', color: 'text-[#111827]', name: 'Spades' },
+];
+
+const getCardDisplay = (card: number) => {
+  const rank = cardRank(card);
+  if (rank === 1) return { display: 'A', name: 'Ace' };
+  if (rank <= 10) return { display: rank.toString(), name: rank.toString() };
+  if (rank === 11) return { display: 'J', name: 'Jack' };
+  if (rank === 12) return { display: 'Q', name: 'Queen' };
+  if (rank === 13) return { display: 'K', name: 'King' };
+  return { display: '?', name: 'Unknown' };
+};
 
-  const getSuit = (val: number) => {
-    // Distribute cards across suits for visual variety
-    const suitIndex = (val - 1) % 4;
-    const suits = [
-      { symbol: '♠', color: 'text-[#111827]', name: 'Spades' },
-      { symbol: '♥', color: 'text-[#dc2626]', name: 'Hearts' },
-      { symbol: '♣', color: 'text-[#111827]', name: 'Clubs' },
-      { symbol: '♦', color: 'text-[#dc2626]', name: 'Diamonds' },
-    ];
-    return suits[suitIndex];
-  };
+const getSuit = (card: number) => SUITS[cardSuit(card)] ?? SUITS[0];
 
+// Card component with suit and value (card byte: suit << 4 | rank)
+const PlayingCard = ({ value, isHidden = false }: { value: number; isHidden?: boolean }) => {
   if (isHidden) {
     return (
       <div className="relative w-20 h-28 rounded-md bg-gradient-to-br from-blue-600 to-blue-800 shadow-xl border-4 border-blue-900 flex items-center justify-center transform transition-all hover:scale-105">
@@ -198,7 +202,7 @@ export function TwentyOneGame({
         // Determine game phase
         if (game.winner) {
           setGamePhase('complete');
-        } else if (game.player1_stuck && game.player2_stuck) {
+        } else if (game.player1_stuck && game.player2_stuck && game.pending_draw === Draw.None) {
           setGamePhase('reveal');
         } else {
           setGamePhase('play');
@@ -537,13 +541,13 @@ export function TwentyOneGame({
           signer
         );
 
-        await twentyOneService.finalizeStartGame(
+        const startedSessionId = await twentyOneService.finalizeStartGame(
           fullySignedTxXDR,
           userAddress,
           signer
         );
 
-        setSessionId(gameParams.sessionId);
+        setSessionId(startedSessionId);
         setSuccess('Game created successfully! Both players signed.');
         setGamePhase('play');
 
@@ -598,7 +602,7 @@ export function TwentyOneGame({
           setGamePhase('complete');
           const isWinner = normalizeAddress(game.winner) === normalizedUserAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_stuck && game.player2_stuck) {
+        } else if (game.player1_stuck && game.player2_stuck && game.pending_draw === Draw.None) {
           setGamePhase('reveal');
           setSuccess('Game loaded! Both players have stuck. You can reveal the winner.');
         } else {
@@ -696,6 +700,50 @@ export function TwentyOneGame({
     });
   };
 
+  const handleRevealLink = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await twentyOneService.revealLink(sessionId, userAddress, signer);
+
+        setSuccess('Link revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal link error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal link');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleClaimTimeout = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        const claimResult = await twentyOneService.claimTimeout(sessionId, userAddress, signer);
+        const winner = (claimResult as any).unwrap ? (claimResult as any).unwrap() : claimResult;
+
+        setSuccess(winner ? '🎉 Opponent timed out - you won!' : 'Neither hand was revealed in time - stakes refunded.');
+        await loadGameState();
+        onStandingsRefresh();
+      } catch (err) {
+        console.error('Claim timeout error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to claim timeout');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await twentyOneService.getGame(sessionId);
     let attempts = 0;
@@ -717,10 +765,17 @@ export function TwentyOneGame({
         const signer = getContractSigner();
         const winnerResult = await twentyOneService.revealWinner(sessionId, userAddress, signer);
 
+        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
+        if (!winner) {
+          // A tie starts a new round, dealt once both players reveal their next links
+          setSuccess('Tie! A new round starts - reveal your link to deal.');
+          await loadGameState();
+          return;
+        }
+
         await waitForWinner();
         await loadGameState();
 
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
         const isWinner = normalizeAddress(winner) === normalizedUserAddress;
         setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
 
@@ -736,7 +791,11 @@ export function TwentyOneGame({
 
   const isPlayer1 = !!gameState && normalizeAddress(gameState.player1) === normalizedUserAddress;
   const isPlayer2 = !!gameState && normalizeAddress(gameState.player2) === normalizedUserAddress;
-  const canAct = (isPlayer1 && !gameState?.player1_stuck) || (isPlayer2 && !gameState?.player2_stuck);
+  const drawPending = !!gameState && gameState.pending_draw !== Draw.None;
+  const canAct = !drawPending
+    && ((isPlayer1 && !gameState?.player1_stuck) || (isPlayer2 && !gameState?.player2_stuck));
+  const userRevealed = isPlayer1 ? !!gameState?.player1_revealed : !!gameState?.player2_revealed;
+  const opponentRevealed = isPlayer1 ? !!gameState?.player2_revealed : !!gameState?.player1_revealed;
 
   return (
     <div className="min-h-screen bg-gradient-to-br from-green-800 via-green-700 to-green-900 p-8">
@@ -1108,7 +1167,33 @@ export function TwentyOneGame({
                 </div>
               )}
 
-              {!canAct && (
+              {/* Pending draw: both players reveal a link before the cards are dealt */}
+              {drawPending && !userRevealed && (
+                <button
+                  onClick={handleRevealLink}
+                  disabled={isBusy}
+                  className="w-full py-4 rounded-xl font-black text-2xl text-white bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-400 disabled:to-gray-500 transition-all shadow-xl"
+                >
+                  {gameState.draws === 0 ? '🃏 DEAL' : '🔑 REVEAL LINK'}
+                </button>
+              )}
+
+              {drawPending && userRevealed && !opponentRevealed && (
+                <div className="text-center p-4 bg-blue-500/20 rounded-xl border-2 border-blue-400/50 space-y-3">
+                  <p className="text-white font-semibold">
+                    Waiting for your opponent to reveal their link (deadline: ledger {gameState.draw_deadline})...
+                  </p>
+                  <button
+                    onClick={handleClaimTimeout}
+                    disabled={isBusy}
+                    className="px-6 py-2 rounded-lg font-bold text-white bg-red-600 hover:bg-red-700 disabled:bg-gray-400 transition-all"
+                  >
+                    Claim win after deadline
+                  </button>
+                </div>
+              )}
+
+              {!canAct && !drawPending && (
                 <div className="text-center p-4 bg-blue-500/20 rounded-xl border-2 border-blue-400/50">
                   <p className="text-white font-semibold">
                     {(isPlayer1 ? gameState.player1_stuck : gameState.player2_stuck)
@@ -1158,7 +1243,9 @@ export function TwentyOneGame({
                 </div>
                 <div className="flex justify-center gap-1 flex-wrap">
                   {Array.from(gameState.player1_hand).map((card, idx) => (
-                    <div key={idx} className="text-2xl">{card}</div>
+                    <div key={idx} className={`text-2xl font-bold ${getSuit(card).color}`}>
+                      {getCardDisplay(card).display}{getSuit(card).symbol}
+                    </div>
                   ))}
                 </div>
               </div>
@@ -1169,7 +1256,9 @@ export function TwentyOneGame({
                 </div>
                 <div className="flex justify-center gap-1 flex-wrap">
                   {Array.from(gameState.player2_hand).map((card, idx) => (
-                    <div key={idx} className="text-2xl">{card}</div>
+                    <div key={idx} className={`text-2xl font-bold ${getSuit(card).color}`}>
+                      {getCardDisplay(card).display}{getSuit(card).symbol}
+                    </div>
                   ))}
                 </div>
               </div>
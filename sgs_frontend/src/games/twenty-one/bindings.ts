@@ -59,15 +59,32 @@ export const networks = {
 
 
 export interface Game {
+  dealer_hand: Buffer;
+  draw_deadline: u32;
+  draws: u32;
+  forfeited: boolean;
+  hidden: boolean;
+  pending_draw: Draw;
   player1: string;
   player1_hand: Buffer;
+  player1_hand_link: Option<Buffer>;
+  player1_hidden: Array<Buffer>;
+  player1_link: Buffer;
   player1_points: i128;
+  player1_revealed: boolean;
   player1_stuck: boolean;
   player2: string;
   player2_hand: Buffer;
+  player2_hand_link: Option<Buffer>;
+  player2_hidden: Array<Buffer>;
+  player2_link: Buffer;
   player2_points: i128;
+  player2_revealed: boolean;
   player2_stuck: boolean;
   round: u32;
+  rules: Rules;
+  shoe: Buffer;
+  showdown_deadline: u32;
   winner: Option<string>;
 }
 
@@ -82,22 +99,100 @@ export const Errors = {
   8: {message:"Draw"},
   9: {message:"SelfPlay"},
   10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+  11: {message:"InvalidHandData"},
+  12: {message:"NoPendingDraw"},
+  13: {message:"DrawPending"},
+  14: {message:"HashMismatch"},
+  15: {message:"AlreadyRevealed"},
+  16: {message:"NotHiddenGame"},
+  17: {message:"HandNotRevealed"},
+  18: {message:"InvalidRules"},
+  19: {message:"ShoeExhausted"},
+  20: {message:"HubNotSet"},
+  21: {message:"SessionExists"},
+  22: {message:"DeadlineNotReached"},
+  23: {message:"CannotClaimTimeout"},
+  100: {message:"SelfPlay"},
+  101: {message:"HubNotSet"},
+  102: {message:"SessionExists"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Rule set chosen per session at start
+ */
+export interface Rules {
+  decks: u32;
+  house: boolean;
+  reshuffle_below: u32;
+  soft_aces: boolean;
+}
+
+/**
+ * Chain anchors a player commits to in a hidden-hand game
+ */
+export interface Anchors {
+  draw: Buffer;
+  hand: Buffer;
+}
+
+/**
+ * A draw waiting for both players to reveal their next chain link
+ */
+export enum Draw {
+  None = 0,
+  Deal = 1,
+  Player1Hit = 2,
+  Player2Hit = 3,
+  Dealer = 4,
+}
 
 export interface Client {
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player draws another card ("hit").
-   * If the player's hand value exceeds 21, they bust and lose immediately.
+   * The player reveals their next chain link with the request; the card is dealt
+   * when the opponent reveals theirs with `reveal_link`. If the player's hand
+   * value then exceeds 21, they bust and lose immediately.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * * `player` - Address of the player drawing a card
+   * * `link` - The player's next hash chain link
    */
-  hit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  hit: ({session_id, player, link}: {session_id: u32, player: string, link: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the next link of a player's hash chain for the pending draw.
+   * The link must hash to the last link the contract verified for this player
+   * (the anchor for the first draw). Once both players have revealed, the
+   * pending cards are dealt; a bust ends the game like in `hit`.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `link` - The player's next hash chain link
+   */
+  reveal_link: ({session_id, player, link}: {session_id: u32, player: string, link: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a game whose opponent failed to reveal in time.
+   * 
+   * Covers a pending draw (past `draw_deadline`, the claimant revealed their link
+   * and the opponent did not) and a hidden-hand showdown (past `showdown_deadline`,
+   * the opponent has not revealed their hand). A claimant who revealed their hand
+   * wins; if neither player revealed, the session ends as a draw in the Game Hub.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `claimant` - Address of the player claiming
+   * 
+   * # Returns
+   * * `Option<Address>` - The winning player (the claimant), or `None` if neither
+   * hand was revealed and both stakes were refunded
+   */
+  claim_timeout: ({session_id, claimant}: {session_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a stick transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -110,6 +205,20 @@ export interface Client {
    */
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a reveal_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a player's face-down cards in a hidden-hand game.
+   * Can only be called after both players have stuck. The secret must hash to the
+   * last verified link of the player's hand chain; the contract recomputes every
+   * face-down card from it and adds them to the player's hand.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `hand_secret` - The player's hand secret for the current round
+   */
+  reveal_hand: ({session_id, player, hand_secret}: {session_id: u32, player: string, hand_secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current GameHub contract address
@@ -117,7 +226,7 @@ export interface Client {
    * # Returns
    * * `Address` - The GameHub contract address
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -171,38 +280,65 @@ export interface Client {
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Start a new game between two players with points.
    * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
+   * Each player commits the anchor of their hash chain; the initial 2-card hands
+   * are dealt once both players have revealed their first link with `reveal_link`.
    * 
    * **CRITICAL:** This method requires authorization from THIS contract (not players).
    * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
+   * * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `points` - Points amounts committed by player 1 and player 2
+   * * `anchors` - Last links of player 1's and player 2's hash chains
+   * * `rules` - Rule set for this session
+   * 
+   * # Returns
+   * * `u32` - The session ID of the new game
+   */
+  start_game: ({session_id, player1, player2, points, anchors, rules}: {session_id: u32, player1: string, player2: string, points: readonly [i128, i128], anchors: readonly [Buffer, Buffer], rules: Rules}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_hidden_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new hidden-hand game (see module docs).
+   * Same as `start_game`, but each player also commits the anchor of their hand
+   * chain, and only the first card of each hand is dealt face up.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32), or 0 to let the Game Hub allocate one
    * * `player1` - Address of first player
    * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `points` - Points amounts committed by player 1 and player 2
+   * * `anchors` - Player 1's and player 2's draw and hand chain anchors
+   * * `rules` - Rule set for this session
+   * 
+   * # Returns
+   * * `u32` - The session ID of the new game
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_hidden_game: ({session_id, player1, player2, points, anchors, rules}: {session_id: u32, player1: string, player2: string, points: readonly [i128, i128], anchors: readonly [Anchors, Anchors], rules: Rules}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
+   * Can only be called after both players have stuck (and, in hidden-hand games,
+   * revealed their hands).
    * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
+   * and handles ties by starting a new round.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `Option<Address>` - Address of the winning player, or `None` after a tie
+   * (the new round is dealt once both players reveal their next links)
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current hand value for a player.
+   * In hidden-hand games this only counts face-up cards until `reveal_hand`.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
@@ -233,35 +369,46 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAbAAAAAAAAAAtkZWFsZXJfaGFuZAAAAAAOAAAAAAAAAA1kcmF3X2RlYWRsaW5lAAAAAAAABAAAAAAAAAAFZHJhd3MAAAAAAAAEAAAAAAAAAAlmb3JmZWl0ZWQAAAAAAAABAAAAAAAAAAZoaWRkZW4AAAAAAAEAAAAAAAAADHBlbmRpbmdfZHJhdwAAB9AAAAAERHJhdwAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAAAAAABFwbGF5ZXIxX2hhbmRfbGluawAAAAAAA+gAAAPuAAAAIAAAAAAAAAAOcGxheWVyMV9oaWRkZW4AAAAAA+oAAAPuAAAAIAAAAAAAAAAMcGxheWVyMV9saW5rAAAD7gAAACAAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAABBwbGF5ZXIxX3JldmVhbGVkAAAAAQAAAAAAAAANcGxheWVyMV9zdHVjawAAAAAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAMcGxheWVyMl9oYW5kAAAADgAAAAAAAAARcGxheWVyMl9oYW5kX2xpbmsAAAAAAAPoAAAD7gAAACAAAAAAAAAADnBsYXllcjJfaGlkZGVuAAAAAAPqAAAD7gAAACAAAAAAAAAADHBsYXllcjJfbGluawAAA+4AAAAgAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAQcGxheWVyMl9yZXZlYWxlZAAAAAEAAAAAAAAADXBsYXllcjJfc3R1Y2sAAAAAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAVSdWxlcwAAAAAAAAAAAAAEc2hvZQAAAA4AAAAAAAAAEXNob3dkb3duX2RlYWRsaW5lAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAABAAAAJtFcnJvcnMgcmFpc2VkIGJ5IHRoZSBzaGFyZWQgaGVscGVycy4KCkNvZGVzIHN0YXJ0IGF0IDEwMCBzbyB0aGV5IG5ldmVyIGNsYXNoIHdpdGggYSBnYW1lJ3Mgb3duIGNvZGVzIHdoZW4KcmV0dXJuZWQgZGlyZWN0bHksIGFzIHRoZSBbYEFkbWluYF0gZnVuY3Rpb25zIGRvLgAAAAAAAAAACUNvcmVFcnJvcgAAAAAAAAMAAAAAAAAACFNlbGZQbGF5AAAAZAAAAAAAAAAJSHViTm90U2V0AAAAAAAAZQAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAGY=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAFwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAABMTm8gbG9uZ2VyIHJldHVybmVkOiBhIHRpZSBkZWFscyBhIG5ldyByb3VuZC4gS2VwdCBzbyBjb2RlIDggaXMgbmV2ZXIgcmV1c2VkLgAAAAREcmF3AAAACAAAAAAAAAAIU2VsZlBsYXkAAAAJAAAAAAAAAA1Sb3VuZE92ZXJmbG93AAAAAAAACgAAAAAAAAAPSW52YWxpZEhhbmREYXRhAAAAAAsAAAAAAAAADU5vUGVuZGluZ0RyYXcAAAAAAAAMAAAAAAAAAAtEcmF3UGVuZGluZwAAAAANAAAAAAAAAAxIYXNoTWlzbWF0Y2gAAAAOAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAADwAAAAAAAAANTm90SGlkZGVuR2FtZQAAAAAAABAAAAAAAAAAD0hhbmROb3RSZXZlYWxlZAAAAAARAAAAAAAAAAxJbnZhbGlkUnVsZXMAAAASAAAAAAAAAA1TaG9lRXhoYXVzdGVkAAAAAAAAEwAAAAAAAAAJSHViTm90U2V0AAAAAAAAFAAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAABUAAAAAAAAAEkRlYWRsaW5lTm90UmVhY2hlZAAAAAAAFgAAAAAAAAASQ2Fubm90Q2xhaW1UaW1lb3V0AAAAAAAX",
+        "AAAAAQAAACRSdWxlIHNldCBjaG9zZW4gcGVyIHNlc3Npb24gYXQgc3RhcnQAAAAAAAAABVJ1bGVzAAAAAAAABAAAAAAAAAAFZGVja3MAAAAAAAAEAAAAAAAAAAVob3VzZQAAAAAAAAEAAAAAAAAAD3Jlc2h1ZmZsZV9iZWxvdwAAAAAEAAAAAAAAAAlzb2Z0X2FjZXMAAAAAAAAB",
+        "AAAAAQAAADdDaGFpbiBhbmNob3JzIGEgcGxheWVyIGNvbW1pdHMgdG8gaW4gYSBoaWRkZW4taGFuZCBnYW1lAAAAAAAAAAAHQW5jaG9ycwAAAAACAAAAAAAAAARkcmF3AAAD7gAAACAAAAAAAAAABGhhbmQAAAPuAAAAIA==",
+        "AAAAAwAAAD9BIGRyYXcgd2FpdGluZyBmb3IgYm90aCBwbGF5ZXJzIHRvIHJldmVhbCB0aGVpciBuZXh0IGNoYWluIGxpbmsAAAAAAAAAAAREcmF3AAAABQAAAAAAAAAETm9uZQAAAAAAAAAAAAAABERlYWwAAAABAAAAAAAAAApQbGF5ZXIxSGl0AAAAAAACAAAAAAAAAApQbGF5ZXIySGl0AAAAAAADAAAAAAAAAAZEZWFsZXIAAAAAAAQ=",
+        "AAAAAAAAAYhQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuClRoZSBwbGF5ZXIgcmV2ZWFscyB0aGVpciBuZXh0IGNoYWluIGxpbmsgd2l0aCB0aGUgcmVxdWVzdDsgdGhlIGNhcmQgaXMgZGVhbHQKd2hlbiB0aGUgb3Bwb25lbnQgcmV2ZWFscyB0aGVpcnMgd2l0aCBgcmV2ZWFsX2xpbmtgLiBJZiB0aGUgcGxheWVyJ3MgaGFuZAp2YWx1ZSB0aGVuIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAoqIGBsaW5rYCAtIFRoZSBwbGF5ZXIncyBuZXh0IGhhc2ggY2hhaW4gbGluawAAAANoaXQAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARsaW5rAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAaNSZXZlYWwgdGhlIG5leHQgbGluayBvZiBhIHBsYXllcidzIGhhc2ggY2hhaW4gZm9yIHRoZSBwZW5kaW5nIGRyYXcuClRoZSBsaW5rIG11c3QgaGFzaCB0byB0aGUgbGFzdCBsaW5rIHRoZSBjb250cmFjdCB2ZXJpZmllZCBmb3IgdGhpcyBwbGF5ZXIKKHRoZSBhbmNob3IgZm9yIHRoZSBmaXJzdCBkcmF3KS4gT25jZSBib3RoIHBsYXllcnMgaGF2ZSByZXZlYWxlZCwgdGhlCnBlbmRpbmcgY2FyZHMgYXJlIGRlYWx0OyBhIGJ1c3QgZW5kcyB0aGUgZ2FtZSBsaWtlIGluIGBoaXRgLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJldmVhbGluZwoqIGBsaW5rYCAtIFRoZSBwbGF5ZXIncyBuZXh0IGhhc2ggY2hhaW4gbGluawAAAAALcmV2ZWFsX2xpbmsAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARsaW5rAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAmFTZXR0bGUgYSBnYW1lIHdob3NlIG9wcG9uZW50IGZhaWxlZCB0byByZXZlYWwgaW4gdGltZS4KCkNvdmVycyBhIHBlbmRpbmcgZHJhdyAocGFzdCBgZHJhd19kZWFkbGluZWAsIHRoZSBjbGFpbWFudCByZXZlYWxlZCB0aGVpciBsaW5rCmFuZCB0aGUgb3Bwb25lbnQgZGlkIG5vdCkgYW5kIGEgaGlkZGVuLWhhbmQgc2hvd2Rvd24gKHBhc3QgYHNob3dkb3duX2RlYWRsaW5lYCwKdGhlIG9wcG9uZW50IGhhcyBub3QgcmV2ZWFsZWQgdGhlaXIgaGFuZCkuIEEgY2xhaW1hbnQgd2hvIHJldmVhbGVkIHRoZWlyIGhhbmQKd2luczsgaWYgbmVpdGhlciBwbGF5ZXIgcmV2ZWFsZWQsIHRoZSBzZXNzaW9uIGVuZHMgYXMgYSBkcmF3IGluIHRoZSBHYW1lIEh1Yi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgY2xhaW1hbnRgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIGNsYWltaW5nCgojIFJldHVybnMKKiBgT3B0aW9uPEFkZHJlc3M+YCAtIFRoZSB3aW5uaW5nIHBsYXllciAodGhlIGNsYWltYW50KSwgb3IgYE5vbmVgIGlmIG5laXRoZXIKaGFuZCB3YXMgcmV2ZWFsZWQgYW5kIGJvdGggc3Rha2VzIHdlcmUgcmVmdW5kZWQAAAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAbVSZXZlYWwgYSBwbGF5ZXIncyBmYWNlLWRvd24gY2FyZHMgaW4gYSBoaWRkZW4taGFuZCBnYW1lLgpDYW4gb25seSBiZSBjYWxsZWQgYWZ0ZXIgYm90aCBwbGF5ZXJzIGhhdmUgc3R1Y2suIFRoZSBzZWNyZXQgbXVzdCBoYXNoIHRvIHRoZQpsYXN0IHZlcmlmaWVkIGxpbmsgb2YgdGhlIHBsYXllcidzIGhhbmQgY2hhaW47IHRoZSBjb250cmFjdCByZWNvbXB1dGVzIGV2ZXJ5CmZhY2UtZG93biBjYXJkIGZyb20gaXQgYW5kIGFkZHMgdGhlbSB0byB0aGUgcGxheWVyJ3MgaGFuZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByZXZlYWxpbmcKKiBgaGFuZF9zZWNyZXRgIC0gVGhlIHBsYXllcidzIGhhbmQgc2VjcmV0IGZvciB0aGUgY3VycmVudCByb3VuZAAAAAAAAAtyZXZlYWxfaGFuZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2hhbmRfc2VjcmV0AAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAAlDb3JlRXJyb3IAAAA=",
         "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
         "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAA1ZTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBjb21taXRzIHRoZSBhbmNob3Igb2YgdGhlaXIgaGFzaCBjaGFpbjsgdGhlIGluaXRpYWwgMi1jYXJkIGhhbmRzCmFyZSBkZWFsdCBvbmNlIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIGZpcnN0IGxpbmsgd2l0aCBgcmV2ZWFsX2xpbmtgLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMiksIG9yIDAgdG8gbGV0IHRoZSBHYW1lIEh1YiBhbGxvY2F0ZSBvbmUKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwb2ludHNgIC0gUG9pbnRzIGFtb3VudHMgY29tbWl0dGVkIGJ5IHBsYXllciAxIGFuZCBwbGF5ZXIgMgoqIGBhbmNob3JzYCAtIExhc3QgbGlua3Mgb2YgcGxheWVyIDEncyBhbmQgcGxheWVyIDIncyBoYXNoIGNoYWlucwoqIGBydWxlc2AgLSBSdWxlIHNldCBmb3IgdGhpcyBzZXNzaW9uCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBuZXcgZ2FtZQAAAAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAZwb2ludHMAAAAAA+0AAAACAAAACwAAAAsAAAAAAAAAB2FuY2hvcnMAAAAD7QAAAAIAAAPuAAAAIAAAA+4AAAAgAAAAAAAAAAVydWxlcwAAAAAAB9AAAAAFUnVsZXMAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAkhTdGFydCBhIG5ldyBoaWRkZW4taGFuZCBnYW1lIChzZWUgbW9kdWxlIGRvY3MpLgpTYW1lIGFzIGBzdGFydF9nYW1lYCwgYnV0IGVhY2ggcGxheWVyIGFsc28gY29tbWl0cyB0aGUgYW5jaG9yIG9mIHRoZWlyIGhhbmQKY2hhaW4sIGFuZCBvbmx5IHRoZSBmaXJzdCBjYXJkIG9mIGVhY2ggaGFuZCBpcyBkZWFsdCBmYWNlIHVwLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBVbmlxdWUgc2Vzc2lvbiBpZGVudGlmaWVyICh1MzIpLCBvciAwIHRvIGxldCB0aGUgR2FtZSBIdWIgYWxsb2NhdGUgb25lCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcG9pbnRzYCAtIFBvaW50cyBhbW91bnRzIGNvbW1pdHRlZCBieSBwbGF5ZXIgMSBhbmQgcGxheWVyIDIKKiBgYW5jaG9yc2AgLSBQbGF5ZXIgMSdzIGFuZCBwbGF5ZXIgMidzIGRyYXcgYW5kIGhhbmQgY2hhaW4gYW5jaG9ycwoqIGBydWxlc2AgLSBSdWxlIHNldCBmb3IgdGhpcyBzZXNzaW9uCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBuZXcgZ2FtZQAAABFzdGFydF9oaWRkZW5fZ2FtZQAAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAZwb2ludHMAAAAAA+0AAAACAAAACwAAAAsAAAAAAAAAB2FuY2hvcnMAAAAD7QAAAAIAAAfQAAAAB0FuY2hvcnMAAAAH0AAAAAdBbmNob3JzAAAAAAAAAAAFcnVsZXMAAAAAAAfQAAAABVJ1bGVzAAAAAAAAAQAAA+kAAAAEAAAAAw==",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAAeBSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrIChhbmQsIGluIGhpZGRlbi1oYW5kIGdhbWVzLApyZXZlYWxlZCB0aGVpciBoYW5kcykuClRoaXMgY2FsY3VsYXRlcyBoYW5kIHZhbHVlcywgZGV0ZXJtaW5lcyB0aGUgd2lubmVyIChjbG9zZXN0IHRvIDIxKSwKYW5kIGhhbmRsZXMgdGllcyBieSBzdGFydGluZyBhIG5ldyByb3VuZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBhZnRlciBhIHRpZQoodGhlIG5ldyByb3VuZCBpcyBkZWFsdCBvbmNlIGJvdGggcGxheWVycyByZXZlYWwgdGhlaXIgbmV4dCBsaW5rcykAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
+        "AAAAAAAAAQdHZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCkluIGhpZGRlbi1oYW5kIGdhbWVzIHRoaXMgb25seSBjb3VudHMgZmFjZS11cCBjYXJkcyB1bnRpbCBgcmV2ZWFsX2hhbmRgLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSB0b3RhbCB2YWx1ZSBvZiB0aGUgcGxheWVyJ3MgaGFuZAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
     hit: this.txFromJSON<Result<void>>,
+        reveal_link: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<Option<string>>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
+        reveal_hand: this.txFromJSON<Result<void>>,
+        get_hub: this.txFromJSON<Result<string>>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
         get_game: this.txFromJSON<Result<Game>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
+        start_game: this.txFromJSON<Result<u32>>,
+        start_hidden_game: this.txFromJSON<Result<u32>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_hand_value: this.txFromJSON<Result<u32>>
   }
 }
\ No newline at end of file
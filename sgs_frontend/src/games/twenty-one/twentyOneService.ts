@@ -1,12 +1,31 @@
-import { Client as TwentyOneClient } from './bindings';
+import { Client as TwentyOneClient, type Rules } from './bindings';
 import { TWENTY_ONE_CONTRACT, NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
+import { keccak_256 } from '@noble/hashes/sha3';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 
 type ClientOptions = contract.ClientOptions;
 
+/** Links in each player's hash chain; every draw consumes one, so 256 covers any game */
+const CHAIN_LENGTH = 256;
+
+/** Classic rules: Aces count 1, no dealer, infinite deck */
+export const CLASSIC_RULES: Rules = {
+  soft_aces: false,
+  house: false,
+  decks: 0,
+  reshuffle_below: 0,
+};
+
+/**
+ * Card bytes hold the rank (1-13) in the low nibble and the suit in the high nibble
+ * (0 = clubs, 1 = diamonds, 2 = hearts, 3 = spades).
+ */
+export const cardRank = (card: number) => card & 0x0f;
+export const cardSuit = (card: number) => card >> 4;
+
 /**
  * Service for interacting with the Twenty-One game contract
  */
@@ -63,6 +82,44 @@ export class TwentyOneService {
     }
   }
 
+  /**
+   * The random seed of a player's hash chain for a session, created on first use.
+   * It never leaves this browser: losing it means the player can no longer draw.
+   */
+  private chainSeed(sessionId: number, player: string): Uint8Array {
+    const key = `twenty-one-chain:${sessionId}:${player}`;
+    const stored = localStorage.getItem(key);
+    if (stored) {
+      return Buffer.from(stored, 'hex');
+    }
+    const seed = crypto.getRandomValues(new Uint8Array(32));
+    localStorage.setItem(key, Buffer.from(seed).toString('hex'));
+    return seed;
+  }
+
+  /**
+   * Link `depth` of a player's hash chain: depth 0 is the anchor committed at start,
+   * and the link for draw `n` is depth `n`, the preimage of depth `n - 1`.
+   */
+  chainLink(sessionId: number, player: string, depth: number): Buffer {
+    let link = this.chainSeed(sessionId, player);
+    for (let i = depth; i < CHAIN_LENGTH; i++) {
+      link = keccak_256(link);
+    }
+    return Buffer.from(link);
+  }
+
+  /**
+   * The link a player must reveal for the pending (or next) draw
+   */
+  private async nextLink(sessionId: number, player: string): Promise<Buffer> {
+    const game = await this.getGame(sessionId);
+    if (!game) {
+      throw new Error('Game not found');
+    }
+    return this.chainLink(sessionId, player, game.draws + 1);
+  }
+
   /**
    * Get hand value for a player
    */
@@ -107,12 +164,14 @@ export class TwentyOneService {
       publicKey: player2,
     });
 
+    // Player 2's anchor is only a placeholder: they commit their own when importing
     const tx = await buildClient.start_game({
       session_id: sessionId,
       player1,
       player2,
-      player1_points: player1Points,
-      player2_points: player2Points,
+      points: [player1Points, player2Points],
+      anchors: [this.chainLink(sessionId, player1, 0), Buffer.alloc(32)],
+      rules: CLASSIC_RULES,
     }, DEFAULT_METHOD_OPTIONS);
 
     console.log('[prepareStartGame] Transaction built and simulated');
@@ -223,8 +282,9 @@ export class TwentyOneService {
       session_id: gameParams.sessionId,
       player1: gameParams.player1,
       player2,
-      player1_points: gameParams.player1Points,
-      player2_points: player2Points,
+      points: [gameParams.player1Points, player2Points],
+      anchors: [gameParams.player1Anchor, this.chainLink(gameParams.sessionId, player2, 0)],
+      rules: CLASSIC_RULES,
     }, DEFAULT_METHOD_OPTIONS);
 
     // Import Player 1's signed auth entry
@@ -241,7 +301,7 @@ export class TwentyOneService {
     fullySignedTxXDR: string,
     player2: string,
     player2Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(player2, player2Signer);
 
     // Import the transaction with all auth entries signed
@@ -256,7 +316,8 @@ export class TwentyOneService {
       DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
       validUntilLedgerSeq
     );
-    return sentTx.result;
+    // start_game returns the session ID the game was stored under
+    return (sentTx.result as { unwrap(): number }).unwrap();
   }
 
   /**
@@ -266,6 +327,7 @@ export class TwentyOneService {
     sessionId: number;
     player1: string;
     player1Points: bigint;
+    player1Anchor: Buffer;
   } {
     try {
       const authEntry = xdr.SorobanAuthorizationEntry.fromXDR(authEntryXDR, 'base64');
@@ -274,6 +336,7 @@ export class TwentyOneService {
       // - signer address (from credentials)
       // - session_id (arg 0)
       // - signer points (arg 1)
+      // - signer chain anchor (arg 2)
       const credentials = authEntry.credentials();
       if (credentials.switch().name !== 'sorobanCredentialsAddress') {
         throw new Error(`Unsupported credentials type: ${credentials.switch().name}`);
@@ -290,17 +353,19 @@ export class TwentyOneService {
       }
 
       const args = contractFn.args();
-      if (args.length !== 2) {
-        throw new Error(`Invalid number of arguments: ${args.length}. Expected 2`);
+      if (args.length !== 3) {
+        throw new Error(`Invalid number of arguments: ${args.length}. Expected 3`);
       }
 
       const sessionId = args[0].u32();
       const player1Points = args[1].i128().lo().toBigInt();
+      const player1Anchor = Buffer.from(args[2].bytes());
 
       return {
         sessionId,
         player1,
         player1Points,
+        player1Anchor,
       };
     } catch (err) {
       console.error('[parseAuthEntry] Error parsing auth entry:', err);
@@ -309,17 +374,71 @@ export class TwentyOneService {
   }
 
   /**
-   * Player hits (draws a card)
+   * Player hits (draws a card), revealing their next chain link.
+   * The card is dealt once the opponent reveals theirs.
    */
   async hit(
     sessionId: number,
     player: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
+    const link = await this.nextLink(sessionId, player);
     const client = this.createSigningClient(player, signer);
     const tx = await client.hit({
       session_id: sessionId,
       player,
+      link,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    return sentTx.result;
+  }
+
+  /**
+   * Reveal the player's next chain link for the pending draw (initial deal,
+   * opponent's hit or a new round after a tie)
+   */
+  async revealLink(
+    sessionId: number,
+    player: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const link = await this.nextLink(sessionId, player);
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_link({
+      session_id: sessionId,
+      player,
+      link,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    return sentTx.result;
+  }
+
+  /**
+   * Settle a game after the opponent missed the reveal deadline. Resolves to
+   * the winner, or None when neither hidden hand was revealed and both stakes
+   * were refunded.
+   */
+  async claimTimeout(
+    sessionId: number,
+    player: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.claim_timeout({
+      session_id: sessionId,
+      claimant: player,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);